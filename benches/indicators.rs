@@ -1,24 +1,32 @@
 use bencher::{benchmark_group, benchmark_main, black_box, Bencher};
 use rand::Rng;
 use ta::indicators::{
-    AverageTrueRange, BollingerBands, ChandelierExit, CommodityChannelIndex, EfficiencyRatio,
-    ExponentialMovingAverage, FastStochastic, KeltnerChannel, Maximum, MeanAbsoluteDeviation,
-    Minimum, MoneyFlowIndex, MovingAverageConvergenceDivergence, OnBalanceVolume,
-    PercentagePriceOscillator, RateOfChange, RelativeStrengthIndex, SimpleMovingAverage,
-    SlowStochastic, StandardDeviation, TrueRange, WeightedMovingAverage,
+    AccumulationDistribution, ArnaudLegouxMovingAverage, Aroon, AverageDirectionalIndex,
+    AverageTrueRange, BollingerBands, ChaikinMoneyFlow, ChaikinOscillator, ChandelierExit,
+    CommodityChannelIndex, EaseOfMovement, EfficiencyRatio, ExponentialMovingAverage,
+    FastStochastic, ForceIndex, FullStochastic, HullMovingAverage, IchimokuCloud,
+    KaufmanAdaptiveMovingAverage, KeltnerChannel, Maximum, MeanAbsoluteDeviation, Minimum,
+    MoneyFlowIndex, MovingAverageConvergenceDivergence, NegativeVolumeIndex, OnBalanceVolume,
+    ParabolicSar, PercentagePriceOscillator, PercentageVolumeOscillator, PositiveVolumeIndex,
+    PriceVolumeTrend, RateOfChange, RelativeStrengthIndex, RollingVolumeWeightedAveragePrice,
+    SimpleMovingAverage, SlowStochastic, StandardDeviation, StochasticRsi,
+    TripleExponentialAverage, TripleExponentialAverageWithSignal, TripleExponentialMovingAverage,
+    TrueRange, UltimateOscillator, VolumeWeightedAveragePrice, VolumeWeightedAveragePriceBands,
+    VolumeWeightedMovingAverage, WeightedMovingAverage, WilderMovingAverage, WilliamsR,
 };
 use ta::{DataItem, Next};
 
 const ITEMS_COUNT: usize = 5_000;
+const LARGE_PERIOD: usize = 1_000;
 
 fn rand_data_item() -> DataItem {
     let mut rng = rand::thread_rng();
 
-    let low = rng.gen_range(0.0, 500.0);
-    let high = rng.gen_range(500.0, 1000.0);
-    let open = rng.gen_range(low, high);
-    let close = rng.gen_range(low, high);
-    let volume = rng.gen_range(0.0, 10_000.0);
+    let low = rng.gen_range(0.0..500.0);
+    let high = rng.gen_range(500.0..1000.0);
+    let open = rng.gen_range(low..high);
+    let close = rng.gen_range(low..high);
+    let volume = rng.gen_range(0.0..10_000.0);
 
     DataItem::builder()
         .open(open)
@@ -30,6 +38,21 @@ fn rand_data_item() -> DataItem {
         .unwrap()
 }
 
+// A steady trend is the worst case for the rolling extremes: in a downtrend the highest value
+// drops out of the window on every input, in an uptrend the lowest one.
+fn trending_data_item(index: usize, step: f64) -> DataItem {
+    let close = 10_000.0 + step * index as f64;
+
+    DataItem::builder()
+        .open(close - step)
+        .high(close + 1.0)
+        .low(close - 1.0)
+        .close(close)
+        .volume(1_000.0)
+        .build()
+        .unwrap()
+}
+
 macro_rules! bench_indicators {
     ($($indicator:ident), *) => {
         $(
@@ -47,31 +70,91 @@ macro_rules! bench_indicators {
         )*
 
         benchmark_group!(benches, $($indicator,)*);
-        benchmark_main!(benches);
     }
 }
 
 bench_indicators!(
+    AccumulationDistribution,
+    ArnaudLegouxMovingAverage,
+    Aroon,
+    AverageDirectionalIndex,
     AverageTrueRange,
     ExponentialMovingAverage,
     MeanAbsoluteDeviation,
     BollingerBands,
+    ChaikinMoneyFlow,
+    ChaikinOscillator,
     ChandelierExit,
+    EaseOfMovement,
     EfficiencyRatio,
     FastStochastic,
+    ForceIndex,
+    FullStochastic,
+    HullMovingAverage,
+    IchimokuCloud,
+    KaufmanAdaptiveMovingAverage,
     KeltnerChannel,
     Maximum,
     Minimum,
     MoneyFlowIndex,
     MovingAverageConvergenceDivergence,
+    NegativeVolumeIndex,
     OnBalanceVolume,
+    ParabolicSar,
     PercentagePriceOscillator,
+    PercentageVolumeOscillator,
+    PositiveVolumeIndex,
+    PriceVolumeTrend,
     CommodityChannelIndex,
     RateOfChange,
     RelativeStrengthIndex,
+    RollingVolumeWeightedAveragePrice,
     SimpleMovingAverage,
     SlowStochastic,
     StandardDeviation,
+    StochasticRsi,
+    TripleExponentialAverage,
+    TripleExponentialAverageWithSignal,
+    TripleExponentialMovingAverage,
     TrueRange,
-    WeightedMovingAverage
+    UltimateOscillator,
+    VolumeWeightedAveragePrice,
+    VolumeWeightedAveragePriceBands,
+    VolumeWeightedMovingAverage,
+    WeightedMovingAverage,
+    WilderMovingAverage,
+    WilliamsR
+);
+
+macro_rules! bench_large_periods {
+    ($($name:ident => $indicator:expr, $step:expr);* $(;)?) => {
+        $(
+            #[allow(non_snake_case)]
+            fn $name(bench: &mut Bencher) {
+                let items: Vec<DataItem> = (0..ITEMS_COUNT)
+                    .map(|index| trending_data_item(index, $step))
+                    .collect();
+                let mut indicator = $indicator;
+
+                bench.iter(|| {
+                    for item in items.iter() {
+                        black_box(indicator.next(item));
+                    }
+                })
+            }
+        )*
+
+        benchmark_group!(large_period_benches, $($name,)*);
+    }
+}
+
+bench_large_periods!(
+    Maximum_1000_downtrend => Maximum::new(LARGE_PERIOD).unwrap(), -1.0;
+    Minimum_1000_uptrend => Minimum::new(LARGE_PERIOD).unwrap(), 1.0;
+    FastStochastic_1000_downtrend => FastStochastic::new(LARGE_PERIOD).unwrap(), -1.0;
+    FastStochastic_1000_uptrend => FastStochastic::new(LARGE_PERIOD).unwrap(), 1.0;
+    ChandelierExit_1000_downtrend => ChandelierExit::new(LARGE_PERIOD, 3.0).unwrap(), -1.0;
+    Aroon_1000_downtrend => Aroon::new(LARGE_PERIOD).unwrap(), -1.0;
 );
+
+benchmark_main!(benches, large_period_benches);
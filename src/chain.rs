@@ -0,0 +1,251 @@
+use core::fmt;
+
+use crate::{Lookback, Next, Peek, Reset};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Feeds the output of one indicator into another one.
+///
+/// It allows building indicators of indicators ad hoc, e.g. an SMA of ROC or an RSI of OBV,
+/// without writing a new structure. Chains can be nested to combine more than two indicators.
+///
+/// The chain is warmed up when the second indicator has consumed enough warmed up values of
+/// the first one, i.e. its lookback is the sum of both lookbacks minus 1.
+///
+/// # Example
+///
+/// ```
+/// use ta::indicators::{RateOfChange, SimpleMovingAverage};
+/// use ta::{Chain, Next};
+///
+/// // SMA(2) of ROC(1)
+/// let mut chain = Chain::new(
+///     RateOfChange::new(1).unwrap(),
+///     SimpleMovingAverage::new(2).unwrap(),
+/// );
+///
+/// assert_eq!(chain.next(10.0), 0.0);
+/// assert_eq!(chain.next(11.0), 5.0);
+/// assert_eq!(chain.next(11.0), 5.0);
+/// assert_eq!(format!("{}", chain), "ROC(1) -> SMA(2)");
+/// ```
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone)]
+pub struct Chain<A, B> {
+    first: A,
+    second: B,
+    count: usize,
+}
+
+impl<A, B> Chain<A, B> {
+    pub fn new(first: A, second: B) -> Self {
+        Self {
+            first,
+            second,
+            count: 0,
+        }
+    }
+
+    /// Returns a reference to the indicator, which consumes the input.
+    pub fn first(&self) -> &A {
+        &self.first
+    }
+
+    /// Returns a reference to the indicator, which consumes the output of the first one.
+    pub fn second(&self) -> &B {
+        &self.second
+    }
+
+    /// Unwraps the indicators.
+    pub fn into_inner(self) -> (A, B) {
+        (self.first, self.second)
+    }
+}
+
+impl<A: Lookback, B: Lookback> Lookback for Chain<A, B> {
+    fn lookback(&self) -> usize {
+        self.first.lookback() + self.second.lookback() - 1
+    }
+
+    fn is_ready(&self) -> bool {
+        self.count >= self.lookback()
+    }
+}
+
+impl<T, A, B> Next<T> for Chain<A, B>
+where
+    A: Next<T>,
+    B: Next<A::Output>,
+{
+    type Output = B::Output;
+
+    fn next(&mut self, input: T) -> Self::Output {
+        self.count = self.count.saturating_add(1);
+        self.second.next(self.first.next(input))
+    }
+}
+
+impl<T, A, B> Peek<T> for Chain<A, B>
+where
+    A: Peek<T>,
+    B: Peek<A::Output>,
+{
+    fn peek(&self, input: T) -> Self::Output {
+        self.second.peek(self.first.peek(input))
+    }
+
+    fn update_last(&mut self, input: T) -> Self::Output {
+        if self.count == 0 {
+            return self.next(input);
+        }
+        self.second.update_last(self.first.update_last(input))
+    }
+}
+
+impl<A: Reset, B: Reset> Reset for Chain<A, B> {
+    fn reset(&mut self) {
+        self.first.reset();
+        self.second.reset();
+        self.count = 0;
+    }
+}
+
+impl<A: Default, B: Default> Default for Chain<A, B> {
+    fn default() -> Self {
+        Self::new(A::default(), B::default())
+    }
+}
+
+impl<A: fmt::Display, B: fmt::Display> fmt::Display for Chain<A, B> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{} -> {}", self.first, self.second)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::indicators::{
+        ExponentialMovingAverage, FastStochastic, OnBalanceVolume, RateOfChange,
+        RelativeStrengthIndex, SimpleMovingAverage, SlowStochastic,
+    };
+    use crate::test_helper::*;
+    use crate::Period;
+
+    #[test]
+    fn test_next() {
+        let mut chain = Chain::new(
+            RateOfChange::new(1).unwrap(),
+            SimpleMovingAverage::new(2).unwrap(),
+        );
+
+        assert_eq!(chain.next(10.0), 0.0);
+        assert_eq!(chain.next(11.0), 5.0);
+        assert_eq!(chain.next(11.0), 5.0);
+        assert_eq!(round(chain.next(8.8)), -10.0);
+    }
+
+    #[test]
+    fn test_next_bar() {
+        // RSI of OBV
+        let mut chain = Chain::new(
+            OnBalanceVolume::new(),
+            RelativeStrengthIndex::new(3).unwrap(),
+        );
+        let mut obv = OnBalanceVolume::new();
+        let mut rsi = RelativeStrengthIndex::new(3).unwrap();
+
+        let bars = [
+            Bar::new().close(10).volume(1000.0),
+            Bar::new().close(11).volume(500.0),
+            Bar::new().close(10.5).volume(700.0),
+            Bar::new().close(12).volume(300.0),
+        ];
+
+        for bar in bars.iter() {
+            assert_eq!(chain.next(bar), rsi.next(obv.next(bar)));
+        }
+    }
+
+    #[test]
+    fn test_next_nested() {
+        // Same as SlowStochastic, but built ad hoc.
+        let mut chain = Chain::new(
+            FastStochastic::new(3).unwrap(),
+            ExponentialMovingAverage::new(2).unwrap(),
+        );
+        let mut slow = SlowStochastic::new(3, 2).unwrap();
+
+        for &x in [10.0, 50.0, 50.0, 30.0, 55.0].iter() {
+            assert_eq!(chain.next(x), slow.next(x));
+        }
+
+        let mut chain = Chain::new(chain, SimpleMovingAverage::new(2).unwrap());
+        let mut sma = SimpleMovingAverage::new(2).unwrap();
+        assert_eq!(chain.next(20.0), sma.next(slow.next(20.0)));
+        assert_eq!(chain.lookback(), 5);
+        assert_eq!(format!("{}", chain), "FAST_STOCH(3) -> EMA(2) -> SMA(2)");
+    }
+
+    #[test]
+    fn test_lookback() {
+        let mut chain = Chain::new(
+            FastStochastic::new(3).unwrap(),
+            ExponentialMovingAverage::new(2).unwrap(),
+        );
+        assert_eq!(chain.lookback(), 4);
+
+        for &x in [10.0, 50.0, 50.0].iter() {
+            chain.next(x);
+        }
+        assert!(!chain.is_ready());
+        chain.next(30.0);
+        assert!(chain.is_ready());
+    }
+
+    #[test]
+    fn test_peek() {
+        let chain = Chain::new(
+            RateOfChange::new(1).unwrap(),
+            SimpleMovingAverage::new(2).unwrap(),
+        );
+        assert_peek(chain, &peek_inputs());
+
+        let chain = Chain::new(
+            OnBalanceVolume::new(),
+            RelativeStrengthIndex::new(3).unwrap(),
+        );
+        let bars = peek_bars();
+        assert_peek(chain, &bars.iter().collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut chain = Chain::new(
+            RateOfChange::new(1).unwrap(),
+            SimpleMovingAverage::new(2).unwrap(),
+        );
+
+        chain.next(10.0);
+        assert_eq!(chain.next(11.0), 5.0);
+
+        chain.reset();
+        assert!(!chain.is_ready());
+        assert_eq!(chain.next(11.0), 0.0);
+        assert_eq!(chain.first().period(), 1);
+    }
+
+    #[test]
+    fn test_default() {
+        Chain::<RateOfChange, SimpleMovingAverage>::default();
+    }
+
+    #[test]
+    fn test_display() {
+        let chain = Chain::new(
+            OnBalanceVolume::new(),
+            RelativeStrengthIndex::new(14).unwrap(),
+        );
+        assert_eq!(format!("{}", chain), "OBV -> RSI(14)");
+    }
+}
@@ -1,16 +1,18 @@
 use crate::errors::*;
-use crate::traits::{Close, High, Low, Open, Volume};
+use crate::traits::{Close, High, Low, Open, Timestamp, Volume};
 
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
 /// Data item is used as an input for indicators.
 ///
+/// The timestamp is optional and is 0 unless it's given to the builder.
+///
 /// # Example
 ///
 /// ```
 /// use ta::DataItem;
-/// use ta::{Open, High, Low, Close, Volume};
+/// use ta::{Open, High, Low, Close, Volume, Timestamp};
 ///
 /// let item = DataItem::builder()
 ///     .open(20.0)
@@ -18,6 +20,7 @@ use serde::{Deserialize, Serialize};
 ///     .low(15.0)
 ///     .close(21.0)
 ///     .volume(7500.0)
+///     .timestamp(1_600_000_000_000)
 ///     .build()
 ///     .unwrap();
 ///
@@ -26,6 +29,7 @@ use serde::{Deserialize, Serialize};
 /// assert_eq!(item.low(), 15.0);
 /// assert_eq!(item.close(), 21.0);
 /// assert_eq!(item.volume(), 7500.0);
+/// assert_eq!(item.timestamp(), 1_600_000_000_000);
 /// ```
 ///
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
@@ -36,6 +40,8 @@ pub struct DataItem {
     low: f64,
     close: f64,
     volume: f64,
+    #[cfg_attr(feature = "serde", serde(default))]
+    timestamp: i64,
 }
 
 impl DataItem {
@@ -74,12 +80,19 @@ impl Volume for DataItem {
     }
 }
 
+impl Timestamp for DataItem {
+    fn timestamp(&self) -> i64 {
+        self.timestamp
+    }
+}
+
 pub struct DataItemBuilder {
     open: Option<f64>,
     high: Option<f64>,
     low: Option<f64>,
     close: Option<f64>,
     volume: Option<f64>,
+    timestamp: Option<i64>,
 }
 
 impl DataItemBuilder {
@@ -90,6 +103,7 @@ impl DataItemBuilder {
             low: None,
             close: None,
             volume: None,
+            timestamp: None,
         }
     }
 
@@ -118,6 +132,12 @@ impl DataItemBuilder {
         self
     }
 
+    /// Sets Unix timestamp in milliseconds (UTC).
+    pub fn timestamp(mut self, val: i64) -> Self {
+        self.timestamp = Some(val);
+        self
+    }
+
     pub fn build(self) -> Result<DataItem> {
         if let (Some(open), Some(high), Some(low), Some(close), Some(volume)) =
             (self.open, self.high, self.low, self.close, self.volume)
@@ -136,6 +156,7 @@ impl DataItemBuilder {
                     low,
                     close,
                     volume,
+                    timestamp: self.timestamp.unwrap_or(0),
                 };
                 Ok(item)
             } else {
@@ -202,4 +223,27 @@ mod tests {
             assert_invalid(record)
         }
     }
+
+    #[test]
+    fn test_builder_timestamp() {
+        let builder = || {
+            DataItem::builder()
+                .open(20.0)
+                .high(25.0)
+                .low(15.0)
+                .close(21.0)
+                .volume(7500.0)
+        };
+
+        assert_eq!(builder().build().unwrap().timestamp(), 0);
+        assert_eq!(builder().timestamp(-1).build().unwrap().timestamp(), -1);
+        assert_eq!(
+            builder()
+                .timestamp(1_600_000_000_000)
+                .build()
+                .unwrap()
+                .timestamp(),
+            1_600_000_000_000
+        );
+    }
 }
@@ -0,0 +1,272 @@
+use alloc::boxed::Box;
+use alloc::vec::Vec;
+use core::fmt;
+
+use crate::indicators::{
+    AroonOutput, AverageDirectionalIndexOutput, BollingerBandsOutput, ChandelierExitOutput,
+    FullStochasticOutput, IchimokuCloudOutput, KeltnerChannelOutput,
+    MovingAverageConvergenceDivergenceOutput, ParabolicSarOutput, PercentagePriceOscillatorOutput,
+    PercentageVolumeOscillatorOutput, StochasticRsiOutput, TripleExponentialAverageOutput,
+    VolumeWeightedAveragePriceBandsOutput,
+};
+use crate::{DataItem, Fields, Lookback, Next, Reset};
+
+/// Output of a [DynIndicator](trait.DynIndicator.html).
+///
+/// Indicators with a single value return `Value`, the others return their output fields by
+/// name, in the order of the fields of their output struct.
+///
+/// # Example
+///
+/// ```
+/// use ta::indicators::{BollingerBands, SimpleMovingAverage};
+/// use ta::{DataItem, DynIndicator};
+///
+/// let item = DataItem::builder()
+///     .open(10.0)
+///     .high(10.0)
+///     .low(10.0)
+///     .close(10.0)
+///     .volume(1000.0)
+///     .build()
+///     .unwrap();
+///
+/// let mut sma = SimpleMovingAverage::new(3).unwrap();
+/// let mut bb = BollingerBands::new(3, 2.0).unwrap();
+///
+/// assert_eq!(sma.next_dyn(&item).get("value"), Some(10.0));
+/// assert_eq!(bb.next_dyn(&item).get("upper"), Some(10.0));
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub enum DynOutput {
+    Value(f64),
+    Fields(Vec<(&'static str, f64)>),
+}
+
+impl DynOutput {
+    /// Returns the value of the field with the given name, `"value"` for `Value`.
+    pub fn get(&self, name: &str) -> Option<f64> {
+        self.iter()
+            .find(|(field, _)| *field == name)
+            .map(|(_, value)| value)
+    }
+
+    /// Iterates over the names and the values of the fields.
+    pub fn iter(&self) -> impl Iterator<Item = (&'static str, f64)> + '_ {
+        let (value, fields) = match self {
+            DynOutput::Value(value) => (Some(("value", *value)), &[][..]),
+            DynOutput::Fields(fields) => (None, &fields[..]),
+        };
+        value.into_iter().chain(fields.iter().copied())
+    }
+}
+
+impl From<f64> for DynOutput {
+    fn from(value: f64) -> Self {
+        DynOutput::Value(value)
+    }
+}
+
+macro_rules! impl_from_output {
+    ($($output:ident),* $(,)?) => {
+        $(
+            impl From<$output> for DynOutput {
+                fn from(output: $output) -> Self {
+                    DynOutput::Fields(output.fields())
+                }
+            }
+        )*
+    };
+}
+
+impl_from_output!(
+    AroonOutput,
+    AverageDirectionalIndexOutput,
+    BollingerBandsOutput,
+    ChandelierExitOutput,
+    FullStochasticOutput,
+    IchimokuCloudOutput,
+    KeltnerChannelOutput,
+    MovingAverageConvergenceDivergenceOutput,
+    ParabolicSarOutput,
+    PercentagePriceOscillatorOutput,
+    PercentageVolumeOscillatorOutput,
+    StochasticRsiOutput,
+    TripleExponentialAverageOutput,
+    VolumeWeightedAveragePriceBandsOutput,
+);
+
+/// Indicator as a trait object.
+///
+/// `Next<T>` has an associated `Output` type, so indicators can't be stored together, e.g. in a
+/// `Vec<Box<dyn Next<T>>>`. `DynIndicator` feeds every indicator with a
+/// [DataItem](struct.DataItem.html) and returns a [DynOutput](enum.DynOutput.html) instead.
+/// It's implemented for every indicator, which accepts `&DataItem`.
+///
+/// Indicators can be created by name with a [Registry](struct.Registry.html) as well.
+///
+/// # Example
+///
+/// ```
+/// use ta::indicators::{RelativeStrengthIndex, SimpleMovingAverage};
+/// use ta::{DataItem, DynIndicator, DynOutput};
+///
+/// let mut indicators: Vec<Box<dyn DynIndicator>> = vec![
+///     Box::new(SimpleMovingAverage::new(2).unwrap()),
+///     Box::new(RelativeStrengthIndex::new(2).unwrap()),
+/// ];
+///
+/// let item = DataItem::builder()
+///     .open(10.0)
+///     .high(10.0)
+///     .low(10.0)
+///     .close(10.0)
+///     .volume(1000.0)
+///     .build()
+///     .unwrap();
+///
+/// for indicator in indicators.iter_mut() {
+///     let output = indicator.next_dyn(&item);
+///     println!("{}: {:?}", indicator, output);
+/// }
+/// ```
+pub trait DynIndicator: Reset + Lookback + fmt::Display + fmt::Debug {
+    fn next_dyn(&mut self, input: &DataItem) -> DynOutput;
+
+    fn clone_dyn(&self) -> Box<dyn DynIndicator>;
+}
+
+impl<I, O> DynIndicator for I
+where
+    I: for<'a> Next<&'a DataItem, Output = O>
+        + Reset
+        + Lookback
+        + fmt::Display
+        + fmt::Debug
+        + Clone
+        + 'static,
+    O: Into<DynOutput>,
+{
+    fn next_dyn(&mut self, input: &DataItem) -> DynOutput {
+        self.next(input).into()
+    }
+
+    fn clone_dyn(&self) -> Box<dyn DynIndicator> {
+        Box::new(self.clone())
+    }
+}
+
+impl Clone for Box<dyn DynIndicator> {
+    fn clone(&self) -> Self {
+        self.clone_dyn()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::indicators::{
+        BollingerBands, ExponentialMovingAverage, ParabolicSar, SimpleMovingAverage,
+    };
+    use crate::test_helper::*;
+    use crate::{Close, High, Low, Volume};
+
+    fn items() -> Vec<DataItem> {
+        peek_bars()
+            .iter()
+            .map(|bar| {
+                DataItem::builder()
+                    .open(bar.close())
+                    .high(bar.high())
+                    .low(bar.low())
+                    .close(bar.close())
+                    .volume(bar.volume())
+                    .build()
+                    .unwrap()
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_next_dyn() {
+        let mut sma = SimpleMovingAverage::new(3).unwrap();
+        let mut bb = BollingerBands::new(3, 2.0).unwrap();
+        let mut indicators: Vec<Box<dyn DynIndicator>> = vec![
+            Box::new(SimpleMovingAverage::new(3).unwrap()),
+            Box::new(BollingerBands::new(3, 2.0).unwrap()),
+        ];
+
+        for item in items().iter() {
+            let sma_output = sma.next(item);
+            let bb_output = bb.next(item);
+            assert_eq!(indicators[0].next_dyn(item), DynOutput::Value(sma_output));
+            assert_eq!(
+                indicators[1].next_dyn(item),
+                DynOutput::Fields(vec![
+                    ("average", bb_output.average),
+                    ("upper", bb_output.upper),
+                    ("lower", bb_output.lower),
+                ])
+            );
+        }
+    }
+
+    #[test]
+    fn test_output() {
+        let output = DynOutput::Value(2.0);
+        assert_eq!(output.get("value"), Some(2.0));
+        assert_eq!(output.get("upper"), None);
+        assert_eq!(output.iter().collect::<Vec<_>>(), vec![("value", 2.0)]);
+
+        let output = DynOutput::Fields(vec![("upper", 3.0), ("lower", 1.0)]);
+        assert_eq!(output.get("lower"), Some(1.0));
+        assert_eq!(output.get("value"), None);
+        assert_eq!(
+            output.iter().collect::<Vec<_>>(),
+            vec![("upper", 3.0), ("lower", 1.0)]
+        );
+    }
+
+    #[test]
+    fn test_parabolic_sar_output() {
+        let mut psar: Box<dyn DynIndicator> = Box::new(ParabolicSar::default());
+
+        for item in items().iter() {
+            let trend = psar.next_dyn(item).get("trend").unwrap();
+            assert!(trend == 1.0 || trend == -1.0);
+        }
+    }
+
+    #[test]
+    fn test_reset_and_lookback() {
+        let mut indicator: Box<dyn DynIndicator> =
+            Box::new(ExponentialMovingAverage::new(2).unwrap());
+        let items = items();
+
+        assert_eq!(indicator.lookback(), 2);
+        let first = indicator.next_dyn(&items[0]);
+        assert!(!indicator.is_ready());
+        indicator.next_dyn(&items[1]);
+        assert!(indicator.is_ready());
+
+        indicator.reset();
+        assert!(!indicator.is_ready());
+        assert_eq!(indicator.next_dyn(&items[0]), first);
+    }
+
+    #[test]
+    fn test_clone() {
+        let mut indicator: Box<dyn DynIndicator> = Box::new(SimpleMovingAverage::new(2).unwrap());
+        let items = items();
+        indicator.next_dyn(&items[0]);
+
+        let mut cloned = indicator.clone();
+        assert_eq!(cloned.next_dyn(&items[1]), indicator.next_dyn(&items[1]));
+    }
+
+    #[test]
+    fn test_display() {
+        let indicator: Box<dyn DynIndicator> = Box::new(BollingerBands::new(20, 2.0).unwrap());
+        assert_eq!(format!("{}", indicator), "BB(20, 2)");
+    }
+}
@@ -1,31 +1,38 @@
-use std::error::Error;
-use std::fmt::{Display, Formatter};
+use core::fmt::{Display, Formatter};
 
-pub type Result<T> = std::result::Result<T, TaError>;
+pub type Result<T> = core::result::Result<T, TaError>;
 
 #[derive(Debug, PartialEq, Eq, Clone)]
+#[non_exhaustive]
 pub enum TaError {
     InvalidParameter,
     DataItemIncomplete,
     DataItemInvalid,
+    UnknownIndicator,
+    InvalidFormat,
 }
 
 impl Display for TaError {
-    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+    fn fmt(&self, f: &mut Formatter) -> core::fmt::Result {
         match *self {
             TaError::InvalidParameter => write!(f, "invalid parameter"),
             TaError::DataItemIncomplete => write!(f, "data item is incomplete"),
             TaError::DataItemInvalid => write!(f, "data item is invalid"),
+            TaError::UnknownIndicator => write!(f, "unknown indicator"),
+            TaError::InvalidFormat => write!(f, "invalid format"),
         }
     }
 }
 
-impl Error for TaError {
-    fn source(&self) -> Option<&(dyn Error + 'static)> {
+#[cfg(feature = "std")]
+impl std::error::Error for TaError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
         match *self {
             TaError::InvalidParameter => None,
             TaError::DataItemIncomplete => None,
             TaError::DataItemInvalid => None,
+            TaError::UnknownIndicator => None,
+            TaError::InvalidFormat => None,
         }
     }
 }
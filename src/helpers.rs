@@ -1,8 +1,128 @@
+use crate::Num;
+
 /// Returns the largest of 3 given numbers.
-pub fn max3(a: f64, b: f64, c: f64) -> f64 {
+pub fn max3<N: Num>(a: N, b: N, c: N) -> N {
     a.max(b).max(c)
 }
 
+/// Implements [NextBatch](crate::NextBatch) for an indicator on top of its `Next` implementation.
+///
+/// `impl_next_batch!(Sma, N => N)` covers `Next<N>` for any [numeric type](crate::Num) `N`,
+/// `impl_next_batch!(Sma, Close => N)` covers `Next<&T>` where `T: Close<N>`.
+/// Within [impl_for_floats], where `N` is a concrete type, `impl_next_batch!(Sma<N>, Close => N)`
+/// covers `Next<&T>` for that type only.
+macro_rules! impl_next_batch {
+    ($indicator:ident, N => $output:ty) => {
+        impl<N: $crate::Num> $crate::NextBatch<N> for $indicator<N> {
+            type Output = $output;
+
+            fn next_batch(&mut self, input: &[N]) -> alloc::vec::Vec<Self::Output> {
+                input.iter().map(|&item| self.next(item)).collect()
+            }
+
+            fn next_batch_into(&mut self, input: &[N], output: &mut [Self::Output]) {
+                assert_eq!(input.len(), output.len());
+                for (item, out) in input.iter().zip(output.iter_mut()) {
+                    *out = self.next(*item);
+                }
+            }
+        }
+    };
+    ($indicator:ident<N>, $bound:ident $(+ $bounds:ident)* => $output:ty) => {
+        impl<T: $bound<N> $(+ $bounds<N>)*> $crate::NextBatch<T> for $indicator<N> {
+            type Output = $output;
+
+            fn next_batch(&mut self, input: &[T]) -> alloc::vec::Vec<Self::Output> {
+                input.iter().map(|item| self.next(item)).collect()
+            }
+
+            fn next_batch_into(&mut self, input: &[T], output: &mut [Self::Output]) {
+                assert_eq!(input.len(), output.len());
+                for (item, out) in input.iter().zip(output.iter_mut()) {
+                    *out = self.next(item);
+                }
+            }
+        }
+    };
+    ($indicator:ident, $bound:ident $(+ $bounds:ident)* => $output:ty) => {
+        impl<N: $crate::Num, T: $bound<N> $(+ $bounds<N>)*> $crate::NextBatch<T> for $indicator<N> {
+            type Output = $output;
+
+            fn next_batch(&mut self, input: &[T]) -> alloc::vec::Vec<Self::Output> {
+                input.iter().map(|item| self.next(item)).collect()
+            }
+
+            fn next_batch_into(&mut self, input: &[T], output: &mut [Self::Output]) {
+                assert_eq!(input.len(), output.len());
+                for (item, out) in input.iter().zip(output.iter_mut()) {
+                    *out = self.next(item);
+                }
+            }
+        }
+    };
+}
+
+/// Repeats the items for `f64` and `f32` as `N`.
+///
+/// An indicator, which implements `Next<N>` for any [numeric type](crate::Num) `N`, can't
+/// implement `Next<&T>` for any `N` as well, because both impls would overlap if `N` was a
+/// reference. Its impls for bars are written once with `N` and repeated for the built-in floats.
+macro_rules! impl_for_floats {
+    ($($item:item)*) => {
+        const _: () = {
+            type N = f64;
+            $($item)*
+        };
+        const _: () = {
+            type N = f32;
+            $($item)*
+        };
+    };
+}
+
+/// Implements [Fields](crate::Fields) for an output struct and converts it into an array of
+/// its values, e.g. `impl_fields!(BollingerBandsOutput { average, upper, lower })`.
+macro_rules! impl_fields {
+    ($output:ident { $($field:ident),* $(,)? }) => {
+        impl<N: Copy> $crate::Fields for $output<N> {
+            type Value = N;
+
+            const NAMES: &'static [&'static str] = &[$(stringify!($field)),*];
+
+            fn values(&self) -> alloc::vec::Vec<N> {
+                alloc::vec![$(self.$field),*]
+            }
+
+            fn get(&self, name: &str) -> Option<N> {
+                match name {
+                    $(stringify!($field) => Some(self.$field),)*
+                    _ => None,
+                }
+            }
+        }
+
+        impl<N> From<$output<N>> for [N; [$(stringify!($field)),*].len()] {
+            fn from(output: $output<N>) -> Self {
+                [$(output.$field),*]
+            }
+        }
+    };
+}
+
+/// Implements `FromStr` for an indicator, which implements `FromParams`, so that it's parsed
+/// from its `Display` representation, e.g. `"SMA(9)"`.
+macro_rules! impl_from_str {
+    ($indicator:ident) => {
+        impl<N: $crate::Num> core::str::FromStr for $indicator<N> {
+            type Err = $crate::errors::TaError;
+
+            fn from_str(s: &str) -> $crate::errors::Result<Self> {
+                $crate::registry::parse_indicator(s)
+            }
+        }
+    };
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
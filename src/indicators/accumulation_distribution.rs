@@ -0,0 +1,238 @@
+use core::fmt;
+
+use crate::errors::{Result, TaError};
+use crate::registry::{FromParams, Param};
+use crate::{Close, High, Lookback, Low, Next, Num, Peek, Reset, Volume};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Accumulation/Distribution line (A/D).
+///
+/// A cumulative volume based indicator, which weights the volume of every bar by the location
+/// of the close price within the bar's range. Closes near the high add volume (accumulation),
+/// closes near the low subtract volume (distribution).
+///
+/// # Formula
+///
+/// CLV = ((close - low) - (high - close)) / (high - low)
+///
+/// A/D<sub>t</sub> = A/D<sub>t-1</sub> + CLV × volume
+///
+/// Where:
+///
+/// * _CLV_ - close location value, also known as money flow multiplier. When high equals low,
+///   CLV is 0.
+///
+/// # Example
+///
+/// ```
+/// use ta::indicators::AccumulationDistribution;
+/// use ta::{DataItem, Next};
+///
+/// let mut ad = AccumulationDistribution::new();
+///
+/// let di1 = DataItem::builder()
+///     .high(10.0)
+///     .low(8.0)
+///     .close(9.5)
+///     .open(9.0)
+///     .volume(100.0)
+///     .build()
+///     .unwrap();
+///
+/// let di2 = DataItem::builder()
+///     .high(12.0)
+///     .low(9.0)
+///     .close(9.0)
+///     .open(11.0)
+///     .volume(200.0)
+///     .build()
+///     .unwrap();
+///
+/// assert_eq!(ad.next(&di1), 50.0);
+/// assert_eq!(ad.next(&di2), -150.0);
+/// ```
+///
+/// # Links
+///
+/// * [Accumulation/distribution index, Wikipedia](https://en.wikipedia.org/wiki/Accumulation/distribution_index)
+/// * [Accumulation Distribution Line, stockcharts](https://school.stockcharts.com/doku.php?id=technical_indicators:accumulation_distribution_line)
+///
+#[doc(alias = "AD")]
+#[doc(alias = "ADL")]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone)]
+pub struct AccumulationDistribution<N = f64> {
+    ad: N,
+    prev_ad: N,
+    count: usize,
+}
+
+impl<N: Num> AccumulationDistribution<N> {
+    pub fn new() -> Self {
+        Self {
+            ad: N::zero(),
+            prev_ad: N::zero(),
+            count: 0,
+        }
+    }
+
+    // Returns the close location value of a bar, 0 when high equals low.
+    pub(super) fn close_location_value<T: High<N> + Low<N> + Close<N>>(input: &T) -> N {
+        let range = input.high() - input.low();
+        if range == N::zero() {
+            N::zero()
+        } else {
+            ((input.close() - input.low()) - (input.high() - input.close())) / range
+        }
+    }
+
+    // Returns the volume of a bar multiplied by its close location value.
+    pub(super) fn money_flow_volume<T: High<N> + Low<N> + Close<N> + Volume<N>>(input: &T) -> N {
+        Self::close_location_value(input) * input.volume()
+    }
+}
+
+impl<N: Num> Lookback for AccumulationDistribution<N> {
+    fn lookback(&self) -> usize {
+        1
+    }
+
+    fn is_ready(&self) -> bool {
+        self.count == self.lookback()
+    }
+}
+
+impl<N: Num, T: High<N> + Low<N> + Close<N> + Volume<N>> Next<&T> for AccumulationDistribution<N> {
+    type Output = N;
+
+    fn next(&mut self, input: &T) -> N {
+        self.prev_ad = self.ad;
+        self.ad += Self::money_flow_volume(input);
+        if self.count < self.lookback() {
+            self.count += 1;
+        }
+        self.ad
+    }
+}
+
+impl_next_batch!(AccumulationDistribution, High + Low + Close + Volume => N);
+
+impl<N: Num, T: High<N> + Low<N> + Close<N> + Volume<N>> Peek<&T> for AccumulationDistribution<N> {
+    fn peek(&self, input: &T) -> N {
+        self.ad + Self::money_flow_volume(input)
+    }
+
+    fn update_last(&mut self, input: &T) -> N {
+        if self.count == 0 {
+            return self.next(input);
+        }
+        self.ad = self.prev_ad + Self::money_flow_volume(input);
+        self.ad
+    }
+}
+
+impl<N: Num> Default for AccumulationDistribution<N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<N: Num> fmt::Display for AccumulationDistribution<N> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "AD")
+    }
+}
+
+impl<N: Num> Reset for AccumulationDistribution<N> {
+    fn reset(&mut self) {
+        self.ad = N::zero();
+        self.prev_ad = N::zero();
+        self.count = 0;
+    }
+}
+
+impl<N: Num> FromParams for AccumulationDistribution<N> {
+    const NAME: &'static str = "AD";
+
+    fn from_params(params: &[Param]) -> Result<Self> {
+        match *params {
+            [] => Ok(Self::new()),
+            _ => Err(TaError::InvalidParameter),
+        }
+    }
+}
+
+impl_from_str!(AccumulationDistribution);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::indicators::AccumulationDistribution;
+    use crate::test_helper::*;
+
+    #[test]
+    fn test_next_bar() {
+        let mut ad = AccumulationDistribution::new();
+
+        let bar1 = Bar::new().high(10).low(8).close(9.5).volume(100.0);
+        let bar2 = Bar::new().high(12).low(9).close(9).volume(200.0);
+        let bar3 = Bar::new().high(11).low(11).close(11).volume(500.0);
+        let bar4 = Bar::new().high(13).low(10).close(12).volume(300.0);
+
+        assert_eq!(ad.next(&bar1), 50.0);
+        assert_eq!(ad.next(&bar2), -150.0);
+
+        // high == low
+        assert_eq!(ad.next(&bar3), -150.0);
+
+        assert_eq!(ad.next(&bar4), -50.0);
+    }
+
+    #[test]
+    fn test_peek() {
+        let bars = peek_bars();
+        let bars: Vec<&Bar> = bars.iter().collect();
+        assert_peek(AccumulationDistribution::new(), &bars);
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut ad = AccumulationDistribution::new();
+
+        let bar1 = Bar::new().high(10).low(8).close(9.5).volume(100.0);
+        let bar2 = Bar::new().high(12).low(9).close(9).volume(200.0);
+
+        assert_eq!(ad.next(&bar1), 50.0);
+        assert_eq!(ad.next(&bar2), -150.0);
+
+        ad.reset();
+
+        assert_eq!(ad.next(&bar1), 50.0);
+        assert_eq!(ad.next(&bar2), -150.0);
+    }
+
+    #[test]
+    fn test_lookback() {
+        let mut ad = AccumulationDistribution::new();
+        assert_eq!(ad.lookback(), 1);
+        assert!(!ad.is_ready());
+
+        ad.next(&Bar::new().high(2).low(1).close(1.5).volume(10.0));
+        assert!(ad.is_ready());
+
+        ad.reset();
+        assert!(!ad.is_ready());
+    }
+
+    #[test]
+    fn test_default() {
+        AccumulationDistribution::default();
+    }
+
+    #[test]
+    fn test_display() {
+        let ad = AccumulationDistribution::new();
+        assert_eq!(format!("{}", ad), "AD");
+    }
+}
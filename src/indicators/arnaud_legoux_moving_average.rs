@@ -0,0 +1,329 @@
+use alloc::boxed::Box;
+use alloc::vec;
+use core::fmt;
+
+use crate::errors::{Result, TaError};
+use crate::registry::{FromParams, Param};
+use crate::{Close, Lookback, Next, Num, Peek, Period, Reset};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Arnaud Legoux moving average (ALMA).
+///
+/// A moving average, which weights the inputs of the window with a Gaussian curve. The peak of
+/// the curve is moved towards the recent inputs by _offset_, which reduces the lag, and the
+/// width of the curve is controlled by _sigma_, which controls the smoothness.
+///
+/// # Formula
+///
+/// ALMA = Σ(_w<sub>i</sub>_ × _p<sub>i</sub>_) / Σ(_w<sub>i</sub>_)
+///
+/// _w<sub>i</sub>_ = exp(-(_i_ - _m_)<sup>2</sup> / (2 × _s_<sup>2</sup>))
+///
+/// Where:
+///
+/// * _i_ - is the position in the window, from 0 for the oldest input to _n_ - 1 for the latest
+/// * _p<sub>i</sub>_ - is the input value at the position _i_
+/// * _m_ - is _offset_ × (_n_ - 1)
+/// * _s_ - is _n_ / _sigma_
+/// * _n_ - is the period
+///
+/// Until _period_ inputs are consumed, the weights of the latest positions are used.
+///
+/// # Parameters
+///
+/// * _period_ - number of periods (integer greater than 0). Default is 9.
+/// * _offset_ - position of the peak of the curve (number between 0 and 1). Default is 0.85.
+/// * _sigma_ - smoothness (number greater than 0). Default is 6.
+///
+/// # Example
+///
+/// ```
+/// use ta::indicators::ArnaudLegouxMovingAverage;
+/// use ta::Next;
+///
+/// // offset 0.5 gives symmetric weights
+/// let mut alma = ArnaudLegouxMovingAverage::new(3, 0.5, 6.0).unwrap();
+/// assert_eq!(alma.next(10.0), 10.0);
+/// alma.next(12.0);
+/// assert_eq!(alma.next(14.0).round(), 12.0);
+/// ```
+///
+/// # Links
+///
+/// * [Arnaud Legoux Moving Average, TradingView](https://www.tradingview.com/support/solutions/43000594683-arnaud-legoux-moving-average/)
+///
+#[doc(alias = "ALMA")]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone)]
+pub struct ArnaudLegouxMovingAverage<N = f64> {
+    period: usize,
+    offset: f64,
+    sigma: f64,
+    index: usize,
+    count: usize,
+    weights: Box<[N]>,
+    deque: Box<[N]>,
+}
+
+impl<N: Num> ArnaudLegouxMovingAverage<N> {
+    pub fn new(period: usize, offset: f64, sigma: f64) -> Result<Self> {
+        if period == 0 || !(0.0..=1.0).contains(&offset) || sigma.is_nan() || sigma <= 0.0 {
+            return Err(TaError::InvalidParameter);
+        }
+
+        let m = offset * (period - 1) as f64;
+        let s = period as f64 / sigma;
+        let weights = (0..period)
+            .map(|i| {
+                let x = i as f64 - m;
+                N::from_f64(Num::exp(-x * x / (2.0 * s * s)))
+            })
+            .collect();
+
+        Ok(Self {
+            period,
+            offset,
+            sigma,
+            index: 0,
+            count: 0,
+            weights,
+            deque: vec![N::zero(); period].into_boxed_slice(),
+        })
+    }
+
+    pub fn offset(&self) -> f64 {
+        self.offset
+    }
+
+    pub fn sigma(&self) -> f64 {
+        self.sigma
+    }
+
+    // Calculates the average of the window of `count` values, which ends at `input_index`,
+    // where the value at `input_index` is replaced with `input`.
+    fn alma(&self, input: N, input_index: usize, count: usize) -> N {
+        let mut sum = N::zero();
+        let mut norm = N::zero();
+        for k in 0..count {
+            let i = (input_index + 1 + self.period - count + k) % self.period;
+            let value = if i == input_index {
+                input
+            } else {
+                self.deque[i]
+            };
+            let weight = self.weights[self.period - count + k];
+            sum += weight * value;
+            norm += weight;
+        }
+        sum / norm
+    }
+}
+
+impl<N: Num> Period for ArnaudLegouxMovingAverage<N> {
+    fn period(&self) -> usize {
+        self.period
+    }
+}
+
+impl<N: Num> Lookback for ArnaudLegouxMovingAverage<N> {
+    fn lookback(&self) -> usize {
+        self.period
+    }
+
+    fn is_ready(&self) -> bool {
+        self.count == self.period
+    }
+}
+
+impl<N: Num> Next<N> for ArnaudLegouxMovingAverage<N> {
+    type Output = N;
+
+    fn next(&mut self, input: N) -> Self::Output {
+        let input_index = self.index;
+        self.deque[input_index] = input;
+
+        self.index = if self.index + 1 < self.period {
+            self.index + 1
+        } else {
+            0
+        };
+
+        if self.count < self.period {
+            self.count += 1;
+        }
+
+        self.alma(input, input_index, self.count)
+    }
+}
+
+impl_next_batch!(ArnaudLegouxMovingAverage, N => N);
+
+impl<N: Num> Peek<N> for ArnaudLegouxMovingAverage<N> {
+    fn peek(&self, input: N) -> Self::Output {
+        self.alma(input, self.index, (self.count + 1).min(self.period))
+    }
+
+    fn update_last(&mut self, input: N) -> Self::Output {
+        if self.count == 0 {
+            return self.next(input);
+        }
+
+        let last = if self.index == 0 {
+            self.period - 1
+        } else {
+            self.index - 1
+        };
+        self.deque[last] = input;
+
+        self.alma(input, last, self.count)
+    }
+}
+
+impl_for_floats! {
+    impl<T: Close<N>> Next<&T> for ArnaudLegouxMovingAverage<N> {
+        type Output = N;
+
+        fn next(&mut self, input: &T) -> Self::Output {
+            self.next(input.close())
+        }
+    }
+
+    impl_next_batch!(ArnaudLegouxMovingAverage<N>, Close => N);
+
+    impl<T: Close<N>> Peek<&T> for ArnaudLegouxMovingAverage<N> {
+        fn peek(&self, input: &T) -> Self::Output {
+            self.peek(input.close())
+        }
+
+        fn update_last(&mut self, input: &T) -> Self::Output {
+            self.update_last(input.close())
+        }
+    }
+}
+
+impl<N: Num> Reset for ArnaudLegouxMovingAverage<N> {
+    fn reset(&mut self) {
+        self.index = 0;
+        self.count = 0;
+        for i in 0..self.period {
+            self.deque[i] = N::zero();
+        }
+    }
+}
+
+impl<N: Num> Default for ArnaudLegouxMovingAverage<N> {
+    fn default() -> Self {
+        Self::new(9, 0.85, 6.0).unwrap()
+    }
+}
+
+impl<N: Num> fmt::Display for ArnaudLegouxMovingAverage<N> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "ALMA({}, {}, {})", self.period, self.offset, self.sigma)
+    }
+}
+
+impl<N: Num> FromParams for ArnaudLegouxMovingAverage<N> {
+    const NAME: &'static str = "ALMA";
+
+    fn from_params(params: &[Param]) -> Result<Self> {
+        match *params {
+            [period, offset, sigma] => {
+                Self::new(period.period()?, offset.number()?, sigma.number()?)
+            }
+            _ => Err(TaError::InvalidParameter),
+        }
+    }
+}
+
+impl_from_str!(ArnaudLegouxMovingAverage);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::indicators::ArnaudLegouxMovingAverage;
+    use crate::test_helper::*;
+    type Alma = ArnaudLegouxMovingAverage;
+
+    test_indicator!(Alma);
+
+    #[test]
+    fn test_new() {
+        assert!(Alma::new(0, 0.85, 6.0).is_err());
+        assert!(Alma::new(9, -0.1, 6.0).is_err());
+        assert!(Alma::new(9, 1.1, 6.0).is_err());
+        assert!(Alma::new(9, 0.85, 0.0).is_err());
+        assert!(Alma::new(9, f64::NAN, 6.0).is_err());
+        assert!(Alma::new(9, 0.85, f64::NAN).is_err());
+        assert!(Alma::new(1, 0.0, 1.0).is_ok());
+        assert!(Alma::new(9, 1.0, 6.0).is_ok());
+    }
+
+    #[test]
+    fn test_next() {
+        let mut alma = Alma::new(3, 0.85, 6.0).unwrap();
+
+        assert_eq!(alma.next(10.0), 10.0);
+        assert_eq!(round(alma.next(12.0)), 11.38);
+        assert_eq!(round(alma.next(15.0)), 14.06);
+        assert_eq!(round(alma.next(13.0)), 13.616);
+    }
+
+    #[test]
+    fn test_next_symmetric() {
+        let mut alma = Alma::new(3, 0.5, 6.0).unwrap();
+
+        assert_eq!(alma.next(10.0), 10.0);
+        alma.next(12.0);
+        assert_eq!(round(alma.next(14.0)), 12.0);
+        assert_eq!(round(alma.next(16.0)), 14.0);
+    }
+
+    #[test]
+    fn test_next_period_1() {
+        let mut alma = Alma::new(1, 0.85, 6.0).unwrap();
+
+        assert_eq!(alma.next(10.0), 10.0);
+        assert_eq!(alma.next(13.0), 13.0);
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut alma = Alma::new(3, 0.85, 6.0).unwrap();
+
+        assert_eq!(alma.next(10.0), 10.0);
+        assert_eq!(round(alma.next(12.0)), 11.38);
+
+        alma.reset();
+
+        assert_eq!(alma.next(10.0), 10.0);
+        assert_eq!(round(alma.next(12.0)), 11.38);
+    }
+
+    #[test]
+    fn test_lookback() {
+        let mut alma = Alma::new(3, 0.85, 6.0).unwrap();
+        assert_eq!(alma.lookback(), 3);
+
+        for _ in 0..2 {
+            alma.next(1.0);
+            assert!(!alma.is_ready());
+        }
+        alma.next(1.0);
+        assert!(alma.is_ready());
+    }
+
+    #[test]
+    fn test_default() {
+        let alma = Alma::default();
+        assert_eq!(alma.offset(), 0.85);
+        assert_eq!(alma.sigma(), 6.0);
+    }
+
+    #[test]
+    fn test_display() {
+        let alma = Alma::new(9, 0.85, 6.0).unwrap();
+        assert_eq!(format!("{}", alma), "ALMA(9, 0.85, 6)");
+    }
+}
@@ -0,0 +1,296 @@
+use core::fmt;
+
+use crate::errors::{Result, TaError};
+use crate::indicators::generic::{Maximum, Minimum};
+use crate::registry::{FromParams, Param};
+use crate::{High, Lookback, Low, Next, Num, Peek, Period, Reset};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Aroon indicator and Aroon Oscillator.
+///
+/// Aroon measures how long it has been since the highest high and the lowest low of the
+/// period. Aroon Up close to 100 means a recent high, so a strong uptrend, Aroon Down close to
+/// 100 means a recent low. The oscillator is the difference of both lines, in the range from
+/// -100 to 100.
+///
+/// # Formula
+///
+/// Aroon Up = 100 × (_n_ - periods since the highest high) / _n_
+///
+/// Aroon Down = 100 × (_n_ - periods since the lowest low) / _n_
+///
+/// Aroon Oscillator = Aroon Up - Aroon Down
+///
+/// Where:
+///
+/// * _n_ - number of periods, the highest high and the lowest low are looked up in the last
+///   _n_ + 1 periods, including the current one
+///
+/// When the highest high or the lowest low occurs more than once, the latest one counts.
+///
+/// # Parameters
+///
+/// * _period_ - number of periods (integer greater than 0). Default is 25.
+///
+/// # Example
+///
+/// ```
+/// use ta::indicators::Aroon;
+/// use ta::Next;
+///
+/// let mut aroon = Aroon::new(2).unwrap();
+/// aroon.next(10.0);
+/// aroon.next(12.0);
+///
+/// let output = aroon.next(11.0);
+/// assert_eq!(output.up, 50.0);
+/// assert_eq!(output.down, 0.0);
+/// assert_eq!(output.oscillator, 50.0);
+/// ```
+///
+/// # Links
+///
+/// * [Aroon, stockcharts](https://school.stockcharts.com/doku.php?id=technical_indicators:aroon)
+///
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone)]
+pub struct Aroon<N = f64> {
+    period: usize,
+    maximum: Maximum<N>,
+    minimum: Minimum<N>,
+}
+
+impl<N: Num> Aroon<N> {
+    pub fn new(period: usize) -> Result<Self> {
+        if period == 0 {
+            return Err(TaError::InvalidParameter);
+        }
+
+        Ok(Self {
+            period,
+            maximum: Maximum::new(period + 1)?,
+            minimum: Minimum::new(period + 1)?,
+        })
+    }
+
+    fn aroon(&self, max_age: usize, min_age: usize) -> AroonOutput<N> {
+        let period = N::from_usize(self.period);
+        let up = N::from_f64(100.0) * (period - N::from_usize(max_age)) / period;
+        let down = N::from_f64(100.0) * (period - N::from_usize(min_age)) / period;
+        AroonOutput {
+            up,
+            down,
+            oscillator: up - down,
+        }
+    }
+}
+
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, PartialEq)]
+pub struct AroonOutput<N = f64> {
+    pub up: N,
+    pub down: N,
+    pub oscillator: N,
+}
+
+impl_fields!(AroonOutput {
+    up,
+    down,
+    oscillator
+});
+
+impl<N> From<AroonOutput<N>> for (N, N, N) {
+    fn from(output: AroonOutput<N>) -> Self {
+        (output.up, output.down, output.oscillator)
+    }
+}
+
+impl<N: Num> Period for Aroon<N> {
+    fn period(&self) -> usize {
+        self.period
+    }
+}
+
+impl<N: Num> Lookback for Aroon<N> {
+    fn lookback(&self) -> usize {
+        self.period + 1
+    }
+
+    fn is_ready(&self) -> bool {
+        self.maximum.is_ready()
+    }
+}
+
+impl<N: Num> Next<N> for Aroon<N> {
+    type Output = AroonOutput<N>;
+
+    fn next(&mut self, input: N) -> Self::Output {
+        self.maximum.next(input);
+        self.minimum.next(input);
+        self.aroon(self.maximum.age(), self.minimum.age())
+    }
+}
+
+impl_next_batch!(Aroon, N => AroonOutput<N>);
+
+impl<N: Num> Peek<N> for Aroon<N> {
+    fn peek(&self, input: N) -> Self::Output {
+        let (_, max_age) = self.maximum.peek_with_age(input);
+        let (_, min_age) = self.minimum.peek_with_age(input);
+        self.aroon(max_age, min_age)
+    }
+
+    fn update_last(&mut self, input: N) -> Self::Output {
+        self.maximum.update_last(input);
+        self.minimum.update_last(input);
+        self.aroon(self.maximum.age(), self.minimum.age())
+    }
+}
+
+impl_for_floats! {
+    impl<T: High<N> + Low<N>> Next<&T> for Aroon<N> {
+        type Output = AroonOutput<N>;
+
+        fn next(&mut self, input: &T) -> Self::Output {
+            self.maximum.next(input.high());
+            self.minimum.next(input.low());
+            self.aroon(self.maximum.age(), self.minimum.age())
+        }
+    }
+
+    impl_next_batch!(Aroon<N>, High + Low => AroonOutput<N>);
+
+    impl<T: High<N> + Low<N>> Peek<&T> for Aroon<N> {
+        fn peek(&self, input: &T) -> Self::Output {
+            let (_, max_age) = self.maximum.peek_with_age(input.high());
+            let (_, min_age) = self.minimum.peek_with_age(input.low());
+            self.aroon(max_age, min_age)
+        }
+
+        fn update_last(&mut self, input: &T) -> Self::Output {
+            self.maximum.update_last(input.high());
+            self.minimum.update_last(input.low());
+            self.aroon(self.maximum.age(), self.minimum.age())
+        }
+    }
+}
+
+impl<N: Num> Reset for Aroon<N> {
+    fn reset(&mut self) {
+        self.maximum.reset();
+        self.minimum.reset();
+    }
+}
+
+impl<N: Num> Default for Aroon<N> {
+    fn default() -> Self {
+        Self::new(25).unwrap()
+    }
+}
+
+impl<N: Num> fmt::Display for Aroon<N> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "AROON({})", self.period)
+    }
+}
+
+impl<N: Num> FromParams for Aroon<N> {
+    const NAME: &'static str = "AROON";
+
+    fn from_params(params: &[Param]) -> Result<Self> {
+        match *params {
+            [period] => Self::new(period.period()?),
+            _ => Err(TaError::InvalidParameter),
+        }
+    }
+}
+
+impl_from_str!(Aroon);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::indicators::Aroon;
+    use crate::test_helper::*;
+
+    test_indicator!(Aroon);
+
+    fn output(up: f64, down: f64, oscillator: f64) -> AroonOutput {
+        AroonOutput {
+            up,
+            down,
+            oscillator,
+        }
+    }
+
+    #[test]
+    fn test_new() {
+        assert!(Aroon::new(0).is_err());
+        assert!(Aroon::new(1).is_ok());
+    }
+
+    #[test]
+    fn test_next() {
+        let mut aroon = Aroon::new(2).unwrap();
+
+        assert_eq!(aroon.next(10.0), output(100.0, 100.0, 0.0));
+        assert_eq!(aroon.next(12.0), output(100.0, 50.0, 50.0));
+        assert_eq!(aroon.next(11.0), output(50.0, 0.0, 50.0));
+        assert_eq!(aroon.next(9.0), output(0.0, 100.0, -100.0));
+        // the latest lowest low counts
+        assert_eq!(aroon.next(9.0), output(0.0, 100.0, -100.0));
+    }
+
+    #[test]
+    fn test_next_bar() {
+        let mut aroon = Aroon::new(2).unwrap();
+
+        let bar1 = Bar::new().high(12).low(8);
+        let bar2 = Bar::new().high(13).low(10);
+        let bar3 = Bar::new().high(11).low(9);
+        let bar4 = Bar::new().high(10).low(7);
+
+        assert_eq!(aroon.next(&bar1), output(100.0, 100.0, 0.0));
+        assert_eq!(aroon.next(&bar2), output(100.0, 50.0, 50.0));
+        assert_eq!(aroon.next(&bar3), output(50.0, 0.0, 50.0));
+        assert_eq!(aroon.next(&bar4), output(0.0, 100.0, -100.0));
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut aroon = Aroon::new(2).unwrap();
+
+        assert_eq!(aroon.next(10.0), output(100.0, 100.0, 0.0));
+        assert_eq!(aroon.next(12.0), output(100.0, 50.0, 50.0));
+
+        aroon.reset();
+
+        assert_eq!(aroon.next(10.0), output(100.0, 100.0, 0.0));
+        assert_eq!(aroon.next(12.0), output(100.0, 50.0, 50.0));
+    }
+
+    #[test]
+    fn test_lookback() {
+        let mut aroon = Aroon::new(2).unwrap();
+        assert_eq!(aroon.lookback(), 3);
+
+        for _ in 0..2 {
+            aroon.next(1.0);
+            assert!(!aroon.is_ready());
+        }
+        aroon.next(1.0);
+        assert!(aroon.is_ready());
+    }
+
+    #[test]
+    fn test_default() {
+        Aroon::default();
+    }
+
+    #[test]
+    fn test_display() {
+        let aroon = Aroon::new(14).unwrap();
+        assert_eq!(format!("{}", aroon), "AROON(14)");
+    }
+}
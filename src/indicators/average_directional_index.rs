@@ -0,0 +1,470 @@
+use core::fmt;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::errors::{Result, TaError};
+use crate::indicators::generic::{TrueRange, WilderMovingAverage};
+use crate::registry::{FromParams, Param};
+use crate::{Close, High, Lookback, Low, Next, Num, Peek, Period, Reset};
+
+/// Average Directional Index (ADX), along with Plus/Minus Directional Indicators (+DI, -DI) and
+/// Directional Movement Index (DX).
+///
+/// Developed by J. Welles Wilder, ADX measures the strength of a trend regardless of its
+/// direction, while +DI and -DI tell the direction.
+///
+/// # Formula
+///
+/// +DM<sub>t</sub> = High<sub>t</sub> - High<sub>t-1</sub>, if it's greater than
+/// Low<sub>t-1</sub> - Low<sub>t</sub> and greater than 0, otherwise 0
+///
+/// -DM<sub>t</sub> = Low<sub>t-1</sub> - Low<sub>t</sub>, if it's greater than
+/// High<sub>t</sub> - High<sub>t-1</sub> and greater than 0, otherwise 0
+///
+/// +DI = 100 * Smoothed(+DM) / Smoothed(TR)
+///
+/// -DI = 100 * Smoothed(-DM) / Smoothed(TR)
+///
+/// DX = 100 * |+DI - -DI| / (+DI + -DI)
+///
+/// ADX = Smoothed(DX)
+///
+/// Where:
+///
+/// * _TR_ - [True Range](struct.TrueRange.html)
+/// * _Smoothed_ - [Wilder's moving average](struct.WilderMovingAverage.html) over _period_
+///
+/// The first period has no previous one, so it only initializes the indicator and returns zeros.
+///
+/// # Parameters
+///
+/// * _period_ - number of periods (integer greater than 0). Default is 14.
+///
+/// # Example
+///
+/// ```
+/// use ta::indicators::AverageDirectionalIndex;
+/// use ta::{Next, DataItem};
+///
+/// let mut adx = AverageDirectionalIndex::new(3).unwrap();
+///
+/// let item1 = DataItem::builder()
+///     .open(9.0).high(10.0).low(8.0).close(9.0).volume(1.0).build().unwrap();
+/// let item2 = DataItem::builder()
+///     .open(9.5).high(11.0).low(9.0).close(10.5).volume(1.0).build().unwrap();
+///
+/// adx.next(&item1);
+/// let out = adx.next(&item2);
+/// assert_eq!(out.plus_di, 50.0);
+/// assert_eq!(out.minus_di, 0.0);
+/// assert_eq!(out.dx, 100.0);
+/// ```
+///
+/// # Links
+///
+/// * [Average directional movement index, Wikipedia](https://en.wikipedia.org/wiki/Average_directional_movement_index)
+/// * [Average Directional Index (ADX), stockcharts](https://school.stockcharts.com/doku.php?id=technical_indicators:average_directional_index_adx)
+#[doc(alias = "ADX")]
+#[doc(alias = "DMI")]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone)]
+pub struct AverageDirectionalIndex<N = f64> {
+    period: usize,
+    count: usize,
+    true_range: TrueRange<N>,
+    prev_high: N,
+    prev_low: N,
+    prev_prev_high: N,
+    prev_prev_low: N,
+    smoothed_tr: WilderMovingAverage<N>,
+    smoothed_plus_dm: WilderMovingAverage<N>,
+    smoothed_minus_dm: WilderMovingAverage<N>,
+    adx: WilderMovingAverage<N>,
+}
+
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, PartialEq)]
+pub struct AverageDirectionalIndexOutput<N = f64> {
+    pub plus_di: N,
+    pub minus_di: N,
+    pub dx: N,
+    pub adx: N,
+}
+
+impl_fields!(AverageDirectionalIndexOutput {
+    plus_di,
+    minus_di,
+    dx,
+    adx
+});
+
+impl<N: Num> AverageDirectionalIndex<N> {
+    pub fn new(period: usize) -> Result<Self> {
+        match period {
+            0 => Err(TaError::InvalidParameter),
+            _ => Ok(Self {
+                period,
+                count: 0,
+                true_range: TrueRange::new(),
+                prev_high: N::zero(),
+                prev_low: N::zero(),
+                prev_prev_high: N::zero(),
+                prev_prev_low: N::zero(),
+                smoothed_tr: WilderMovingAverage::new(period)?,
+                smoothed_plus_dm: WilderMovingAverage::new(period)?,
+                smoothed_minus_dm: WilderMovingAverage::new(period)?,
+                adx: WilderMovingAverage::new(period)?,
+            }),
+        }
+    }
+
+    // Returns +DM and -DM.
+    fn directional_movement(prev_high: N, prev_low: N, high: N, low: N) -> (N, N) {
+        let up_move = high - prev_high;
+        let down_move = prev_low - low;
+
+        let plus_dm = if up_move > down_move && up_move > N::zero() {
+            up_move
+        } else {
+            N::zero()
+        };
+        let minus_dm = if down_move > up_move && down_move > N::zero() {
+            down_move
+        } else {
+            N::zero()
+        };
+        (plus_dm, minus_dm)
+    }
+
+    // Returns +DI, -DI and DX.
+    fn directional_index(smoothed_tr: N, smoothed_plus_dm: N, smoothed_minus_dm: N) -> (N, N, N) {
+        let hundred = N::from_f64(100.0);
+        let (plus_di, minus_di) = if smoothed_tr == N::zero() {
+            (N::zero(), N::zero())
+        } else {
+            (
+                hundred * smoothed_plus_dm / smoothed_tr,
+                hundred * smoothed_minus_dm / smoothed_tr,
+            )
+        };
+
+        let dx = if plus_di + minus_di == N::zero() {
+            N::zero()
+        } else {
+            hundred * (plus_di - minus_di).abs() / (plus_di + minus_di)
+        };
+        (plus_di, minus_di, dx)
+    }
+
+    fn first_output() -> AverageDirectionalIndexOutput<N> {
+        AverageDirectionalIndexOutput {
+            plus_di: N::zero(),
+            minus_di: N::zero(),
+            dx: N::zero(),
+            adx: N::zero(),
+        }
+    }
+}
+
+impl<N: Num> Period for AverageDirectionalIndex<N> {
+    fn period(&self) -> usize {
+        self.period
+    }
+}
+
+impl<N: Num> Lookback for AverageDirectionalIndex<N> {
+    fn lookback(&self) -> usize {
+        2 * self.period
+    }
+
+    fn is_ready(&self) -> bool {
+        self.count == self.lookback()
+    }
+}
+
+impl<N: Num, T: High<N> + Low<N> + Close<N>> Next<&T> for AverageDirectionalIndex<N> {
+    type Output = AverageDirectionalIndexOutput<N>;
+
+    fn next(&mut self, input: &T) -> Self::Output {
+        let high = input.high();
+        let low = input.low();
+        let tr = self.true_range.next_bar(input);
+
+        if self.count < self.lookback() {
+            self.count += 1;
+        }
+
+        if self.count == 1 {
+            self.prev_high = high;
+            self.prev_low = low;
+            return Self::first_output();
+        }
+
+        let (plus_dm, minus_dm) =
+            Self::directional_movement(self.prev_high, self.prev_low, high, low);
+        self.prev_prev_high = self.prev_high;
+        self.prev_prev_low = self.prev_low;
+        self.prev_high = high;
+        self.prev_low = low;
+
+        let (plus_di, minus_di, dx) = Self::directional_index(
+            self.smoothed_tr.next(tr),
+            self.smoothed_plus_dm.next(plus_dm),
+            self.smoothed_minus_dm.next(minus_dm),
+        );
+
+        // DX values are averaged only once +DI and -DI are warmed up.
+        let adx = if self.smoothed_tr.is_ready() {
+            self.adx.next(dx)
+        } else {
+            dx
+        };
+
+        AverageDirectionalIndexOutput {
+            plus_di,
+            minus_di,
+            dx,
+            adx,
+        }
+    }
+}
+
+impl_next_batch!(AverageDirectionalIndex, High + Low + Close => AverageDirectionalIndexOutput<N>);
+
+impl<N: Num, T: High<N> + Low<N> + Close<N>> Peek<&T> for AverageDirectionalIndex<N> {
+    fn peek(&self, input: &T) -> Self::Output {
+        let tr = self.true_range.peek_bar(input);
+        if self.count == 0 {
+            return Self::first_output();
+        }
+
+        let (plus_dm, minus_dm) =
+            Self::directional_movement(self.prev_high, self.prev_low, input.high(), input.low());
+        let (plus_di, minus_di, dx) = Self::directional_index(
+            self.smoothed_tr.peek(tr),
+            self.smoothed_plus_dm.peek(plus_dm),
+            self.smoothed_minus_dm.peek(minus_dm),
+        );
+
+        // The smoothed values consume one input less than the indicator.
+        let adx = if self.count >= self.period {
+            self.adx.peek(dx)
+        } else {
+            dx
+        };
+
+        AverageDirectionalIndexOutput {
+            plus_di,
+            minus_di,
+            dx,
+            adx,
+        }
+    }
+
+    fn update_last(&mut self, input: &T) -> Self::Output {
+        if self.count == 0 {
+            return self.next(input);
+        }
+
+        let high = input.high();
+        let low = input.low();
+        let tr = self.true_range.update_last_bar(input);
+
+        if self.count == 1 {
+            self.prev_high = high;
+            self.prev_low = low;
+            return Self::first_output();
+        }
+
+        let (plus_dm, minus_dm) =
+            Self::directional_movement(self.prev_prev_high, self.prev_prev_low, high, low);
+        self.prev_high = high;
+        self.prev_low = low;
+
+        let (plus_di, minus_di, dx) = Self::directional_index(
+            self.smoothed_tr.update_last(tr),
+            self.smoothed_plus_dm.update_last(plus_dm),
+            self.smoothed_minus_dm.update_last(minus_dm),
+        );
+
+        let adx = if self.smoothed_tr.is_ready() {
+            self.adx.update_last(dx)
+        } else {
+            dx
+        };
+
+        AverageDirectionalIndexOutput {
+            plus_di,
+            minus_di,
+            dx,
+            adx,
+        }
+    }
+}
+
+impl<N: Num> Reset for AverageDirectionalIndex<N> {
+    fn reset(&mut self) {
+        self.count = 0;
+        self.true_range.reset();
+        self.prev_high = N::zero();
+        self.prev_low = N::zero();
+        self.prev_prev_high = N::zero();
+        self.prev_prev_low = N::zero();
+        self.smoothed_tr.reset();
+        self.smoothed_plus_dm.reset();
+        self.smoothed_minus_dm.reset();
+        self.adx.reset();
+    }
+}
+
+impl<N: Num> Default for AverageDirectionalIndex<N> {
+    fn default() -> Self {
+        Self::new(14).unwrap()
+    }
+}
+
+impl<N: Num> fmt::Display for AverageDirectionalIndex<N> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "ADX({})", self.period)
+    }
+}
+
+impl<N: Num> FromParams for AverageDirectionalIndex<N> {
+    const NAME: &'static str = "ADX";
+
+    fn from_params(params: &[Param]) -> Result<Self> {
+        match *params {
+            [period] => Self::new(period.period()?),
+            _ => Err(TaError::InvalidParameter),
+        }
+    }
+}
+
+impl_from_str!(AverageDirectionalIndex);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::indicators::AverageDirectionalIndex;
+    use crate::test_helper::*;
+
+    type Adx = AverageDirectionalIndex;
+
+    #[test]
+    fn test_new() {
+        assert!(Adx::new(0).is_err());
+        assert!(Adx::new(1).is_ok());
+    }
+
+    #[test]
+    fn test_next_bar() {
+        let mut adx = Adx::new(3).unwrap();
+
+        // Expected values are calculated with Wilder's worksheet method: the first smoothed
+        // TR/DM is a sum of 3 values, the first ADX is an average of 3 DX values.
+        let test_data = vec![
+            // high, low, close, +DI, -DI, DX, ADX
+            (10.0, 8.0, 9.0, None),
+            (11.0, 9.0, 10.5, None),
+            (12.0, 10.0, 11.5, None),
+            (11.5, 9.5, 10.0, Some((33.333, 8.333, 60.0, None))),
+            (11.0, 8.5, 9.0, Some((20.513, 20.513, 0.0, None))),
+            (10.0, 8.0, 8.5, Some((14.035, 21.93, 21.951, Some(27.317)))),
+            (10.5, 8.5, 10.0, Some((17.56, 14.881, 8.257, Some(20.964)))),
+            (
+                12.0,
+                10.0,
+                11.5,
+                Some((36.245, 10.04, 56.616, Some(32.848))),
+            ),
+            (
+                13.0,
+                11.0,
+                12.5,
+                Some((40.756, 6.748, 71.591, Some(45.762))),
+            ),
+            (
+                12.5,
+                10.5,
+                11.0,
+                Some((27.318, 12.766, 36.305, Some(42.61))),
+            ),
+        ];
+
+        for (high, low, close, expected) in test_data {
+            let bar = Bar::new().high(high).low(low).close(close);
+            let out = adx.next(&bar);
+            if let Some((plus_di, minus_di, dx, expected_adx)) = expected {
+                assert_eq!(round(out.plus_di), plus_di);
+                assert_eq!(round(out.minus_di), minus_di);
+                assert_eq!(round(out.dx), dx);
+                if let Some(expected_adx) = expected_adx {
+                    assert_eq!(round(out.adx), expected_adx);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_next_bar_flat() {
+        let mut adx = Adx::new(3).unwrap();
+
+        let bar = Bar::new().high(10).low(10).close(10);
+        for _ in 0..5 {
+            let out = adx.next(&bar);
+            assert_eq!(out.plus_di, 0.0);
+            assert_eq!(out.minus_di, 0.0);
+            assert_eq!(out.dx, 0.0);
+            assert_eq!(out.adx, 0.0);
+        }
+    }
+
+    #[test]
+    fn test_lookback() {
+        let mut adx = Adx::new(3).unwrap();
+        assert_eq!(adx.lookback(), 6);
+
+        let bar = Bar::new().high(10).low(8).close(9);
+        for _ in 0..5 {
+            adx.next(&bar);
+        }
+        assert!(!adx.is_ready());
+        adx.next(&bar);
+        assert!(adx.is_ready());
+    }
+
+    #[test]
+    fn test_peek() {
+        let bars = peek_bars();
+        let bars: Vec<&Bar> = bars.iter().collect();
+        assert_peek(AverageDirectionalIndex::new(3).unwrap(), &bars);
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut adx = Adx::new(3).unwrap();
+
+        let bar1 = Bar::new().high(10).low(8).close(9);
+        let bar2 = Bar::new().high(11).low(9).close(10.5);
+
+        adx.next(&bar1);
+        let out1 = adx.next(&bar2);
+
+        adx.reset();
+
+        assert_eq!(adx.next(&bar1).adx, 0.0);
+        assert_eq!(adx.next(&bar2), out1);
+    }
+
+    #[test]
+    fn test_default() {
+        Adx::default();
+    }
+
+    #[test]
+    fn test_display() {
+        let indicator = Adx::new(14).unwrap();
+        assert_eq!(format!("{}", indicator), "ADX(14)");
+    }
+}
@@ -1,8 +1,9 @@
-use std::fmt;
+use core::fmt;
 
-use crate::errors::Result;
-use crate::indicators::{ExponentialMovingAverage, TrueRange};
-use crate::{Close, High, Low, Next, Period, Reset};
+use crate::errors::{Result, TaError};
+use crate::indicators::generic::{MovingAverage, MovingAverageType, TrueRange};
+use crate::registry::{FromParams, Param};
+use crate::{Close, High, Lookback, Low, Next, Num, Peek, Period, Reset};
 
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
@@ -11,7 +12,10 @@ use serde::{Deserialize, Serialize};
 ///
 /// A technical analysis volatility indicator, originally developed by J. Welles Wilder.
 /// The average true range is an N-day smoothed moving average of the true range values.
-/// This implementation uses exponential moving average.
+/// By default this implementation uses exponential moving average, use
+/// [`with_smoothing`](#method.with_smoothing) to choose another
+/// [moving average type](enum.MovingAverageType.html), e.g. Wilder's smoothing as in the original
+/// definition.
 ///
 /// # Formula
 ///
@@ -25,6 +29,8 @@ use serde::{Deserialize, Serialize};
 /// # Parameters
 ///
 /// * _period_ - smoothing period of EMA (integer greater than 0)
+/// * _smoothing_ - type of moving average used to smooth TR. Default is
+///   [`MovingAverageType::Exponential`](enum.MovingAverageType.html).
 ///
 /// # Example
 ///
@@ -59,64 +65,142 @@ use serde::{Deserialize, Serialize};
 #[doc(alias = "ATR")]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[derive(Debug, Clone)]
-pub struct AverageTrueRange {
-    true_range: TrueRange,
-    ema: ExponentialMovingAverage,
+pub struct AverageTrueRange<N = f64> {
+    true_range: TrueRange<N>,
+    ma: MovingAverage<N>,
 }
 
-impl AverageTrueRange {
+impl<N: Num> AverageTrueRange<N> {
     pub fn new(period: usize) -> Result<Self> {
+        Self::with_smoothing(period, MovingAverageType::Exponential)
+    }
+
+    pub fn with_smoothing(period: usize, smoothing: MovingAverageType) -> Result<Self> {
         Ok(Self {
             true_range: TrueRange::new(),
-            ema: ExponentialMovingAverage::new(period)?,
+            ma: MovingAverage::new(smoothing, period)?,
         })
     }
+
+    pub fn smoothing(&self) -> MovingAverageType {
+        self.ma.ma_type()
+    }
+
+    // The bar versions of `next`, `peek` and `update_last` for any numeric type, for the
+    // indicators, which accept only bars and use the average true range.
+    pub(super) fn next_bar<T: High<N> + Low<N> + Close<N>>(&mut self, bar: &T) -> N {
+        self.ma.next(self.true_range.next_bar(bar))
+    }
+
+    pub(super) fn peek_bar<T: High<N> + Low<N>>(&self, bar: &T) -> N {
+        self.ma.peek(self.true_range.peek_bar(bar))
+    }
+
+    pub(super) fn update_last_bar<T: High<N> + Low<N> + Close<N>>(&mut self, bar: &T) -> N {
+        self.ma.update_last(self.true_range.update_last_bar(bar))
+    }
 }
 
-impl Period for AverageTrueRange {
+impl<N: Num> Period for AverageTrueRange<N> {
     fn period(&self) -> usize {
-        self.ema.period()
+        self.ma.period()
     }
 }
 
-impl Next<f64> for AverageTrueRange {
-    type Output = f64;
+impl<N: Num> Lookback for AverageTrueRange<N> {
+    fn lookback(&self) -> usize {
+        self.ma.lookback()
+    }
+
+    fn is_ready(&self) -> bool {
+        self.ma.is_ready()
+    }
+}
+
+impl<N: Num> Next<N> for AverageTrueRange<N> {
+    type Output = N;
+
+    fn next(&mut self, input: N) -> Self::Output {
+        self.ma.next(self.true_range.next(input))
+    }
+}
+
+impl_next_batch!(AverageTrueRange, N => N);
+
+impl<N: Num> Peek<N> for AverageTrueRange<N> {
+    fn peek(&self, input: N) -> Self::Output {
+        self.ma.peek(self.true_range.peek(input))
+    }
 
-    fn next(&mut self, input: f64) -> Self::Output {
-        self.ema.next(self.true_range.next(input))
+    fn update_last(&mut self, input: N) -> Self::Output {
+        self.ma.update_last(self.true_range.update_last(input))
     }
 }
 
-impl<T: High + Low + Close> Next<&T> for AverageTrueRange {
-    type Output = f64;
+impl_for_floats! {
+    impl<T: High<N> + Low<N> + Close<N>> Next<&T> for AverageTrueRange<N> {
+        type Output = N;
+
+        fn next(&mut self, input: &T) -> Self::Output {
+            self.next_bar(input)
+        }
+    }
+
+    impl_next_batch!(AverageTrueRange<N>, High + Low + Close => N);
+
+    impl<T: High<N> + Low<N> + Close<N>> Peek<&T> for AverageTrueRange<N> {
+        fn peek(&self, input: &T) -> Self::Output {
+            self.peek_bar(input)
+        }
 
-    fn next(&mut self, input: &T) -> Self::Output {
-        self.ema.next(self.true_range.next(input))
+        fn update_last(&mut self, input: &T) -> Self::Output {
+            self.update_last_bar(input)
+        }
     }
 }
 
-impl Reset for AverageTrueRange {
+impl<N: Num> Reset for AverageTrueRange<N> {
     fn reset(&mut self) {
         self.true_range.reset();
-        self.ema.reset();
+        self.ma.reset();
     }
 }
 
-impl Default for AverageTrueRange {
+impl<N: Num> Default for AverageTrueRange<N> {
     fn default() -> Self {
         Self::new(14).unwrap()
     }
 }
 
-impl fmt::Display for AverageTrueRange {
+impl<N: Num> fmt::Display for AverageTrueRange<N> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "ATR({})", self.ema.period())
+        match self.smoothing() {
+            MovingAverageType::Exponential => write!(f, "ATR({})", self.ma.period()),
+            smoothing => write!(f, "ATR({}, {})", self.ma.period(), smoothing),
+        }
     }
 }
 
+impl<N: Num> FromParams for AverageTrueRange<N> {
+    const NAME: &'static str = "ATR";
+
+    fn from_params(params: &[Param]) -> Result<Self> {
+        match *params {
+            [period] => Self::new(period.period()?),
+            [period, smoothing] => {
+                Self::with_smoothing(period.period()?, smoothing.moving_average()?)
+            }
+            _ => Err(TaError::InvalidParameter),
+        }
+    }
+}
+
+impl_from_str!(AverageTrueRange);
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::indicators::AverageTrueRange;
     use crate::test_helper::*;
 
     test_indicator!(AverageTrueRange);
@@ -139,6 +223,22 @@ mod tests {
         assert_eq!(atr.next(&bar3), 3.375);
     }
 
+    #[test]
+    fn test_next_wilder() {
+        let mut atr = AverageTrueRange::with_smoothing(3, MovingAverageType::Wilder).unwrap();
+        assert_eq!(atr.smoothing(), MovingAverageType::Wilder);
+
+        let bar1 = Bar::new().high(10).low(7.5).close(9);
+        let bar2 = Bar::new().high(11).low(9).close(9.5);
+        let bar3 = Bar::new().high(9).low(5).close(8);
+        let bar4 = Bar::new().high(10).low(8).close(9);
+
+        assert_eq!(atr.next(&bar1), 2.5);
+        assert_eq!(atr.next(&bar2), 2.25);
+        assert_eq!(atr.next(&bar3), 3.0);
+        assert_eq!(round(atr.next(&bar4)), 2.667);
+    }
+
     #[test]
     fn test_reset() {
         let mut atr = AverageTrueRange::new(9).unwrap();
@@ -163,5 +263,7 @@ mod tests {
     fn test_display() {
         let indicator = AverageTrueRange::new(8).unwrap();
         assert_eq!(format!("{}", indicator), "ATR(8)");
+        let indicator = AverageTrueRange::with_smoothing(8, MovingAverageType::Simple).unwrap();
+        assert_eq!(format!("{}", indicator), "ATR(8, SMA)");
     }
 }
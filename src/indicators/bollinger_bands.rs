@@ -1,8 +1,9 @@
-use std::fmt;
+use core::fmt;
 
-use crate::errors::Result;
-use crate::indicators::StandardDeviation as Sd;
-use crate::{Close, Next, Period, Reset};
+use crate::errors::{Result, TaError};
+use crate::indicators::generic::{MovingAverage, MovingAverageType, StandardDeviation as Sd};
+use crate::registry::{FromParams, Param};
+use crate::{Close, Lookback, Next, Num, Peek, Period, Reset};
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
@@ -21,6 +22,10 @@ use serde::{Deserialize, Serialize};
 ///  * _BB<sub>Upper Band</sub>_ = SMA + SD of observation * multipler (usually 2.0)
 ///  * _BB<sub>Lower Band</sub>_ = SMA - SD of observation * multipler (usually 2.0)
 ///
+/// Use [`with_moving_average`](#method.with_moving_average) to replace the SMA of the middle
+/// band with another [moving average type](enum.MovingAverageType.html). SD is always
+/// calculated around the simple mean.
+///
 /// # Example
 ///
 ///```
@@ -48,84 +53,209 @@ use serde::{Deserialize, Serialize};
 #[doc(alias = "BB")]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[derive(Debug, Clone)]
-pub struct BollingerBands {
+pub struct BollingerBands<N = f64> {
     period: usize,
     multiplier: f64,
-    sd: Sd,
+    sd: Sd<N>,
+    average: Option<MovingAverage<N>>,
 }
 
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[derive(Debug, Clone, PartialEq)]
-pub struct BollingerBandsOutput {
-    pub average: f64,
-    pub upper: f64,
-    pub lower: f64,
+pub struct BollingerBandsOutput<N = f64> {
+    pub average: N,
+    pub upper: N,
+    pub lower: N,
 }
 
-impl BollingerBands {
+impl_fields!(BollingerBandsOutput {
+    average,
+    upper,
+    lower
+});
+
+impl<N: Num> BollingerBands<N> {
     pub fn new(period: usize, multiplier: f64) -> Result<Self> {
+        Self::with_moving_average(period, multiplier, MovingAverageType::Simple)
+    }
+
+    pub fn with_moving_average(
+        period: usize,
+        multiplier: f64,
+        moving_average: MovingAverageType,
+    ) -> Result<Self> {
+        // The simple mean is already known to the standard deviation.
+        let average = match moving_average {
+            MovingAverageType::Simple => None,
+            _ => Some(MovingAverage::new(moving_average, period)?),
+        };
+
         Ok(Self {
             period,
             multiplier,
             sd: Sd::new(period)?,
+            average,
         })
     }
 
     pub fn multiplier(&self) -> f64 {
         self.multiplier
     }
+
+    pub fn moving_average(&self) -> MovingAverageType {
+        match self.average {
+            Some(ref average) => average.ma_type(),
+            None => MovingAverageType::Simple,
+        }
+    }
 }
 
-impl Period for BollingerBands {
+impl<N: Num> Period for BollingerBands<N> {
     fn period(&self) -> usize {
         self.period
     }
 }
 
-impl Next<f64> for BollingerBands {
-    type Output = BollingerBandsOutput;
+impl<N: Num> Lookback for BollingerBands<N> {
+    fn lookback(&self) -> usize {
+        match self.average {
+            Some(ref average) => self.sd.lookback().max(average.lookback()),
+            None => self.sd.lookback(),
+        }
+    }
+
+    fn is_ready(&self) -> bool {
+        match self.average {
+            Some(ref average) => self.sd.is_ready() && average.is_ready(),
+            None => self.sd.is_ready(),
+        }
+    }
+}
+
+impl<N: Num> Next<N> for BollingerBands<N> {
+    type Output = BollingerBandsOutput<N>;
 
-    fn next(&mut self, input: f64) -> Self::Output {
+    fn next(&mut self, input: N) -> Self::Output {
         let sd = self.sd.next(input);
-        let mean = self.sd.mean();
+        let mean = match self.average {
+            Some(ref mut average) => average.next(input),
+            None => self.sd.mean(),
+        };
 
         Self::Output {
             average: mean,
-            upper: mean + sd * self.multiplier,
-            lower: mean - sd * self.multiplier,
+            upper: mean + sd * N::from_f64(self.multiplier),
+            lower: mean - sd * N::from_f64(self.multiplier),
         }
     }
 }
 
-impl<T: Close> Next<&T> for BollingerBands {
-    type Output = BollingerBandsOutput;
+impl_next_batch!(BollingerBands, N => BollingerBandsOutput<N>);
+
+impl<N: Num> Peek<N> for BollingerBands<N> {
+    fn peek(&self, input: N) -> Self::Output {
+        let sd = self.sd.peek(input);
+        let mean = match self.average {
+            Some(ref average) => average.peek(input),
+            None => self.sd.peek_mean(input),
+        };
+
+        Self::Output {
+            average: mean,
+            upper: mean + sd * N::from_f64(self.multiplier),
+            lower: mean - sd * N::from_f64(self.multiplier),
+        }
+    }
+
+    fn update_last(&mut self, input: N) -> Self::Output {
+        let sd = self.sd.update_last(input);
+        let mean = match self.average {
+            Some(ref mut average) => average.update_last(input),
+            None => self.sd.mean(),
+        };
+
+        Self::Output {
+            average: mean,
+            upper: mean + sd * N::from_f64(self.multiplier),
+            lower: mean - sd * N::from_f64(self.multiplier),
+        }
+    }
+}
+
+impl_for_floats! {
+    impl<T: Close<N>> Next<&T> for BollingerBands<N> {
+        type Output = BollingerBandsOutput<N>;
+
+        fn next(&mut self, input: &T) -> Self::Output {
+            self.next(input.close())
+        }
+    }
+
+    impl_next_batch!(BollingerBands<N>, Close => BollingerBandsOutput<N>);
+
+    impl<T: Close<N>> Peek<&T> for BollingerBands<N> {
+        fn peek(&self, input: &T) -> Self::Output {
+            self.peek(input.close())
+        }
 
-    fn next(&mut self, input: &T) -> Self::Output {
-        self.next(input.close())
+        fn update_last(&mut self, input: &T) -> Self::Output {
+            self.update_last(input.close())
+        }
     }
 }
 
-impl Reset for BollingerBands {
+impl<N: Num> Reset for BollingerBands<N> {
     fn reset(&mut self) {
         self.sd.reset();
+        if let Some(ref mut average) = self.average {
+            average.reset();
+        }
     }
 }
 
-impl Default for BollingerBands {
+impl<N: Num> Default for BollingerBands<N> {
     fn default() -> Self {
         Self::new(9, 2_f64).unwrap()
     }
 }
 
-impl fmt::Display for BollingerBands {
+impl<N: Num> fmt::Display for BollingerBands<N> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "BB({}, {})", self.period, self.multiplier)
+        match self.moving_average() {
+            MovingAverageType::Simple => write!(f, "BB({}, {})", self.period, self.multiplier),
+            moving_average => write!(
+                f,
+                "BB({}, {}, {})",
+                self.period, self.multiplier, moving_average
+            ),
+        }
     }
 }
 
+impl<N: Num> FromParams for BollingerBands<N> {
+    const NAME: &'static str = "BB";
+
+    fn from_params(params: &[Param]) -> Result<Self> {
+        match *params {
+            [period, multiplier] => Self::new(period.period()?, multiplier.number()?),
+            [period, multiplier, moving_average] => Self::with_moving_average(
+                period.period()?,
+                multiplier.number()?,
+                moving_average.moving_average()?,
+            ),
+            _ => Err(TaError::InvalidParameter),
+        }
+    }
+}
+
+impl_from_str!(BollingerBands);
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::indicators::BollingerBands;
     use crate::test_helper::*;
+    use crate::Fields;
 
     test_indicator!(BollingerBands);
 
@@ -161,6 +291,52 @@ mod tests {
         assert_eq!(round(d.lower), -0.395);
     }
 
+    #[test]
+    fn test_output_fields() {
+        let output = BollingerBandsOutput {
+            average: 2.0,
+            upper: 3.0,
+            lower: 1.0,
+        };
+
+        assert_eq!(
+            BollingerBandsOutput::<f64>::NAMES,
+            ["average", "upper", "lower"]
+        );
+        assert_eq!(output.values(), vec![2.0, 3.0, 1.0]);
+        assert_eq!(
+            output.fields(),
+            vec![("average", 2.0), ("upper", 3.0), ("lower", 1.0)]
+        );
+        assert_eq!(output.get("lower"), Some(1.0));
+        assert_eq!(output.get("middle"), None);
+        assert_eq!(<[f64; 3]>::from(output), [2.0, 3.0, 1.0]);
+    }
+
+    #[test]
+    fn test_next_ema() {
+        let mut bb =
+            BollingerBands::with_moving_average(3, 2.0_f64, MovingAverageType::Exponential)
+                .unwrap();
+        assert_eq!(bb.moving_average(), MovingAverageType::Exponential);
+
+        let a = bb.next(2.0);
+        let b = bb.next(5.0);
+        let c = bb.next(1.0);
+        let d = bb.next(6.25);
+
+        assert_eq!(round(a.average), 2.0);
+        assert_eq!(round(b.average), 3.5);
+        assert_eq!(round(c.average), 2.25);
+        assert_eq!(round(d.average), 4.25);
+
+        assert_eq!(round(c.upper), 5.649);
+        assert_eq!(round(d.upper), 8.728);
+
+        assert_eq!(round(c.lower), -1.149);
+        assert_eq!(round(d.lower), -0.228);
+    }
+
     #[test]
     fn test_reset() {
         let mut bb = BollingerBands::new(5, 2.0_f64).unwrap();
@@ -197,5 +373,8 @@ mod tests {
     fn test_display() {
         let bb = BollingerBands::new(10, 3.0_f64).unwrap();
         assert_eq!(format!("{}", bb), "BB(10, 3)");
+        let bb =
+            BollingerBands::with_moving_average(10, 3.0_f64, MovingAverageType::Weighted).unwrap();
+        assert_eq!(format!("{}", bb), "BB(10, 3, WMA)");
     }
 }
@@ -0,0 +1,253 @@
+use core::fmt;
+
+use crate::errors::{Result, TaError};
+use crate::indicators::generic::AccumulationDistribution;
+use crate::indicators::volume_weighted_window::VolumeWeightedWindow;
+use crate::registry::{FromParams, Param};
+use crate::{Close, High, Lookback, Low, Next, Num, Peek, Period, Reset, Volume};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Chaikin Money Flow (CMF).
+///
+/// The sum of the money flow volume over the last _period_ bars divided by the sum of the
+/// volume over the same bars. It oscillates between -1 and 1: positive values show buying
+/// pressure, negative values show selling pressure.
+///
+/// # Formula
+///
+/// CLV = ((close - low) - (high - close)) / (high - low)
+///
+/// CMF = Σ(CLV × volume) / Σ(volume)
+///
+/// Where the sums are taken over the last _period_ bars. When high equals low, CLV is 0.
+/// When the total volume of the window is 0, CMF is 0.
+///
+/// # Parameters
+///
+/// * _period_ - number of periods (integer greater than 0). Default is 20.
+///
+/// # Example
+///
+/// ```
+/// use ta::indicators::ChaikinMoneyFlow;
+/// use ta::{DataItem, Next};
+///
+/// let mut cmf = ChaikinMoneyFlow::new(2).unwrap();
+///
+/// let di1 = DataItem::builder()
+///     .high(10.0)
+///     .low(8.0)
+///     .close(9.5)
+///     .open(9.0)
+///     .volume(100.0)
+///     .build()
+///     .unwrap();
+///
+/// let di2 = DataItem::builder()
+///     .high(12.0)
+///     .low(9.0)
+///     .close(9.0)
+///     .open(11.0)
+///     .volume(200.0)
+///     .build()
+///     .unwrap();
+///
+/// assert_eq!(cmf.next(&di1), 0.5);
+/// assert_eq!(cmf.next(&di2), -0.5);
+/// ```
+///
+/// # Links
+///
+/// * [Chaikin Money Flow, stockcharts](https://school.stockcharts.com/doku.php?id=technical_indicators:chaikin_money_flow_cmf)
+///
+#[doc(alias = "CMF")]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone)]
+pub struct ChaikinMoneyFlow<N = f64> {
+    window: VolumeWeightedWindow<N>,
+}
+
+impl<N: Num> ChaikinMoneyFlow<N> {
+    pub fn new(period: usize) -> Result<Self> {
+        Ok(Self {
+            window: VolumeWeightedWindow::new(period)?,
+        })
+    }
+}
+
+impl<N: Num> Period for ChaikinMoneyFlow<N> {
+    fn period(&self) -> usize {
+        self.window.period()
+    }
+}
+
+impl<N: Num> Lookback for ChaikinMoneyFlow<N> {
+    fn lookback(&self) -> usize {
+        self.window.period()
+    }
+
+    fn is_ready(&self) -> bool {
+        self.window.count() == self.window.period()
+    }
+}
+
+// CMF is the volume weighted average of the close location values.
+impl<N: Num, T: High<N> + Low<N> + Close<N> + Volume<N>> Next<&T> for ChaikinMoneyFlow<N> {
+    type Output = N;
+
+    fn next(&mut self, input: &T) -> Self::Output {
+        let clv = AccumulationDistribution::close_location_value(input);
+        self.window.next(clv, input.volume()).unwrap_or(N::zero())
+    }
+}
+
+impl_next_batch!(ChaikinMoneyFlow, High + Low + Close + Volume => N);
+
+impl<N: Num, T: High<N> + Low<N> + Close<N> + Volume<N>> Peek<&T> for ChaikinMoneyFlow<N> {
+    fn peek(&self, input: &T) -> Self::Output {
+        let clv = AccumulationDistribution::close_location_value(input);
+        self.window.peek(clv, input.volume()).unwrap_or(N::zero())
+    }
+
+    fn update_last(&mut self, input: &T) -> Self::Output {
+        if self.window.count() == 0 {
+            return self.next(input);
+        }
+
+        let clv = AccumulationDistribution::close_location_value(input);
+        self.window
+            .update_last(clv, input.volume())
+            .unwrap_or(N::zero())
+    }
+}
+
+impl<N: Num> Reset for ChaikinMoneyFlow<N> {
+    fn reset(&mut self) {
+        self.window.reset();
+    }
+}
+
+impl<N: Num> Default for ChaikinMoneyFlow<N> {
+    fn default() -> Self {
+        Self::new(20).unwrap()
+    }
+}
+
+impl<N: Num> fmt::Display for ChaikinMoneyFlow<N> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "CMF({})", self.period())
+    }
+}
+
+impl<N: Num> FromParams for ChaikinMoneyFlow<N> {
+    const NAME: &'static str = "CMF";
+
+    fn from_params(params: &[Param]) -> Result<Self> {
+        match *params {
+            [period] => Self::new(period.period()?),
+            _ => Err(TaError::InvalidParameter),
+        }
+    }
+}
+
+impl_from_str!(ChaikinMoneyFlow);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::indicators::ChaikinMoneyFlow;
+    use crate::test_helper::*;
+
+    #[test]
+    fn test_new() {
+        assert!(ChaikinMoneyFlow::new(0).is_err());
+        assert!(ChaikinMoneyFlow::new(1).is_ok());
+    }
+
+    #[test]
+    fn test_next_bar() {
+        let mut cmf = ChaikinMoneyFlow::new(2).unwrap();
+
+        let bar1 = Bar::new().high(10).low(8).close(9.5).volume(100.0);
+        let bar2 = Bar::new().high(12).low(9).close(9).volume(200.0);
+        let bar3 = Bar::new().high(11).low(11).close(11).volume(500.0);
+        let bar4 = Bar::new().high(13).low(10).close(12).volume(300.0);
+        let bar5 = Bar::new().high(13).low(13).close(13).volume(0.0);
+
+        assert_eq!(cmf.next(&bar1), 0.5);
+        assert_eq!(cmf.next(&bar2), -0.5);
+
+        // high == low
+        assert_eq!(round(cmf.next(&bar3)), -0.286);
+
+        assert_eq!(cmf.next(&bar4), 0.125);
+        assert_eq!(cmf.next(&bar5), 1.0 / 3.0);
+
+        // zero volume
+        assert_eq!(cmf.next(&bar5), 0.0);
+    }
+
+    #[test]
+    fn test_next_no_volume_after_rounding_errors() {
+        let mut cmf = ChaikinMoneyFlow::new(2).unwrap();
+
+        cmf.next(&Bar::new().high(10).low(8).close(9.5).volume(0.1));
+        cmf.next(&Bar::new().high(12).low(9).close(9).volume(0.2));
+        cmf.next(&Bar::new().high(11).low(10).close(11).volume(0.0));
+        // the running sum of the volume isn't exactly 0 here
+        let bar = Bar::new().high(13).low(10).close(12).volume(0.0);
+        assert_eq!(cmf.next(&bar), 0.0);
+        assert_eq!(cmf.peek(&bar), 0.0);
+        assert_eq!(cmf.update_last(&bar), 0.0);
+    }
+
+    #[test]
+    fn test_peek() {
+        let bars = peek_bars();
+        let bars: Vec<&Bar> = bars.iter().collect();
+        assert_peek(ChaikinMoneyFlow::new(3).unwrap(), &bars);
+        assert_peek(ChaikinMoneyFlow::new(1).unwrap(), &bars);
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut cmf = ChaikinMoneyFlow::new(2).unwrap();
+
+        let bar1 = Bar::new().high(10).low(8).close(9.5).volume(100.0);
+        let bar2 = Bar::new().high(12).low(9).close(9).volume(200.0);
+
+        assert_eq!(cmf.next(&bar1), 0.5);
+        assert_eq!(cmf.next(&bar2), -0.5);
+
+        cmf.reset();
+
+        assert_eq!(cmf.next(&bar2), -1.0);
+    }
+
+    #[test]
+    fn test_lookback() {
+        let mut cmf = ChaikinMoneyFlow::new(2).unwrap();
+        assert_eq!(cmf.lookback(), 2);
+
+        let bar = Bar::new().high(2).low(1).close(1.5).volume(10.0);
+        cmf.next(&bar);
+        assert!(!cmf.is_ready());
+        cmf.next(&bar);
+        assert!(cmf.is_ready());
+
+        cmf.reset();
+        assert!(!cmf.is_ready());
+    }
+
+    #[test]
+    fn test_default() {
+        ChaikinMoneyFlow::default();
+    }
+
+    #[test]
+    fn test_display() {
+        let cmf = ChaikinMoneyFlow::new(21).unwrap();
+        assert_eq!(format!("{}", cmf), "CMF(21)");
+    }
+}
@@ -0,0 +1,281 @@
+use core::fmt;
+
+use crate::errors::{Result, TaError};
+use crate::indicators::generic::{AccumulationDistribution, MovingAverage, MovingAverageType};
+use crate::registry::{FromParams, Param};
+use crate::{Close, High, Lookback, Low, Next, Num, Peek, Period, Reset, Volume};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Chaikin Oscillator.
+///
+/// The difference between a fast and a slow exponential moving average of the
+/// [Accumulation/Distribution line](struct.AccumulationDistribution.html). It measures the
+/// momentum of the A/D line.
+///
+/// # Formula
+///
+/// CO = EMA<sub>fast</sub>(A/D) - EMA<sub>slow</sub>(A/D)
+///
+/// # Parameters
+///
+/// * _fast_period_ - period for the fast EMA. Default is 3.
+/// * _slow_period_ - period for the slow EMA. Default is 10.
+/// * _moving_average_ - type of the fast and slow moving averages. Default is
+///   [`MovingAverageType::Exponential`](enum.MovingAverageType.html), see
+///   [`with_moving_average`](#method.with_moving_average).
+///
+/// # Example
+///
+/// ```
+/// use ta::indicators::ChaikinOscillator;
+/// use ta::{DataItem, Next};
+///
+/// let mut co = ChaikinOscillator::new(2, 3).unwrap();
+///
+/// let di1 = DataItem::builder()
+///     .high(10.0)
+///     .low(8.0)
+///     .close(9.5)
+///     .open(9.0)
+///     .volume(100.0)
+///     .build()
+///     .unwrap();
+///
+/// let di2 = DataItem::builder()
+///     .high(12.0)
+///     .low(9.0)
+///     .close(9.0)
+///     .open(11.0)
+///     .volume(200.0)
+///     .build()
+///     .unwrap();
+///
+/// assert_eq!(co.next(&di1), 0.0);
+/// assert_eq!(co.next(&di2).round(), -33.0);
+/// ```
+///
+/// # Links
+///
+/// * [Chaikin Oscillator, stockcharts](https://school.stockcharts.com/doku.php?id=technical_indicators:chaikin_oscillator)
+///
+#[doc(alias = "CO")]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone)]
+pub struct ChaikinOscillator<N = f64> {
+    ad: AccumulationDistribution<N>,
+    fast_ma: MovingAverage<N>,
+    slow_ma: MovingAverage<N>,
+    count: usize,
+}
+
+impl<N: Num> ChaikinOscillator<N> {
+    pub fn new(fast_period: usize, slow_period: usize) -> Result<Self> {
+        Self::with_moving_average(fast_period, slow_period, MovingAverageType::Exponential)
+    }
+
+    pub fn with_moving_average(
+        fast_period: usize,
+        slow_period: usize,
+        moving_average: MovingAverageType,
+    ) -> Result<Self> {
+        Ok(Self {
+            ad: AccumulationDistribution::new(),
+            fast_ma: MovingAverage::new(moving_average, fast_period)?,
+            slow_ma: MovingAverage::new(moving_average, slow_period)?,
+            count: 0,
+        })
+    }
+
+    pub fn moving_average(&self) -> MovingAverageType {
+        self.fast_ma.ma_type()
+    }
+}
+
+impl<N: Num> Lookback for ChaikinOscillator<N> {
+    fn lookback(&self) -> usize {
+        self.fast_ma.lookback().max(self.slow_ma.lookback())
+    }
+
+    fn is_ready(&self) -> bool {
+        self.count == self.lookback()
+    }
+}
+
+impl<N: Num, T: High<N> + Low<N> + Close<N> + Volume<N>> Next<&T> for ChaikinOscillator<N> {
+    type Output = N;
+
+    fn next(&mut self, input: &T) -> Self::Output {
+        let ad = self.ad.next(input);
+        if self.count < self.lookback() {
+            self.count += 1;
+        }
+
+        self.fast_ma.next(ad) - self.slow_ma.next(ad)
+    }
+}
+
+impl_next_batch!(ChaikinOscillator, High + Low + Close + Volume => N);
+
+impl<N: Num, T: High<N> + Low<N> + Close<N> + Volume<N>> Peek<&T> for ChaikinOscillator<N> {
+    fn peek(&self, input: &T) -> Self::Output {
+        let ad = self.ad.peek(input);
+        self.fast_ma.peek(ad) - self.slow_ma.peek(ad)
+    }
+
+    fn update_last(&mut self, input: &T) -> Self::Output {
+        if self.count == 0 {
+            return self.next(input);
+        }
+
+        let ad = self.ad.update_last(input);
+        self.fast_ma.update_last(ad) - self.slow_ma.update_last(ad)
+    }
+}
+
+impl<N: Num> Reset for ChaikinOscillator<N> {
+    fn reset(&mut self) {
+        self.ad.reset();
+        self.fast_ma.reset();
+        self.slow_ma.reset();
+        self.count = 0;
+    }
+}
+
+impl<N: Num> Default for ChaikinOscillator<N> {
+    fn default() -> Self {
+        Self::new(3, 10).unwrap()
+    }
+}
+
+impl<N: Num> fmt::Display for ChaikinOscillator<N> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self.moving_average() {
+            MovingAverageType::Exponential => write!(
+                f,
+                "CO({}, {})",
+                self.fast_ma.period(),
+                self.slow_ma.period()
+            ),
+            moving_average => write!(
+                f,
+                "CO({}, {}, {})",
+                self.fast_ma.period(),
+                self.slow_ma.period(),
+                moving_average
+            ),
+        }
+    }
+}
+
+impl<N: Num> FromParams for ChaikinOscillator<N> {
+    const NAME: &'static str = "CO";
+
+    fn from_params(params: &[Param]) -> Result<Self> {
+        match *params {
+            [fast_period, slow_period] => Self::new(fast_period.period()?, slow_period.period()?),
+            [fast_period, slow_period, moving_average] => Self::with_moving_average(
+                fast_period.period()?,
+                slow_period.period()?,
+                moving_average.moving_average()?,
+            ),
+            _ => Err(TaError::InvalidParameter),
+        }
+    }
+}
+
+impl_from_str!(ChaikinOscillator);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::indicators::ChaikinOscillator;
+    use crate::test_helper::*;
+
+    fn bars() -> Vec<Bar> {
+        vec![
+            Bar::new().high(10).low(8).close(9.5).volume(100.0),
+            Bar::new().high(12).low(9).close(9).volume(200.0),
+            // high == low
+            Bar::new().high(11).low(11).close(11).volume(500.0),
+            Bar::new().high(13).low(10).close(12).volume(300.0),
+        ]
+    }
+
+    #[test]
+    fn test_new() {
+        assert!(ChaikinOscillator::new(0, 1).is_err());
+        assert!(ChaikinOscillator::new(1, 0).is_err());
+        assert!(ChaikinOscillator::new(1, 1).is_ok());
+    }
+
+    #[test]
+    fn test_next_bar() {
+        let mut co = ChaikinOscillator::new(2, 3).unwrap();
+        let outputs: Vec<f64> = bars().iter().map(|bar| round(co.next(bar))).collect();
+
+        // A/D: 50, -150, -150, -50
+        assert_eq!(outputs, vec![0.0, -33.333, -27.778, -0.926]);
+    }
+
+    #[test]
+    fn test_next_sma() {
+        let mut co =
+            ChaikinOscillator::with_moving_average(1, 2, MovingAverageType::Simple).unwrap();
+        assert_eq!(co.moving_average(), MovingAverageType::Simple);
+
+        let outputs: Vec<f64> = bars().iter().map(|bar| co.next(bar)).collect();
+        assert_eq!(outputs, vec![0.0, -100.0, 0.0, 50.0]);
+    }
+
+    #[test]
+    fn test_peek() {
+        let bars = peek_bars();
+        let bars: Vec<&Bar> = bars.iter().collect();
+        assert_peek(ChaikinOscillator::new(3, 10).unwrap(), &bars);
+        assert_peek(
+            ChaikinOscillator::with_moving_average(2, 4, MovingAverageType::Simple).unwrap(),
+            &bars,
+        );
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut co = ChaikinOscillator::new(2, 3).unwrap();
+        let bars = bars();
+
+        co.next(&bars[0]);
+        assert_eq!(round(co.next(&bars[1])), -33.333);
+
+        co.reset();
+
+        assert_eq!(co.next(&bars[0]), 0.0);
+        assert_eq!(round(co.next(&bars[1])), -33.333);
+    }
+
+    #[test]
+    fn test_lookback() {
+        let mut co = ChaikinOscillator::new(3, 2).unwrap();
+        assert_eq!(co.lookback(), 3);
+
+        for bar in bars().iter().take(2) {
+            co.next(bar);
+            assert!(!co.is_ready());
+        }
+        co.next(&bars()[2]);
+        assert!(co.is_ready());
+    }
+
+    #[test]
+    fn test_default() {
+        ChaikinOscillator::default();
+    }
+
+    #[test]
+    fn test_display() {
+        let co = ChaikinOscillator::new(3, 10).unwrap();
+        assert_eq!(format!("{}", co), "CO(3, 10)");
+        let co = ChaikinOscillator::with_moving_average(3, 10, MovingAverageType::Simple).unwrap();
+        assert_eq!(format!("{}", co), "CO(3, 10, SMA)");
+    }
+}
@@ -1,11 +1,12 @@
-use std::fmt;
+use core::fmt;
 
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
-use crate::errors::Result;
-use crate::indicators::{AverageTrueRange, Maximum, Minimum};
-use crate::{Close, High, Low, Next, Period, Reset};
+use crate::errors::{Result, TaError};
+use crate::indicators::generic::{AverageTrueRange, Maximum, Minimum, MovingAverageType};
+use crate::registry::{FromParams, Param};
+use crate::{Close, High, Lookback, Low, Next, Num, Peek, Period, Reset};
 
 /// Chandelier Exit (CE).
 ///
@@ -23,6 +24,9 @@ use crate::{Close, High, Low, Next, Period, Reset};
 ///
 /// * _period_ - number of periods (integer greater than 0). Default is 22.
 /// * _multipler_ - ATR factor. Default is 3.
+/// * _smoothing_ - type of moving average used by the ATR. Default is
+///   [`MovingAverageType::Exponential`](enum.MovingAverageType.html), see
+///   [`with_smoothing`](#method.with_smoothing).
 ///
 /// # Example
 ///
@@ -53,17 +57,25 @@ use crate::{Close, High, Low, Next, Period, Reset};
 #[doc(alias = "CE")]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[derive(Debug, Clone)]
-pub struct ChandelierExit {
-    atr: AverageTrueRange,
-    min: Minimum,
-    max: Maximum,
+pub struct ChandelierExit<N = f64> {
+    atr: AverageTrueRange<N>,
+    min: Minimum<N>,
+    max: Maximum<N>,
     multiplier: f64,
 }
 
-impl ChandelierExit {
+impl<N: Num> ChandelierExit<N> {
     pub fn new(period: usize, multiplier: f64) -> Result<Self> {
+        Self::with_smoothing(period, multiplier, MovingAverageType::Exponential)
+    }
+
+    pub fn with_smoothing(
+        period: usize,
+        multiplier: f64,
+        smoothing: MovingAverageType,
+    ) -> Result<Self> {
         Ok(Self {
-            atr: AverageTrueRange::new(period)?,
+            atr: AverageTrueRange::with_smoothing(period, smoothing)?,
             min: Minimum::new(period)?,
             max: Maximum::new(period)?,
             multiplier,
@@ -73,33 +85,50 @@ impl ChandelierExit {
     pub fn multiplier(&self) -> f64 {
         self.multiplier
     }
+
+    pub fn smoothing(&self) -> MovingAverageType {
+        self.atr.smoothing()
+    }
 }
 
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[derive(Debug, Clone, PartialEq)]
-pub struct ChandelierExitOutput {
-    pub long: f64,
-    pub short: f64,
+pub struct ChandelierExitOutput<N = f64> {
+    pub long: N,
+    pub short: N,
 }
 
-impl From<ChandelierExitOutput> for (f64, f64) {
-    fn from(ce: ChandelierExitOutput) -> Self {
+impl_fields!(ChandelierExitOutput { long, short });
+
+impl<N> From<ChandelierExitOutput<N>> for (N, N) {
+    fn from(ce: ChandelierExitOutput<N>) -> Self {
         (ce.long, ce.short)
     }
 }
 
-impl Period for ChandelierExit {
+impl<N: Num> Period for ChandelierExit<N> {
     fn period(&self) -> usize {
         self.atr.period()
     }
 }
 
-impl<T: Low + High + Close> Next<&T> for ChandelierExit {
-    type Output = ChandelierExitOutput;
+impl<N: Num> Lookback for ChandelierExit<N> {
+    fn lookback(&self) -> usize {
+        self.atr.lookback()
+    }
+
+    fn is_ready(&self) -> bool {
+        self.atr.is_ready()
+    }
+}
+
+impl<N: Num, T: Low<N> + High<N> + Close<N>> Next<&T> for ChandelierExit<N> {
+    type Output = ChandelierExitOutput<N>;
 
     fn next(&mut self, input: &T) -> Self::Output {
-        let atr = self.atr.next(input) * self.multiplier;
-        let min = self.min.next(input);
-        let max = self.max.next(input);
+        let atr = self.atr.next_bar(input) * N::from_f64(self.multiplier);
+        let min = self.min.next(input.low());
+        let max = self.max.next(input.high());
 
         ChandelierExitOutput {
             long: max - atr,
@@ -108,7 +137,33 @@ impl<T: Low + High + Close> Next<&T> for ChandelierExit {
     }
 }
 
-impl Reset for ChandelierExit {
+impl_next_batch!(ChandelierExit, Low + High + Close => ChandelierExitOutput<N>);
+
+impl<N: Num, T: Low<N> + High<N> + Close<N>> Peek<&T> for ChandelierExit<N> {
+    fn peek(&self, input: &T) -> Self::Output {
+        let atr = self.atr.peek_bar(input) * N::from_f64(self.multiplier);
+        let min = self.min.peek(input.low());
+        let max = self.max.peek(input.high());
+
+        ChandelierExitOutput {
+            long: max - atr,
+            short: min + atr,
+        }
+    }
+
+    fn update_last(&mut self, input: &T) -> Self::Output {
+        let atr = self.atr.update_last_bar(input) * N::from_f64(self.multiplier);
+        let min = self.min.update_last(input.low());
+        let max = self.max.update_last(input.high());
+
+        ChandelierExitOutput {
+            long: max - atr,
+            short: min + atr,
+        }
+    }
+}
+
+impl<N: Num> Reset for ChandelierExit<N> {
     fn reset(&mut self) {
         self.atr.reset();
         self.min.reset();
@@ -116,23 +171,53 @@ impl Reset for ChandelierExit {
     }
 }
 
-impl Default for ChandelierExit {
+impl<N: Num> Default for ChandelierExit<N> {
     fn default() -> Self {
         Self::new(22, 3.0).unwrap()
     }
 }
 
-impl fmt::Display for ChandelierExit {
+impl<N: Num> fmt::Display for ChandelierExit<N> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "CE({}, {})", self.atr.period(), self.multiplier)
+        match self.smoothing() {
+            MovingAverageType::Exponential => {
+                write!(f, "CE({}, {})", self.atr.period(), self.multiplier)
+            }
+            smoothing => write!(
+                f,
+                "CE({}, {}, {})",
+                self.atr.period(),
+                self.multiplier,
+                smoothing
+            ),
+        }
+    }
+}
+
+impl<N: Num> FromParams for ChandelierExit<N> {
+    const NAME: &'static str = "CE";
+
+    fn from_params(params: &[Param]) -> Result<Self> {
+        match *params {
+            [period, multiplier] => Self::new(period.period()?, multiplier.number()?),
+            [period, multiplier, smoothing] => Self::with_smoothing(
+                period.period()?,
+                multiplier.number()?,
+                smoothing.moving_average()?,
+            ),
+            _ => Err(TaError::InvalidParameter),
+        }
     }
 }
 
+impl_from_str!(ChandelierExit);
+
 #[cfg(test)]
 mod tests {
     use crate::test_helper::*;
 
     use super::*;
+    use crate::indicators::ChandelierExit;
 
     type Ce = ChandelierExit;
 
@@ -172,6 +257,30 @@ mod tests {
         assert_eq!(round(ce.next(&bar6).into()), (2.92, 7.08));
     }
 
+    #[test]
+    fn test_next_bar_wilder() {
+        let mut ce = Ce::with_smoothing(3, 2.0, MovingAverageType::Wilder).unwrap();
+        assert_eq!(ce.smoothing(), MovingAverageType::Wilder);
+
+        let bar1 = Bar::new().high(2).low(1).close(1.5);
+        assert_eq!(round(ce.next(&bar1).into()), (0.0, 3.0));
+
+        // tr = 3.5, atr = 2.25
+        let bar2 = Bar::new().high(5).low(3).close(4);
+        assert_eq!(round(ce.next(&bar2).into()), (0.5, 5.5));
+
+        // tr = 5, atr = 3.167
+        let bar3 = Bar::new().high(9).low(7).close(8);
+        assert_eq!(round(ce.next(&bar3).into()), (2.67, 7.33));
+    }
+
+    #[test]
+    fn test_peek() {
+        let bars = peek_bars();
+        let bars: Vec<&Bar> = bars.iter().collect();
+        assert_peek(ChandelierExit::new(5, 2.0).unwrap(), &bars);
+    }
+
     #[test]
     fn test_reset() {
         let mut ce = Ce::new(5, 2.0).unwrap();
@@ -188,6 +297,19 @@ mod tests {
         assert_eq!(round(ce.next(&bar2).into()), (1.33, 4.67));
     }
 
+    #[test]
+    fn test_lookback() {
+        let mut ce = Ce::new(3, 2.0).unwrap();
+        assert_eq!(ce.lookback(), 3);
+
+        let bar = Bar::new().high(2).low(1).close(1.5);
+        ce.next(&bar);
+        ce.next(&bar);
+        assert!(!ce.is_ready());
+        ce.next(&bar);
+        assert!(ce.is_ready());
+    }
+
     #[test]
     fn test_default() {
         Ce::default();
@@ -197,5 +319,7 @@ mod tests {
     fn test_display() {
         let indicator = Ce::new(10, 5.0).unwrap();
         assert_eq!(format!("{}", indicator), "CE(10, 5)");
+        let indicator = Ce::with_smoothing(10, 5.0, MovingAverageType::Wilder).unwrap();
+        assert_eq!(format!("{}", indicator), "CE(10, 5, RMA)");
     }
 }
@@ -1,11 +1,12 @@
-use std::fmt;
+use core::fmt;
 
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
-use crate::errors::Result;
-use crate::indicators::{MeanAbsoluteDeviation, SimpleMovingAverage};
-use crate::{Close, High, Low, Next, Period, Reset};
+use crate::errors::{Result, TaError};
+use crate::indicators::generic::{MeanAbsoluteDeviation, SimpleMovingAverage};
+use crate::registry::{FromParams, Param};
+use crate::{Close, High, Lookback, Low, Next, Num, Peek, Period, Reset};
 
 /// Commodity Channel Index (CCI)
 ///
@@ -30,12 +31,12 @@ use crate::{Close, High, Low, Next, Period, Reset};
 ///
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[derive(Debug, Clone)]
-pub struct CommodityChannelIndex {
-    sma: SimpleMovingAverage,
-    mad: MeanAbsoluteDeviation,
+pub struct CommodityChannelIndex<N = f64> {
+    sma: SimpleMovingAverage<N>,
+    mad: MeanAbsoluteDeviation<N>,
 }
 
-impl CommodityChannelIndex {
+impl<N: Num> CommodityChannelIndex<N> {
     pub fn new(period: usize) -> Result<Self> {
         Ok(Self {
             sma: SimpleMovingAverage::new(period)?,
@@ -44,50 +45,102 @@ impl CommodityChannelIndex {
     }
 }
 
-impl Period for CommodityChannelIndex {
+impl<N: Num> Period for CommodityChannelIndex<N> {
     fn period(&self) -> usize {
         self.sma.period()
     }
 }
 
-impl<T: Close + High + Low> Next<&T> for CommodityChannelIndex {
-    type Output = f64;
+impl<N: Num> Lookback for CommodityChannelIndex<N> {
+    fn lookback(&self) -> usize {
+        self.sma.lookback()
+    }
+
+    fn is_ready(&self) -> bool {
+        self.sma.is_ready()
+    }
+}
+
+impl<N: Num, T: Close<N> + High<N> + Low<N>> Next<&T> for CommodityChannelIndex<N> {
+    type Output = N;
 
     fn next(&mut self, input: &T) -> Self::Output {
-        let tp = (input.close() + input.high() + input.low()) / 3.0;
+        let tp = (input.close() + input.high() + input.low()) / N::from_f64(3.0);
         let sma = self.sma.next(tp);
-        let mad = self.mad.next(input);
+        let mad = self.mad.next(input.close());
 
-        if mad == 0.0 {
-            return 0.0;
+        if mad == N::zero() {
+            return N::zero();
         }
 
-        (tp - sma) / (mad * 0.015)
+        (tp - sma) / (mad * N::from_f64(0.015))
     }
 }
 
-impl Reset for CommodityChannelIndex {
+impl_next_batch!(CommodityChannelIndex, Close + High + Low => N);
+
+impl<N: Num, T: Close<N> + High<N> + Low<N>> Peek<&T> for CommodityChannelIndex<N> {
+    fn peek(&self, input: &T) -> Self::Output {
+        let tp = (input.close() + input.high() + input.low()) / N::from_f64(3.0);
+        let sma = self.sma.peek(tp);
+        let mad = self.mad.peek(input.close());
+
+        if mad == N::zero() {
+            return N::zero();
+        }
+
+        (tp - sma) / (mad * N::from_f64(0.015))
+    }
+
+    fn update_last(&mut self, input: &T) -> Self::Output {
+        let tp = (input.close() + input.high() + input.low()) / N::from_f64(3.0);
+        let sma = self.sma.update_last(tp);
+        let mad = self.mad.update_last(input.close());
+
+        if mad == N::zero() {
+            return N::zero();
+        }
+
+        (tp - sma) / (mad * N::from_f64(0.015))
+    }
+}
+
+impl<N: Num> Reset for CommodityChannelIndex<N> {
     fn reset(&mut self) {
         self.sma.reset();
         self.mad.reset();
     }
 }
 
-impl Default for CommodityChannelIndex {
+impl<N: Num> Default for CommodityChannelIndex<N> {
     fn default() -> Self {
         Self::new(20).unwrap()
     }
 }
 
-impl fmt::Display for CommodityChannelIndex {
+impl<N: Num> fmt::Display for CommodityChannelIndex<N> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         write!(f, "CCI({})", self.sma.period())
     }
 }
 
+impl<N: Num> FromParams for CommodityChannelIndex<N> {
+    const NAME: &'static str = "CCI";
+
+    fn from_params(params: &[Param]) -> Result<Self> {
+        match *params {
+            [period] => Self::new(period.period()?),
+            _ => Err(TaError::InvalidParameter),
+        }
+    }
+}
+
+impl_from_str!(CommodityChannelIndex);
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::indicators::CommodityChannelIndex;
     use crate::test_helper::*;
 
     #[test]
@@ -119,6 +172,13 @@ mod tests {
         assert_eq!(round(cci.next(&bar6)), -126.126);
     }
 
+    #[test]
+    fn test_peek() {
+        let bars = peek_bars();
+        let bars: Vec<&Bar> = bars.iter().collect();
+        assert_peek(CommodityChannelIndex::new(5).unwrap(), &bars);
+    }
+
     #[test]
     fn test_reset() {
         let mut cci = CommodityChannelIndex::new(5).unwrap();
@@ -135,6 +195,19 @@ mod tests {
         assert_eq!(round(cci.next(&bar2)), 66.667);
     }
 
+    #[test]
+    fn test_lookback() {
+        let mut cci = CommodityChannelIndex::new(3).unwrap();
+        assert_eq!(cci.lookback(), 3);
+
+        let bar = Bar::new().high(2).low(1).close(1.5);
+        cci.next(&bar);
+        cci.next(&bar);
+        assert!(!cci.is_ready());
+        cci.next(&bar);
+        assert!(cci.is_ready());
+    }
+
     #[test]
     fn test_default() {
         CommodityChannelIndex::default();
@@ -1,8 +1,9 @@
-use std::fmt;
+use core::fmt;
 
 use crate::errors::{Result, TaError};
-use crate::{Close, Next, Period, Reset};
-use crate::indicators::ExponentialMovingAverage;
+use crate::indicators::generic::ExponentialMovingAverage;
+use crate::registry::{FromParams, Param};
+use crate::{Close, Lookback, Next, Num, Peek, Period, Reset};
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
@@ -52,99 +53,170 @@ use serde::{Deserialize, Serialize};
 ///
 /// * [Exponential moving average, Wikipedia](https://en.wikipedia.org/wiki/Moving_average#Exponential_moving_average)
 ///
-
-
-
 #[doc(alias = "DEMA")]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[derive(Debug, Clone)]
-pub struct DoubleExponentialMovingAverage {
+pub struct DoubleExponentialMovingAverage<N = f64> {
     period: usize,
     // k: f64,
-    current: f64,
+    current: N,
     is_new: bool,
-    ema: ExponentialMovingAverage,
-    ema2: ExponentialMovingAverage,
+    count: usize,
+    ema: ExponentialMovingAverage<N>,
+    ema2: ExponentialMovingAverage<N>,
 }
 
-impl DoubleExponentialMovingAverage {
+impl<N: Num> DoubleExponentialMovingAverage<N> {
     pub fn new(period: usize) -> Result<Self> {
         match period {
             0 => Err(TaError::InvalidParameter),
             _ => Ok(Self {
                 period,
                 // k: 2.0 / (period + 1) as f64,
-                current: 0.0,
+                current: N::zero(),
                 is_new: true,
+                count: 0,
                 ema: ExponentialMovingAverage::new(period).unwrap(),
                 ema2: ExponentialMovingAverage::new(period).unwrap(),
-                
             }),
         }
     }
 }
 
-impl Period for DoubleExponentialMovingAverage {
+impl<N: Num> Period for DoubleExponentialMovingAverage<N> {
     fn period(&self) -> usize {
         self.period
     }
 }
 
-impl Next<f64> for DoubleExponentialMovingAverage {
-    type Output = f64;
+impl<N: Num> Lookback for DoubleExponentialMovingAverage<N> {
+    fn lookback(&self) -> usize {
+        2 * self.period - 1
+    }
+
+    fn is_ready(&self) -> bool {
+        self.count == self.lookback()
+    }
+}
 
-    
-    fn next(&mut self, input: f64) -> Self::Output {
+impl<N: Num> Next<N> for DoubleExponentialMovingAverage<N> {
+    type Output = N;
 
+    fn next(&mut self, input: N) -> Self::Output {
         let ema_value = self.ema.next(input);
 
         if self.is_new {
             self.is_new = false;
             self.current = self.ema2.next(ema_value);
         } else {
+            let ema_2_value = self.ema2.next(ema_value);
 
-            let ema_2_value = self.ema2.next(ema_value); 
+            self.current = (N::from_f64(2.0) * ema_value) - ema_2_value;
+        }
+
+        if self.count < self.lookback() {
+            self.count += 1;
+        }
+
+        self.current
+    }
+}
+
+impl_next_batch!(DoubleExponentialMovingAverage, N => N);
+
+impl<N: Num> Peek<N> for DoubleExponentialMovingAverage<N> {
+    fn peek(&self, input: N) -> Self::Output {
+        let ema_value = self.ema.peek(input);
+
+        if self.is_new {
+            self.ema2.peek(ema_value)
+        } else {
+            (N::from_f64(2.0) * ema_value) - self.ema2.peek(ema_value)
+        }
+    }
 
-            self.current = (2.0 * ema_value) - ema_2_value;
+    fn update_last(&mut self, input: N) -> Self::Output {
+        if self.is_new {
+            return self.next(input);
         }
 
+        let ema_value = self.ema.update_last(input);
+        let ema_2_value = self.ema2.update_last(ema_value);
+
+        // `count` stops at the lookback, which is 1 for period 1, but then both EMAs
+        // are equal to the input anyway.
+        self.current = if self.count == 1 {
+            ema_2_value
+        } else {
+            (N::from_f64(2.0) * ema_value) - ema_2_value
+        };
+
         self.current
     }
 }
 
-impl<T: Close> Next<&T> for DoubleExponentialMovingAverage {
-    type Output = f64;
+impl_for_floats! {
+    impl<T: Close<N>> Next<&T> for DoubleExponentialMovingAverage<N> {
+        type Output = N;
 
-    fn next(&mut self, input: &T) -> Self::Output {
-        self.next(input.close())
+        fn next(&mut self, input: &T) -> Self::Output {
+            self.next(input.close())
+        }
+    }
+
+    impl_next_batch!(DoubleExponentialMovingAverage<N>, Close => N);
+
+    impl<T: Close<N>> Peek<&T> for DoubleExponentialMovingAverage<N> {
+        fn peek(&self, input: &T) -> Self::Output {
+            self.peek(input.close())
+        }
+
+        fn update_last(&mut self, input: &T) -> Self::Output {
+            self.update_last(input.close())
+        }
     }
 }
 
-impl Reset for DoubleExponentialMovingAverage {
+impl<N: Num> Reset for DoubleExponentialMovingAverage<N> {
     fn reset(&mut self) {
-        self.current = 0.0;
+        self.current = N::zero();
         self.is_new = true;
+        self.count = 0;
 
         self.ema.reset();
         self.ema2.reset();
     }
 }
 
-impl Default for DoubleExponentialMovingAverage {
+impl<N: Num> Default for DoubleExponentialMovingAverage<N> {
     fn default() -> Self {
         Self::new(9).unwrap()
     }
 }
 
-impl fmt::Display for DoubleExponentialMovingAverage {
+impl<N: Num> fmt::Display for DoubleExponentialMovingAverage<N> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         write!(f, "DEMA({})", self.period)
     }
 }
 
+impl<N: Num> FromParams for DoubleExponentialMovingAverage<N> {
+    const NAME: &'static str = "DEMA";
+
+    fn from_params(params: &[Param]) -> Result<Self> {
+        match *params {
+            [period] => Self::new(period.period()?),
+            _ => Err(TaError::InvalidParameter),
+        }
+    }
+}
+
+impl_from_str!(DoubleExponentialMovingAverage);
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::indicators::DoubleExponentialMovingAverage;
     use crate::test_helper::*;
 
     test_indicator!(DoubleExponentialMovingAverage);
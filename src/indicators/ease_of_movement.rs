@@ -0,0 +1,280 @@
+use core::fmt;
+
+use crate::errors::{Result, TaError};
+use crate::indicators::generic::SimpleMovingAverage;
+use crate::registry::{FromParams, Param};
+use crate::{High, Lookback, Low, Next, Num, Peek, Period, Reset, Volume};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Ease of Movement (EMV).
+///
+/// Relates the change of the price to the volume. High positive values show that the price
+/// goes up on a low volume, high negative values show that the price goes down on a low volume.
+///
+/// # Formula
+///
+/// Distance = (high<sub>t</sub> + low<sub>t</sub>) / 2 - (high<sub>t-1</sub> + low<sub>t-1</sub>) / 2
+///
+/// Box Ratio = (volume<sub>t</sub> / _divisor_) / (high<sub>t</sub> - low<sub>t</sub>)
+///
+/// EMV = SMA<sub>period</sub>(Distance / Box Ratio)
+///
+/// When the volume of a bar is 0, or its high equals its low, its raw EMV is 0. The first bar
+/// has no prior bar, so EMV is 0 for it and the SMA starts with the second bar.
+///
+/// # Parameters
+///
+/// * _period_ - period of the SMA (integer greater than 0). Default is 14.
+/// * _divisor_ - scales the volume (number greater than 0). Default is 100,000,000.
+///
+/// # Example
+///
+/// ```
+/// use ta::indicators::EaseOfMovement;
+/// use ta::{DataItem, Next};
+///
+/// let item = |high: f64, low: f64, volume: f64| {
+///     DataItem::builder()
+///         .open(low)
+///         .high(high)
+///         .low(low)
+///         .close(high)
+///         .volume(volume)
+///         .build()
+///         .unwrap()
+/// };
+///
+/// let mut emv = EaseOfMovement::new(1, 100.0).unwrap();
+/// assert_eq!(emv.next(&item(10.0, 8.0, 100.0)), 0.0);
+/// assert_eq!(emv.next(&item(12.0, 10.0, 200.0)), 2.0);
+/// ```
+///
+/// # Links
+///
+/// * [Ease of movement, Wikipedia](https://en.wikipedia.org/wiki/Ease_of_movement)
+/// * [Ease of Movement, stockcharts](https://school.stockcharts.com/doku.php?id=technical_indicators:ease_of_movement_emv)
+///
+#[doc(alias = "EMV")]
+#[doc(alias = "EOM")]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone)]
+pub struct EaseOfMovement<N = f64> {
+    divisor: f64,
+    sma: SimpleMovingAverage<N>,
+    prev_midpoint: N,
+    prev_prev_midpoint: N,
+    count: usize,
+}
+
+impl<N: Num> EaseOfMovement<N> {
+    pub fn new(period: usize, divisor: f64) -> Result<Self> {
+        if divisor.is_nan() || divisor <= 0.0 {
+            return Err(TaError::InvalidParameter);
+        }
+
+        Ok(Self {
+            divisor,
+            sma: SimpleMovingAverage::new(period)?,
+            prev_midpoint: N::zero(),
+            prev_prev_midpoint: N::zero(),
+            count: 0,
+        })
+    }
+
+    pub fn divisor(&self) -> f64 {
+        self.divisor
+    }
+
+    fn midpoint<T: High<N> + Low<N>>(input: &T) -> N {
+        (input.high() + input.low()) / N::from_f64(2.0)
+    }
+
+    fn emv<T: High<N> + Low<N> + Volume<N>>(&self, prev_midpoint: N, input: &T) -> N {
+        if input.volume() == N::zero() {
+            N::zero()
+        } else {
+            let distance = Self::midpoint(input) - prev_midpoint;
+            distance * (input.high() - input.low()) * N::from_f64(self.divisor) / input.volume()
+        }
+    }
+}
+
+impl<N: Num> Period for EaseOfMovement<N> {
+    fn period(&self) -> usize {
+        self.sma.period()
+    }
+}
+
+impl<N: Num> Lookback for EaseOfMovement<N> {
+    fn lookback(&self) -> usize {
+        self.sma.lookback() + 1
+    }
+
+    fn is_ready(&self) -> bool {
+        self.count == self.lookback()
+    }
+}
+
+impl<N: Num, T: High<N> + Low<N> + Volume<N>> Next<&T> for EaseOfMovement<N> {
+    type Output = N;
+
+    fn next(&mut self, input: &T) -> Self::Output {
+        let emv = if self.count == 0 {
+            N::zero()
+        } else {
+            let emv = self.emv(self.prev_midpoint, input);
+            self.sma.next(emv)
+        };
+
+        self.prev_prev_midpoint = self.prev_midpoint;
+        self.prev_midpoint = Self::midpoint(input);
+        if self.count < self.lookback() {
+            self.count += 1;
+        }
+
+        emv
+    }
+}
+
+impl_next_batch!(EaseOfMovement, High + Low + Volume => N);
+
+impl<N: Num, T: High<N> + Low<N> + Volume<N>> Peek<&T> for EaseOfMovement<N> {
+    fn peek(&self, input: &T) -> Self::Output {
+        if self.count == 0 {
+            N::zero()
+        } else {
+            self.sma.peek(self.emv(self.prev_midpoint, input))
+        }
+    }
+
+    fn update_last(&mut self, input: &T) -> Self::Output {
+        let emv = match self.count {
+            0 => return self.next(input),
+            1 => N::zero(),
+            _ => {
+                let emv = self.emv(self.prev_prev_midpoint, input);
+                self.sma.update_last(emv)
+            }
+        };
+
+        self.prev_midpoint = Self::midpoint(input);
+        emv
+    }
+}
+
+impl<N: Num> Reset for EaseOfMovement<N> {
+    fn reset(&mut self) {
+        self.sma.reset();
+        self.prev_midpoint = N::zero();
+        self.prev_prev_midpoint = N::zero();
+        self.count = 0;
+    }
+}
+
+impl<N: Num> Default for EaseOfMovement<N> {
+    fn default() -> Self {
+        Self::new(14, 100_000_000.0).unwrap()
+    }
+}
+
+impl<N: Num> fmt::Display for EaseOfMovement<N> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "EMV({}, {})", self.sma.period(), self.divisor)
+    }
+}
+
+impl<N: Num> FromParams for EaseOfMovement<N> {
+    const NAME: &'static str = "EMV";
+
+    fn from_params(params: &[Param]) -> Result<Self> {
+        match *params {
+            [period, divisor] => Self::new(period.period()?, divisor.number()?),
+            _ => Err(TaError::InvalidParameter),
+        }
+    }
+}
+
+impl_from_str!(EaseOfMovement);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::indicators::EaseOfMovement;
+    use crate::test_helper::*;
+
+    fn bar(high: f64, low: f64, volume: f64) -> Bar {
+        Bar::new().high(high).low(low).volume(volume)
+    }
+
+    #[test]
+    fn test_new() {
+        assert!(EaseOfMovement::new(0, 1.0).is_err());
+        assert!(EaseOfMovement::new(1, 0.0).is_err());
+        assert!(EaseOfMovement::new(1, -1.0).is_err());
+        assert!(EaseOfMovement::new(1, f64::NAN).is_err());
+        assert!(EaseOfMovement::new(1, 1.0).is_ok());
+    }
+
+    #[test]
+    fn test_next_bar() {
+        let mut emv = EaseOfMovement::new(2, 100.0).unwrap();
+
+        assert_eq!(emv.next(&bar(10.0, 8.0, 100.0)), 0.0);
+        assert_eq!(emv.next(&bar(12.0, 10.0, 200.0)), 2.0);
+
+        // high == low
+        assert_eq!(emv.next(&bar(11.0, 11.0, 500.0)), 1.0);
+
+        // zero volume
+        assert_eq!(emv.next(&bar(13.0, 9.0, 0.0)), 0.0);
+
+        assert_eq!(emv.next(&bar(14.0, 10.0, 400.0)), 0.5);
+    }
+
+    #[test]
+    fn test_peek() {
+        let bars = peek_bars();
+        let bars: Vec<&Bar> = bars.iter().collect();
+        assert_peek(EaseOfMovement::default(), &bars);
+        assert_peek(EaseOfMovement::new(1, 100.0).unwrap(), &bars);
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut emv = EaseOfMovement::new(2, 100.0).unwrap();
+
+        assert_eq!(emv.next(&bar(10.0, 8.0, 100.0)), 0.0);
+        assert_eq!(emv.next(&bar(12.0, 10.0, 200.0)), 2.0);
+
+        emv.reset();
+
+        assert_eq!(emv.next(&bar(12.0, 10.0, 200.0)), 0.0);
+        assert_eq!(emv.next(&bar(14.0, 10.0, 400.0)), 1.0);
+    }
+
+    #[test]
+    fn test_lookback() {
+        let mut emv = EaseOfMovement::new(2, 100.0).unwrap();
+        assert_eq!(emv.lookback(), 3);
+
+        for _ in 0..2 {
+            emv.next(&bar(10.0, 8.0, 100.0));
+            assert!(!emv.is_ready());
+        }
+        emv.next(&bar(10.0, 8.0, 100.0));
+        assert!(emv.is_ready());
+    }
+
+    #[test]
+    fn test_default() {
+        let emv = EaseOfMovement::default();
+        assert_eq!(emv.divisor(), 100_000_000.0);
+    }
+
+    #[test]
+    fn test_display() {
+        let emv = EaseOfMovement::new(14, 10_000.0).unwrap();
+        assert_eq!(format!("{}", emv), "EMV(14, 10000)");
+    }
+}
@@ -1,7 +1,11 @@
-use std::fmt;
+use alloc::boxed::Box;
+use alloc::vec;
+use core::fmt;
 
 use crate::errors::{Result, TaError};
-use crate::traits::{Close, Next, Period, Reset};
+use crate::registry::{FromParams, Param};
+use crate::traits::{Close, Lookback, Next, Peek, Period, Reset};
+use crate::Num;
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
@@ -9,6 +13,7 @@ use serde::{Deserialize, Serialize};
 ///
 /// It is calculated by dividing the price change over a period by the absolute sum of the price movements that occurred to achieve that change.
 /// The resulting ratio ranges between 0.0 and 1.0 with higher values representing a more efficient or trending market.
+/// When the price doesn't move at all, the ratio is 0.0.
 ///
 /// # Parameters
 ///
@@ -28,18 +33,18 @@ use serde::{Deserialize, Serialize};
 /// assert_eq!(er.next(18.0), 0.8);
 /// assert_eq!(er.next(19.0), 0.75);
 /// ```
-
 #[doc(alias = "ER")]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[derive(Debug, Clone)]
-pub struct EfficiencyRatio {
+pub struct EfficiencyRatio<N = f64> {
     period: usize,
     index: usize,
     count: usize,
-    deque: Box<[f64]>,
+    first: N,
+    deque: Box<[N]>,
 }
 
-impl EfficiencyRatio {
+impl<N: Num> EfficiencyRatio<N> {
     pub fn new(period: usize) -> Result<Self> {
         match period {
             0 => Err(TaError::InvalidParameter),
@@ -47,29 +52,70 @@ impl EfficiencyRatio {
                 period,
                 index: 0,
                 count: 0,
-                deque: vec![0.0; period].into_boxed_slice(),
+                first: N::zero(),
+                deque: vec![N::zero(); period].into_boxed_slice(),
             }),
         }
     }
+
+    // Calculates the ratio for the window of `count` values, where the value at `input_index`
+    // is replaced with `input` and `first` is the value preceding the window.
+    fn efficiency(&self, first: N, input: N, input_index: usize, count: usize) -> N {
+        let start = if input_index + 1 < self.period {
+            input_index + 1
+        } else {
+            0
+        };
+
+        let mut volatility = N::zero();
+        let mut previous = first;
+        for i in (start..count).chain(0..start) {
+            let n = if i == input_index {
+                input
+            } else {
+                self.deque[i]
+            };
+            volatility += (previous - n).abs();
+            previous = n;
+        }
+
+        if volatility == N::zero() {
+            N::zero()
+        } else {
+            (first - input).abs() / volatility
+        }
+    }
 }
 
-impl Period for EfficiencyRatio {
+impl<N: Num> Period for EfficiencyRatio<N> {
     fn period(&self) -> usize {
         self.period
     }
 }
 
-impl Next<f64> for EfficiencyRatio {
-    type Output = f64;
+impl<N: Num> Lookback for EfficiencyRatio<N> {
+    fn lookback(&self) -> usize {
+        self.period
+    }
+
+    fn is_ready(&self) -> bool {
+        self.count == self.period
+    }
+}
 
-    fn next(&mut self, input: f64) -> f64 {
+impl<N: Num> Next<N> for EfficiencyRatio<N> {
+    type Output = N;
+
+    fn next(&mut self, input: N) -> N {
         let first = if self.count >= self.period {
             self.deque[self.index]
         } else {
             self.count += 1;
             self.deque[0]
         };
-        self.deque[self.index] = input;
+        let input_index = self.index;
+        self.deque[input_index] = input;
+        self.first = first;
 
         self.index = if self.index + 1 < self.period {
             self.index + 1
@@ -77,54 +123,101 @@ impl Next<f64> for EfficiencyRatio {
             0
         };
 
-        let mut volatility = 0.0;
-        let mut previous = first;
-        for n in &self.deque[self.index..self.count] {
-            volatility += (previous - n).abs();
-            previous = *n;
-        }
-        for n in &self.deque[0..self.index] {
-            volatility += (previous - n).abs();
-            previous = *n;
+        self.efficiency(first, input, input_index, self.count)
+    }
+}
+
+impl_next_batch!(EfficiencyRatio, N => N);
+
+impl<N: Num> Peek<N> for EfficiencyRatio<N> {
+    fn peek(&self, input: N) -> N {
+        let (first, count) = if self.count >= self.period {
+            (self.deque[self.index], self.count)
+        } else {
+            (self.deque[0], self.count + 1)
+        };
+
+        self.efficiency(first, input, self.index, count)
+    }
+
+    fn update_last(&mut self, input: N) -> N {
+        if self.count == 0 {
+            return self.next(input);
         }
 
-        (first - input).abs() / volatility
+        let last = if self.index == 0 {
+            self.period - 1
+        } else {
+            self.index - 1
+        };
+        self.deque[last] = input;
+
+        self.efficiency(self.first, input, last, self.count)
     }
 }
 
-impl<T: Close> Next<&T> for EfficiencyRatio {
-    type Output = f64;
+impl_for_floats! {
+    impl<T: Close<N>> Next<&T> for EfficiencyRatio<N> {
+        type Output = N;
+
+        fn next(&mut self, input: &T) -> N {
+            self.next(input.close())
+        }
+    }
+
+    impl_next_batch!(EfficiencyRatio<N>, Close => N);
+
+    impl<T: Close<N>> Peek<&T> for EfficiencyRatio<N> {
+        fn peek(&self, input: &T) -> N {
+            self.peek(input.close())
+        }
 
-    fn next(&mut self, input: &T) -> f64 {
-        self.next(input.close())
+        fn update_last(&mut self, input: &T) -> N {
+            self.update_last(input.close())
+        }
     }
 }
 
-impl Reset for EfficiencyRatio {
+impl<N: Num> Reset for EfficiencyRatio<N> {
     fn reset(&mut self) {
         self.index = 0;
         self.count = 0;
+        self.first = N::zero();
         for i in 0..self.period {
-            self.deque[i] = 0.0;
+            self.deque[i] = N::zero();
         }
     }
 }
 
-impl Default for EfficiencyRatio {
+impl<N: Num> Default for EfficiencyRatio<N> {
     fn default() -> Self {
         Self::new(14).unwrap()
     }
 }
 
-impl fmt::Display for EfficiencyRatio {
+impl<N: Num> fmt::Display for EfficiencyRatio<N> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         write!(f, "ER({})", self.period)
     }
 }
 
+impl<N: Num> FromParams for EfficiencyRatio<N> {
+    const NAME: &'static str = "ER";
+
+    fn from_params(params: &[Param]) -> Result<Self> {
+        match *params {
+            [period] => Self::new(period.period()?),
+            _ => Err(TaError::InvalidParameter),
+        }
+    }
+}
+
+impl_from_str!(EfficiencyRatio);
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::indicators::EfficiencyRatio;
     use crate::test_helper::*;
 
     test_indicator!(EfficiencyRatio);
@@ -149,6 +242,18 @@ mod tests {
         assert_eq!(round(er.next(6.0)), 1.0);
     }
 
+    #[test]
+    fn test_next_flat() {
+        let mut er = EfficiencyRatio::new(3).unwrap();
+
+        assert_eq!(er.next(0.0), 0.0);
+        assert_eq!(er.next(2.0), 1.0);
+        assert_eq!(er.next(2.0), 1.0);
+        assert_eq!(er.next(2.0), 1.0);
+        assert_eq!(er.next(2.0), 0.0);
+        assert_eq!(er.peek(2.0), 0.0);
+    }
+
     #[test]
     fn test_reset() {
         let mut er = EfficiencyRatio::new(3).unwrap();
@@ -1,7 +1,10 @@
-use std::fmt;
+use alloc::vec;
+use alloc::vec::Vec;
+use core::fmt;
 
 use crate::errors::{Result, TaError};
-use crate::{Close, Next, Period, Reset};
+use crate::registry::{FromParams, Param};
+use crate::{Close, Lookback, Next, NextBatch, Num, Peek, Period, Reset};
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
@@ -51,81 +54,193 @@ use serde::{Deserialize, Serialize};
 ///
 /// * [Exponential moving average, Wikipedia](https://en.wikipedia.org/wiki/Moving_average#Exponential_moving_average)
 ///
-
 #[doc(alias = "EMA")]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[derive(Debug, Clone)]
-pub struct ExponentialMovingAverage {
+pub struct ExponentialMovingAverage<N = f64> {
     period: usize,
-    k: f64,
-    current: f64,
-    is_new: bool,
+    k: N,
+    current: N,
+    prev: N,
+    count: usize,
 }
 
-impl ExponentialMovingAverage {
+impl<N: Num> ExponentialMovingAverage<N> {
     pub fn new(period: usize) -> Result<Self> {
         match period {
             0 => Err(TaError::InvalidParameter),
             _ => Ok(Self {
                 period,
-                k: 2.0 / (period + 1) as f64,
-                current: 0.0,
-                is_new: true,
+                k: N::from_f64(2.0 / (period + 1) as f64),
+                current: N::zero(),
+                prev: N::zero(),
+                count: 0,
             }),
         }
     }
+
+    fn next_batch_iter<I: Iterator<Item = N>>(&mut self, mut input: I, output: &mut [N]) {
+        let mut output = output.iter_mut();
+
+        // Go through the warm-up period first, so the loop below doesn't need to check `count`.
+        while self.count < self.period {
+            match (input.next(), output.next()) {
+                (Some(item), Some(out)) => *out = self.next(item),
+                _ => return,
+            }
+        }
+
+        let mut prev = self.prev;
+        let mut current = self.current;
+        for (item, out) in input.zip(output) {
+            prev = current;
+            current = self.k * item + (N::one() - self.k) * current;
+            *out = current;
+        }
+        self.prev = prev;
+        self.current = current;
+    }
 }
 
-impl Period for ExponentialMovingAverage {
+impl<N: Num> Period for ExponentialMovingAverage<N> {
     fn period(&self) -> usize {
         self.period
     }
 }
 
-impl Next<f64> for ExponentialMovingAverage {
-    type Output = f64;
+impl<N: Num> Lookback for ExponentialMovingAverage<N> {
+    fn lookback(&self) -> usize {
+        self.period
+    }
 
-    fn next(&mut self, input: f64) -> Self::Output {
-        if self.is_new {
-            self.is_new = false;
+    fn is_ready(&self) -> bool {
+        self.count == self.period
+    }
+}
+
+impl<N: Num> Next<N> for ExponentialMovingAverage<N> {
+    type Output = N;
+
+    fn next(&mut self, input: N) -> Self::Output {
+        self.prev = self.current;
+        if self.count == 0 {
             self.current = input;
         } else {
-            self.current = self.k * input + (1.0 - self.k) * self.current;
+            self.current = self.k * input + (N::one() - self.k) * self.current;
+        }
+        if self.count < self.period {
+            self.count += 1;
+        }
+        self.current
+    }
+}
+
+impl<N: Num> NextBatch<N> for ExponentialMovingAverage<N> {
+    type Output = N;
+
+    fn next_batch(&mut self, input: &[N]) -> Vec<Self::Output> {
+        let mut output = vec![N::zero(); input.len()];
+        self.next_batch_iter(input.iter().copied(), &mut output);
+        output
+    }
+
+    fn next_batch_into(&mut self, input: &[N], output: &mut [Self::Output]) {
+        assert_eq!(input.len(), output.len());
+        self.next_batch_iter(input.iter().copied(), output);
+    }
+}
+
+impl<N: Num> Peek<N> for ExponentialMovingAverage<N> {
+    fn peek(&self, input: N) -> Self::Output {
+        if self.count == 0 {
+            input
+        } else {
+            self.k * input + (N::one() - self.k) * self.current
+        }
+    }
+
+    fn update_last(&mut self, input: N) -> Self::Output {
+        match self.count {
+            0 => return self.next(input),
+            1 => self.current = input,
+            _ => self.current = self.k * input + (N::one() - self.k) * self.prev,
         }
         self.current
     }
 }
 
-impl<T: Close> Next<&T> for ExponentialMovingAverage {
-    type Output = f64;
+impl_for_floats! {
+    impl<T: Close<N>> Next<&T> for ExponentialMovingAverage<N> {
+        type Output = N;
+
+        fn next(&mut self, input: &T) -> Self::Output {
+            self.next(input.close())
+        }
+    }
+
+    impl<T: Close<N>> NextBatch<T> for ExponentialMovingAverage<N> {
+        type Output = N;
+
+        fn next_batch(&mut self, input: &[T]) -> Vec<Self::Output> {
+            let mut output = vec![N::zero(); input.len()];
+            self.next_batch_iter(input.iter().map(Close::close), &mut output);
+            output
+        }
+
+        fn next_batch_into(&mut self, input: &[T], output: &mut [Self::Output]) {
+            assert_eq!(input.len(), output.len());
+            self.next_batch_iter(input.iter().map(Close::close), output);
+        }
+    }
 
-    fn next(&mut self, input: &T) -> Self::Output {
-        self.next(input.close())
+    impl<T: Close<N>> Peek<&T> for ExponentialMovingAverage<N> {
+        fn peek(&self, input: &T) -> Self::Output {
+            self.peek(input.close())
+        }
+
+        fn update_last(&mut self, input: &T) -> Self::Output {
+            self.update_last(input.close())
+        }
     }
 }
 
-impl Reset for ExponentialMovingAverage {
+impl<N: Num> Reset for ExponentialMovingAverage<N> {
     fn reset(&mut self) {
-        self.current = 0.0;
-        self.is_new = true;
+        self.current = N::zero();
+        self.prev = N::zero();
+        self.count = 0;
     }
 }
 
-impl Default for ExponentialMovingAverage {
+impl<N: Num> Default for ExponentialMovingAverage<N> {
     fn default() -> Self {
         Self::new(9).unwrap()
     }
 }
 
-impl fmt::Display for ExponentialMovingAverage {
+impl<N: Num> fmt::Display for ExponentialMovingAverage<N> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         write!(f, "EMA({})", self.period)
     }
 }
 
+impl<N: Num> FromParams for ExponentialMovingAverage<N> {
+    const NAME: &'static str = "EMA";
+
+    fn from_params(params: &[Param]) -> Result<Self> {
+        match *params {
+            [period] => Self::new(period.period()?),
+            _ => Err(TaError::InvalidParameter),
+        }
+    }
+}
+
+impl_from_str!(ExponentialMovingAverage);
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::indicators::ExponentialMovingAverage;
     use crate::test_helper::*;
 
     test_indicator!(ExponentialMovingAverage);
@@ -152,6 +267,24 @@ mod tests {
         assert_eq!(ema.next(&bar2), 3.5);
     }
 
+    #[test]
+    fn test_next_batch() {
+        let input = [2.0, 5.0, 1.0, 6.25, 3.5, 7.0];
+
+        let mut ema = ExponentialMovingAverage::new(3).unwrap();
+        let expected: Vec<f64> = input.iter().map(|&x| ema.next(x)).collect();
+
+        let mut ema = ExponentialMovingAverage::new(3).unwrap();
+        assert_eq!(ema.next_batch(&input[..1]), expected[..1]);
+        assert_eq!(ema.next_batch(&input[1..]), expected[1..]);
+
+        let bars: Vec<Bar> = input.iter().map(|&x| Bar::new().close(x)).collect();
+        let mut output = vec![0.0; bars.len()];
+        let mut ema = ExponentialMovingAverage::new(3).unwrap();
+        ema.next_batch_into(&bars, &mut output);
+        assert_eq!(output, expected);
+    }
+
     #[test]
     fn test_reset() {
         let mut ema = ExponentialMovingAverage::new(5).unwrap();
@@ -1,8 +1,9 @@
-use std::fmt;
+use core::fmt;
 
-use crate::errors::Result;
-use crate::indicators::{Maximum, Minimum};
-use crate::{Close, High, Low, Next, Period, Reset};
+use crate::errors::{Result, TaError};
+use crate::indicators::generic::{Maximum, Minimum};
+use crate::registry::{FromParams, Param};
+use crate::{Close, High, Lookback, Low, Next, Num, Peek, Period, Reset};
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
@@ -42,13 +43,13 @@ use serde::{Deserialize, Serialize};
 /// ```
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[derive(Debug, Clone)]
-pub struct FastStochastic {
+pub struct FastStochastic<N = f64> {
     period: usize,
-    minimum: Minimum,
-    maximum: Maximum,
+    minimum: Minimum<N>,
+    maximum: Maximum<N>,
 }
 
-impl FastStochastic {
+impl<N: Num> FastStochastic<N> {
     pub fn new(period: usize) -> Result<Self> {
         Ok(Self {
             period,
@@ -56,70 +57,124 @@ impl FastStochastic {
             maximum: Maximum::new(period)?,
         })
     }
+
+    fn stochastic(close: N, lowest: N, highest: N) -> N {
+        if highest == lowest {
+            // When only 1 input was given, than min and max are the same,
+            // therefore it makes sense to return 50. It also avoids division by zero.
+            N::from_f64(50.0)
+        } else {
+            (close - lowest) / (highest - lowest) * N::from_f64(100.0)
+        }
+    }
 }
 
-impl Period for FastStochastic {
+impl<N: Num> Period for FastStochastic<N> {
     fn period(&self) -> usize {
         self.period
     }
 }
 
-impl Next<f64> for FastStochastic {
-    type Output = f64;
+impl<N: Num> Lookback for FastStochastic<N> {
+    fn lookback(&self) -> usize {
+        self.period
+    }
 
-    fn next(&mut self, input: f64) -> Self::Output {
+    fn is_ready(&self) -> bool {
+        self.maximum.is_ready()
+    }
+}
+
+impl<N: Num> Next<N> for FastStochastic<N> {
+    type Output = N;
+
+    fn next(&mut self, input: N) -> Self::Output {
         let min = self.minimum.next(input);
         let max = self.maximum.next(input);
+        Self::stochastic(input, min, max)
+    }
+}
 
-        if min == max {
-            // When only 1 input was given, than min and max are the same,
-            // therefore it makes sense to return 50
-            50.0
-        } else {
-            (input - min) / (max - min) * 100.0
-        }
+impl_next_batch!(FastStochastic, N => N);
+
+impl<N: Num> Peek<N> for FastStochastic<N> {
+    fn peek(&self, input: N) -> Self::Output {
+        let min = self.minimum.peek(input);
+        let max = self.maximum.peek(input);
+        Self::stochastic(input, min, max)
+    }
+
+    fn update_last(&mut self, input: N) -> Self::Output {
+        let min = self.minimum.update_last(input);
+        let max = self.maximum.update_last(input);
+        Self::stochastic(input, min, max)
     }
 }
 
-impl<T: High + Low + Close> Next<&T> for FastStochastic {
-    type Output = f64;
+impl_for_floats! {
+    impl<T: High<N> + Low<N> + Close<N>> Next<&T> for FastStochastic<N> {
+        type Output = N;
+
+        fn next(&mut self, input: &T) -> Self::Output {
+            let highest = self.maximum.next(input.high());
+            let lowest = self.minimum.next(input.low());
+            Self::stochastic(input.close(), lowest, highest)
+        }
+    }
 
-    fn next(&mut self, input: &T) -> Self::Output {
-        let highest = self.maximum.next(input.high());
-        let lowest = self.minimum.next(input.low());
-        let close = input.close();
+    impl_next_batch!(FastStochastic<N>, High + Low + Close => N);
 
-        if highest == lowest {
-            // To avoid division by zero, return 50.0
-            50.0
-        } else {
-            (close - lowest) / (highest - lowest) * 100.0
+    impl<T: High<N> + Low<N> + Close<N>> Peek<&T> for FastStochastic<N> {
+        fn peek(&self, input: &T) -> Self::Output {
+            let highest = self.maximum.peek(input.high());
+            let lowest = self.minimum.peek(input.low());
+            Self::stochastic(input.close(), lowest, highest)
+        }
+
+        fn update_last(&mut self, input: &T) -> Self::Output {
+            let highest = self.maximum.update_last(input.high());
+            let lowest = self.minimum.update_last(input.low());
+            Self::stochastic(input.close(), lowest, highest)
         }
     }
 }
 
-impl Reset for FastStochastic {
+impl<N: Num> Reset for FastStochastic<N> {
     fn reset(&mut self) {
         self.minimum.reset();
         self.maximum.reset();
     }
 }
 
-impl Default for FastStochastic {
+impl<N: Num> Default for FastStochastic<N> {
     fn default() -> Self {
         Self::new(14).unwrap()
     }
 }
 
-impl fmt::Display for FastStochastic {
+impl<N: Num> fmt::Display for FastStochastic<N> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         write!(f, "FAST_STOCH({})", self.period)
     }
 }
 
+impl<N: Num> FromParams for FastStochastic<N> {
+    const NAME: &'static str = "FAST_STOCH";
+
+    fn from_params(params: &[Param]) -> Result<Self> {
+        match *params {
+            [period] => Self::new(period.period()?),
+            _ => Err(TaError::InvalidParameter),
+        }
+    }
+}
+
+impl_from_str!(FastStochastic);
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::indicators::FastStochastic;
     use crate::test_helper::*;
 
     test_indicator!(FastStochastic);
@@ -0,0 +1,246 @@
+use core::fmt;
+
+use crate::errors::{Result, TaError};
+use crate::indicators::generic::ExponentialMovingAverage;
+use crate::registry::{FromParams, Param};
+use crate::{Close, Lookback, Next, Num, Peek, Period, Reset, Volume};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Elder's Force Index (FI).
+///
+/// Combines the direction and the extent of a price change with the volume. The raw force of
+/// every bar is smoothed with an exponential moving average.
+///
+/// # Formula
+///
+/// Force = (close<sub>t</sub> - close<sub>t-1</sub>) × volume<sub>t</sub>
+///
+/// FI = EMA<sub>period</sub>(Force)
+///
+/// The first bar has no prior close, so FI is 0 for it and the EMA starts with the second bar.
+///
+/// # Parameters
+///
+/// * _period_ - period of the EMA (integer greater than 0). Default is 13.
+///
+/// # Example
+///
+/// ```
+/// use ta::indicators::ForceIndex;
+/// use ta::{DataItem, Next};
+///
+/// let item = |close: f64, volume: f64| {
+///     DataItem::builder()
+///         .open(close)
+///         .high(close)
+///         .low(close)
+///         .close(close)
+///         .volume(volume)
+///         .build()
+///         .unwrap()
+/// };
+///
+/// let mut fi = ForceIndex::new(1).unwrap();
+/// assert_eq!(fi.next(&item(10.0, 100.0)), 0.0);
+/// assert_eq!(fi.next(&item(11.0, 200.0)), 200.0);
+/// assert_eq!(fi.next(&item(10.5, 300.0)), -150.0);
+/// ```
+///
+/// # Links
+///
+/// * [Force Index, Wikipedia](https://en.wikipedia.org/wiki/Force_index)
+/// * [Force Index, stockcharts](https://school.stockcharts.com/doku.php?id=technical_indicators:force_index)
+///
+#[doc(alias = "FI")]
+#[doc(alias = "EFI")]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone)]
+pub struct ForceIndex<N = f64> {
+    ema: ExponentialMovingAverage<N>,
+    prev_close: N,
+    prev_prev_close: N,
+    count: usize,
+}
+
+impl<N: Num> ForceIndex<N> {
+    pub fn new(period: usize) -> Result<Self> {
+        Ok(Self {
+            ema: ExponentialMovingAverage::new(period)?,
+            prev_close: N::zero(),
+            prev_prev_close: N::zero(),
+            count: 0,
+        })
+    }
+
+    fn force<T: Close<N> + Volume<N>>(prev_close: N, input: &T) -> N {
+        (input.close() - prev_close) * input.volume()
+    }
+}
+
+impl<N: Num> Period for ForceIndex<N> {
+    fn period(&self) -> usize {
+        self.ema.period()
+    }
+}
+
+impl<N: Num> Lookback for ForceIndex<N> {
+    fn lookback(&self) -> usize {
+        self.ema.lookback() + 1
+    }
+
+    fn is_ready(&self) -> bool {
+        self.count == self.lookback()
+    }
+}
+
+impl<N: Num, T: Close<N> + Volume<N>> Next<&T> for ForceIndex<N> {
+    type Output = N;
+
+    fn next(&mut self, input: &T) -> Self::Output {
+        let fi = if self.count == 0 {
+            N::zero()
+        } else {
+            self.ema.next(Self::force(self.prev_close, input))
+        };
+
+        self.prev_prev_close = self.prev_close;
+        self.prev_close = input.close();
+        if self.count < self.lookback() {
+            self.count += 1;
+        }
+
+        fi
+    }
+}
+
+impl_next_batch!(ForceIndex, Close + Volume => N);
+
+impl<N: Num, T: Close<N> + Volume<N>> Peek<&T> for ForceIndex<N> {
+    fn peek(&self, input: &T) -> Self::Output {
+        if self.count == 0 {
+            N::zero()
+        } else {
+            self.ema.peek(Self::force(self.prev_close, input))
+        }
+    }
+
+    fn update_last(&mut self, input: &T) -> Self::Output {
+        let fi = match self.count {
+            0 => return self.next(input),
+            1 => N::zero(),
+            _ => self
+                .ema
+                .update_last(Self::force(self.prev_prev_close, input)),
+        };
+
+        self.prev_close = input.close();
+        fi
+    }
+}
+
+impl<N: Num> Reset for ForceIndex<N> {
+    fn reset(&mut self) {
+        self.ema.reset();
+        self.prev_close = N::zero();
+        self.prev_prev_close = N::zero();
+        self.count = 0;
+    }
+}
+
+impl<N: Num> Default for ForceIndex<N> {
+    fn default() -> Self {
+        Self::new(13).unwrap()
+    }
+}
+
+impl<N: Num> fmt::Display for ForceIndex<N> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "FI({})", self.ema.period())
+    }
+}
+
+impl<N: Num> FromParams for ForceIndex<N> {
+    const NAME: &'static str = "FI";
+
+    fn from_params(params: &[Param]) -> Result<Self> {
+        match *params {
+            [period] => Self::new(period.period()?),
+            _ => Err(TaError::InvalidParameter),
+        }
+    }
+}
+
+impl_from_str!(ForceIndex);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::indicators::ForceIndex;
+    use crate::test_helper::*;
+
+    fn bar(close: f64, volume: f64) -> Bar {
+        Bar::new().close(close).volume(volume)
+    }
+
+    #[test]
+    fn test_new() {
+        assert!(ForceIndex::new(0).is_err());
+        assert!(ForceIndex::new(1).is_ok());
+    }
+
+    #[test]
+    fn test_next_bar() {
+        let mut fi = ForceIndex::new(2).unwrap();
+
+        assert_eq!(fi.next(&bar(10.0, 100.0)), 0.0);
+        assert_eq!(fi.next(&bar(11.0, 200.0)), 200.0);
+        assert_eq!(round(fi.next(&bar(10.5, 300.0))), -33.333);
+        assert_eq!(round(fi.next(&bar(12.0, 100.0))), 88.889);
+    }
+
+    #[test]
+    fn test_peek() {
+        let bars = peek_bars();
+        let bars: Vec<&Bar> = bars.iter().collect();
+        assert_peek(ForceIndex::new(13).unwrap(), &bars);
+        assert_peek(ForceIndex::new(1).unwrap(), &bars);
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut fi = ForceIndex::new(2).unwrap();
+
+        assert_eq!(fi.next(&bar(10.0, 100.0)), 0.0);
+        assert_eq!(fi.next(&bar(11.0, 200.0)), 200.0);
+
+        fi.reset();
+
+        assert_eq!(fi.next(&bar(11.0, 200.0)), 0.0);
+        assert_eq!(fi.next(&bar(10.5, 300.0)), -150.0);
+    }
+
+    #[test]
+    fn test_lookback() {
+        let mut fi = ForceIndex::new(2).unwrap();
+        assert_eq!(fi.lookback(), 3);
+
+        for _ in 0..2 {
+            fi.next(&bar(10.0, 100.0));
+            assert!(!fi.is_ready());
+        }
+        fi.next(&bar(10.0, 100.0));
+        assert!(fi.is_ready());
+    }
+
+    #[test]
+    fn test_default() {
+        ForceIndex::default();
+    }
+
+    #[test]
+    fn test_display() {
+        let fi = ForceIndex::new(13).unwrap();
+        assert_eq!(format!("{}", fi), "FI(13)");
+    }
+}
@@ -0,0 +1,393 @@
+use core::fmt;
+
+use crate::errors::{Result, TaError};
+use crate::indicators::generic::{FastStochastic, MovingAverage, MovingAverageType};
+use crate::registry::{FromParams, Param};
+use crate::{Close, High, Lookback, Low, Next, Num, Peek, Period, Reset};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Full stochastic oscillator.
+///
+/// A [fast stochastic](struct.FastStochastic.html), which is smoothed to get the %K line,
+/// and %K is smoothed once more to get the %D signal line. Crossovers of %K and %D are
+/// commonly used as signals. Both stages use simple moving averages by default, use
+/// [`with_moving_average`](#method.with_moving_average) to choose the
+/// [moving average type](enum.MovingAverageType.html) of each stage.
+///
+/// With _k_period_ of 1 %K is the fast stochastic, with _k_period_ of 3 it is the classic
+/// slow stochastic.
+///
+/// # Formula
+///
+/// %K = MA<sub>k</sub>(fast stochastic)
+///
+/// %D = MA<sub>d</sub>(%K)
+///
+/// # Parameters
+///
+/// * _stochastic_period_ - number of periods for fast stochastic (integer greater than 0). Default is 14.
+/// * _k_period_ - number of periods to smooth %K (integer greater than 0). Default is 3.
+/// * _d_period_ - number of periods to smooth %D (integer greater than 0). Default is 3.
+/// * _k_moving_average_ - type of the moving average of %K. Default is
+///   [`MovingAverageType::Simple`](enum.MovingAverageType.html).
+/// * _d_moving_average_ - type of the moving average of %D. Default is
+///   [`MovingAverageType::Simple`](enum.MovingAverageType.html).
+///
+/// # Example
+///
+/// ```
+/// use ta::indicators::FullStochastic;
+/// use ta::Next;
+///
+/// let mut stoch = FullStochastic::new(3, 2, 2).unwrap();
+///
+/// let output = stoch.next(0.0);
+/// assert_eq!((output.k, output.d), (50.0, 50.0));
+/// let output = stoch.next(200.0);
+/// assert_eq!((output.k, output.d), (75.0, 62.5));
+/// let output = stoch.next(100.0);
+/// assert_eq!((output.k, output.d), (75.0, 75.0));
+/// ```
+///
+/// # Links
+///
+/// * [Stochastic oscillator, Wikipedia](https://en.wikipedia.org/wiki/Stochastic_oscillator)
+///
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone)]
+pub struct FullStochastic<N = f64> {
+    fast_stochastic: FastStochastic<N>,
+    k_ma: MovingAverage<N>,
+    d_ma: MovingAverage<N>,
+    count: usize,
+}
+
+impl<N: Num> FullStochastic<N> {
+    pub fn new(stochastic_period: usize, k_period: usize, d_period: usize) -> Result<Self> {
+        Self::with_moving_average(
+            stochastic_period,
+            k_period,
+            d_period,
+            MovingAverageType::Simple,
+            MovingAverageType::Simple,
+        )
+    }
+
+    pub fn with_moving_average(
+        stochastic_period: usize,
+        k_period: usize,
+        d_period: usize,
+        k_moving_average: MovingAverageType,
+        d_moving_average: MovingAverageType,
+    ) -> Result<Self> {
+        Ok(Self {
+            fast_stochastic: FastStochastic::new(stochastic_period)?,
+            k_ma: MovingAverage::new(k_moving_average, k_period)?,
+            d_ma: MovingAverage::new(d_moving_average, d_period)?,
+            count: 0,
+        })
+    }
+
+    pub fn k_moving_average(&self) -> MovingAverageType {
+        self.k_ma.ma_type()
+    }
+
+    pub fn d_moving_average(&self) -> MovingAverageType {
+        self.d_ma.ma_type()
+    }
+
+    fn next_stochastic(&mut self, stochastic: N) -> FullStochasticOutput<N> {
+        if self.count < self.lookback() {
+            self.count += 1;
+        }
+        let k = self.k_ma.next(stochastic);
+        let d = self.d_ma.next(k);
+        FullStochasticOutput { k, d }
+    }
+
+    fn peek_stochastic(&self, stochastic: N) -> FullStochasticOutput<N> {
+        let k = self.k_ma.peek(stochastic);
+        let d = self.d_ma.peek(k);
+        FullStochasticOutput { k, d }
+    }
+
+    fn update_last_stochastic(&mut self, stochastic: N) -> FullStochasticOutput<N> {
+        let k = self.k_ma.update_last(stochastic);
+        let d = self.d_ma.update_last(k);
+        FullStochasticOutput { k, d }
+    }
+}
+
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, PartialEq)]
+pub struct FullStochasticOutput<N = f64> {
+    pub k: N,
+    pub d: N,
+}
+
+impl_fields!(FullStochasticOutput { k, d });
+
+impl<N> From<FullStochasticOutput<N>> for (N, N) {
+    fn from(output: FullStochasticOutput<N>) -> Self {
+        (output.k, output.d)
+    }
+}
+
+impl<N: Num> Period for FullStochastic<N> {
+    fn period(&self) -> usize {
+        self.fast_stochastic.period()
+    }
+}
+
+impl<N: Num> Lookback for FullStochastic<N> {
+    fn lookback(&self) -> usize {
+        self.fast_stochastic.lookback() + self.k_ma.lookback() + self.d_ma.lookback() - 2
+    }
+
+    fn is_ready(&self) -> bool {
+        self.count == self.lookback()
+    }
+}
+
+impl<N: Num> Next<N> for FullStochastic<N> {
+    type Output = FullStochasticOutput<N>;
+
+    fn next(&mut self, input: N) -> Self::Output {
+        let stochastic = self.fast_stochastic.next(input);
+        self.next_stochastic(stochastic)
+    }
+}
+
+impl_next_batch!(FullStochastic, N => FullStochasticOutput<N>);
+
+impl<N: Num> Peek<N> for FullStochastic<N> {
+    fn peek(&self, input: N) -> Self::Output {
+        self.peek_stochastic(self.fast_stochastic.peek(input))
+    }
+
+    fn update_last(&mut self, input: N) -> Self::Output {
+        if self.count == 0 {
+            return self.next(input);
+        }
+        let stochastic = self.fast_stochastic.update_last(input);
+        self.update_last_stochastic(stochastic)
+    }
+}
+
+impl_for_floats! {
+    impl<T: High<N> + Low<N> + Close<N>> Next<&T> for FullStochastic<N> {
+        type Output = FullStochasticOutput<N>;
+
+        fn next(&mut self, input: &T) -> Self::Output {
+            let stochastic = self.fast_stochastic.next(input);
+            self.next_stochastic(stochastic)
+        }
+    }
+
+    impl_next_batch!(FullStochastic<N>, High + Low + Close => FullStochasticOutput<N>);
+
+    impl<T: High<N> + Low<N> + Close<N>> Peek<&T> for FullStochastic<N> {
+        fn peek(&self, input: &T) -> Self::Output {
+            self.peek_stochastic(self.fast_stochastic.peek(input))
+        }
+
+        fn update_last(&mut self, input: &T) -> Self::Output {
+            if self.count == 0 {
+                return self.next(input);
+            }
+            let stochastic = self.fast_stochastic.update_last(input);
+            self.update_last_stochastic(stochastic)
+        }
+    }
+}
+
+impl<N: Num> Reset for FullStochastic<N> {
+    fn reset(&mut self) {
+        self.fast_stochastic.reset();
+        self.k_ma.reset();
+        self.d_ma.reset();
+        self.count = 0;
+    }
+}
+
+impl<N: Num> Default for FullStochastic<N> {
+    fn default() -> Self {
+        Self::new(14, 3, 3).unwrap()
+    }
+}
+
+impl<N: Num> fmt::Display for FullStochastic<N> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match (self.k_moving_average(), self.d_moving_average()) {
+            (MovingAverageType::Simple, MovingAverageType::Simple) => write!(
+                f,
+                "FULL_STOCH({}, {}, {})",
+                self.fast_stochastic.period(),
+                self.k_ma.period(),
+                self.d_ma.period()
+            ),
+            (k_moving_average, d_moving_average) => write!(
+                f,
+                "FULL_STOCH({}, {}, {}, {}, {})",
+                self.fast_stochastic.period(),
+                self.k_ma.period(),
+                self.d_ma.period(),
+                k_moving_average,
+                d_moving_average
+            ),
+        }
+    }
+}
+
+impl<N: Num> FromParams for FullStochastic<N> {
+    const NAME: &'static str = "FULL_STOCH";
+
+    fn from_params(params: &[Param]) -> Result<Self> {
+        match *params {
+            [stochastic_period, k_period, d_period] => Self::new(
+                stochastic_period.period()?,
+                k_period.period()?,
+                d_period.period()?,
+            ),
+            [stochastic_period, k_period, d_period, k_moving_average, d_moving_average] => {
+                Self::with_moving_average(
+                    stochastic_period.period()?,
+                    k_period.period()?,
+                    d_period.period()?,
+                    k_moving_average.moving_average()?,
+                    d_moving_average.moving_average()?,
+                )
+            }
+            _ => Err(TaError::InvalidParameter),
+        }
+    }
+}
+
+impl_from_str!(FullStochastic);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::indicators::FullStochastic;
+    use crate::test_helper::*;
+
+    test_indicator!(FullStochastic);
+
+    fn output(k: f64, d: f64) -> FullStochasticOutput {
+        FullStochasticOutput { k, d }
+    }
+
+    #[test]
+    fn test_new() {
+        assert!(FullStochastic::new(0, 3, 3).is_err());
+        assert!(FullStochastic::new(14, 0, 3).is_err());
+        assert!(FullStochastic::new(14, 3, 0).is_err());
+        assert!(FullStochastic::new(1, 1, 1).is_ok());
+    }
+
+    #[test]
+    fn test_next_with_f64() {
+        let mut stoch = FullStochastic::new(3, 2, 2).unwrap();
+
+        // fast stochastic: 50, 100, 50, 20, 75
+        assert_eq!(stoch.next(0.0), output(50.0, 50.0));
+        assert_eq!(stoch.next(200.0), output(75.0, 62.5));
+        assert_eq!(stoch.next(100.0), output(75.0, 75.0));
+        assert_eq!(stoch.next(120.0), output(35.0, 55.0));
+        assert_eq!(stoch.next(115.0), output(47.5, 41.25));
+    }
+
+    #[test]
+    fn test_next_with_bars() {
+        let mut stoch = FullStochastic::new(3, 2, 2).unwrap();
+
+        // fast stochastic: 50, 75, 20
+        let bar1 = Bar::new().high(20).low(20).close(20);
+        let bar2 = Bar::new().high(30).low(10).close(25);
+        let bar3 = Bar::new().high(40).low(20).close(16);
+
+        assert_eq!(stoch.next(&bar1), output(50.0, 50.0));
+        assert_eq!(stoch.next(&bar2), output(62.5, 56.25));
+        assert_eq!(stoch.next(&bar3), output(47.5, 55.0));
+    }
+
+    #[test]
+    fn test_next_k_period_1() {
+        let mut stoch = FullStochastic::new(3, 1, 2).unwrap();
+        let mut fast_stochastic = FastStochastic::new(3).unwrap();
+
+        for &input in peek_inputs().iter() {
+            assert_eq!(stoch.next(input).k, fast_stochastic.next(input));
+        }
+    }
+
+    #[test]
+    fn test_next_moving_average() {
+        let mut stoch = FullStochastic::with_moving_average(
+            3,
+            2,
+            2,
+            MovingAverageType::Exponential,
+            MovingAverageType::Simple,
+        )
+        .unwrap();
+        assert_eq!(stoch.k_moving_average(), MovingAverageType::Exponential);
+        assert_eq!(stoch.d_moving_average(), MovingAverageType::Simple);
+
+        let round = |output: FullStochasticOutput| (round(output.k), round(output.d));
+        assert_eq!(round(stoch.next(0.0)), (50.0, 50.0));
+        assert_eq!(round(stoch.next(200.0)), (83.333, 66.667));
+        assert_eq!(round(stoch.next(100.0)), (61.111, 72.222));
+        assert_eq!(round(stoch.next(120.0)), (33.704, 47.407));
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut stoch = FullStochastic::new(3, 2, 2).unwrap();
+
+        assert_eq!(stoch.next(0.0), output(50.0, 50.0));
+        assert_eq!(stoch.next(200.0), output(75.0, 62.5));
+
+        stoch.reset();
+
+        assert_eq!(stoch.next(0.0), output(50.0, 50.0));
+        assert_eq!(stoch.next(200.0), output(75.0, 62.5));
+    }
+
+    #[test]
+    fn test_lookback() {
+        let mut stoch = FullStochastic::new(3, 2, 3).unwrap();
+        assert_eq!(stoch.lookback(), 6);
+
+        for _ in 0..5 {
+            stoch.next(1.0);
+            assert!(!stoch.is_ready());
+        }
+        stoch.next(1.0);
+        assert!(stoch.is_ready());
+    }
+
+    #[test]
+    fn test_default() {
+        let stoch = FullStochastic::default();
+        assert_eq!(stoch.k_moving_average(), MovingAverageType::Simple);
+        assert_eq!(stoch.d_moving_average(), MovingAverageType::Simple);
+    }
+
+    #[test]
+    fn test_display() {
+        let stoch = FullStochastic::new(14, 3, 3).unwrap();
+        assert_eq!(format!("{}", stoch), "FULL_STOCH(14, 3, 3)");
+
+        let stoch = FullStochastic::with_moving_average(
+            14,
+            3,
+            3,
+            MovingAverageType::Exponential,
+            MovingAverageType::Simple,
+        )
+        .unwrap();
+        assert_eq!(format!("{}", stoch), "FULL_STOCH(14, 3, 3, EMA, SMA)");
+    }
+}
@@ -0,0 +1,247 @@
+use core::fmt;
+
+use crate::errors::{Result, TaError};
+use crate::indicators::generic::WeightedMovingAverage;
+use crate::registry::{FromParams, Param};
+use crate::{Close, Lookback, Next, Num, Peek, Period, Reset};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Hull moving average (HMA).
+///
+/// A low lag moving average, which is built from three
+/// [weighted moving averages](struct.WeightedMovingAverage.html).
+///
+/// # Formula
+///
+/// HMA = WMA<sub>√n</sub>(2 × WMA<sub>n/2</sub>(_p_) - WMA<sub>n</sub>(_p_))
+///
+/// Where:
+///
+/// * _n_ - is the period, _n_/2 and √_n_ are rounded down (but at least 1)
+/// * _p_ - is the input value
+///
+/// # Parameters
+///
+/// * _period_ - number of periods (integer greater than 0). Default is 9.
+///
+/// # Example
+///
+/// ```
+/// use ta::indicators::HullMovingAverage;
+/// use ta::Next;
+///
+/// let mut hma = HullMovingAverage::new(4).unwrap();
+/// assert_eq!(round(hma.next(10.0)), 10.0);
+/// assert_eq!(round(hma.next(13.0)), 11.33);
+/// assert_eq!(round(hma.next(16.0)), 14.67);
+/// assert_eq!(round(hma.next(19.0)), 18.67);
+///
+/// fn round(num: f64) -> f64 {
+///     (num * 100.0).round() / 100.0
+/// }
+/// ```
+///
+/// # Links
+///
+/// * [Hull Moving Average, Alan Hull](https://alanhull.com/hull-moving-average)
+///
+#[doc(alias = "HMA")]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone)]
+pub struct HullMovingAverage<N = f64> {
+    period: usize,
+    count: usize,
+    wma_half: WeightedMovingAverage<N>,
+    wma_full: WeightedMovingAverage<N>,
+    wma_sqrt: WeightedMovingAverage<N>,
+}
+
+impl<N: Num> HullMovingAverage<N> {
+    pub fn new(period: usize) -> Result<Self> {
+        let sqrt_period = Num::sqrt(period as f64) as usize;
+
+        Ok(Self {
+            period,
+            count: 0,
+            wma_full: WeightedMovingAverage::new(period)?,
+            wma_half: WeightedMovingAverage::new((period / 2).max(1))?,
+            wma_sqrt: WeightedMovingAverage::new(sqrt_period.max(1))?,
+        })
+    }
+}
+
+impl<N: Num> Period for HullMovingAverage<N> {
+    fn period(&self) -> usize {
+        self.period
+    }
+}
+
+impl<N: Num> Lookback for HullMovingAverage<N> {
+    fn lookback(&self) -> usize {
+        self.wma_full.lookback() + self.wma_sqrt.lookback() - 1
+    }
+
+    fn is_ready(&self) -> bool {
+        self.count == self.lookback()
+    }
+}
+
+impl<N: Num> Next<N> for HullMovingAverage<N> {
+    type Output = N;
+
+    fn next(&mut self, input: N) -> Self::Output {
+        let diff = N::from_f64(2.0) * self.wma_half.next(input) - self.wma_full.next(input);
+        if self.count < self.lookback() {
+            self.count += 1;
+        }
+        self.wma_sqrt.next(diff)
+    }
+}
+
+impl_next_batch!(HullMovingAverage, N => N);
+
+impl<N: Num> Peek<N> for HullMovingAverage<N> {
+    fn peek(&self, input: N) -> Self::Output {
+        let diff = N::from_f64(2.0) * self.wma_half.peek(input) - self.wma_full.peek(input);
+        self.wma_sqrt.peek(diff)
+    }
+
+    fn update_last(&mut self, input: N) -> Self::Output {
+        if self.count == 0 {
+            return self.next(input);
+        }
+
+        let diff =
+            N::from_f64(2.0) * self.wma_half.update_last(input) - self.wma_full.update_last(input);
+        self.wma_sqrt.update_last(diff)
+    }
+}
+
+impl_for_floats! {
+    impl<T: Close<N>> Next<&T> for HullMovingAverage<N> {
+        type Output = N;
+
+        fn next(&mut self, input: &T) -> Self::Output {
+            self.next(input.close())
+        }
+    }
+
+    impl_next_batch!(HullMovingAverage<N>, Close => N);
+
+    impl<T: Close<N>> Peek<&T> for HullMovingAverage<N> {
+        fn peek(&self, input: &T) -> Self::Output {
+            self.peek(input.close())
+        }
+
+        fn update_last(&mut self, input: &T) -> Self::Output {
+            self.update_last(input.close())
+        }
+    }
+}
+
+impl<N: Num> Reset for HullMovingAverage<N> {
+    fn reset(&mut self) {
+        self.count = 0;
+        self.wma_half.reset();
+        self.wma_full.reset();
+        self.wma_sqrt.reset();
+    }
+}
+
+impl<N: Num> Default for HullMovingAverage<N> {
+    fn default() -> Self {
+        Self::new(9).unwrap()
+    }
+}
+
+impl<N: Num> fmt::Display for HullMovingAverage<N> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "HMA({})", self.period)
+    }
+}
+
+impl<N: Num> FromParams for HullMovingAverage<N> {
+    const NAME: &'static str = "HMA";
+
+    fn from_params(params: &[Param]) -> Result<Self> {
+        match *params {
+            [period] => Self::new(period.period()?),
+            _ => Err(TaError::InvalidParameter),
+        }
+    }
+}
+
+impl_from_str!(HullMovingAverage);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::indicators::HullMovingAverage;
+    use crate::test_helper::*;
+
+    test_indicator!(HullMovingAverage);
+
+    #[test]
+    fn test_new() {
+        assert!(HullMovingAverage::new(0).is_err());
+        assert!(HullMovingAverage::new(1).is_ok());
+    }
+
+    #[test]
+    fn test_next() {
+        let mut hma = HullMovingAverage::new(4).unwrap();
+
+        assert_eq!(round(hma.next(10.0)), 10.0);
+        assert_eq!(round(hma.next(13.0)), 11.333);
+        assert_eq!(round(hma.next(16.0)), 14.667);
+        assert_eq!(round(hma.next(19.0)), 18.667);
+        assert_eq!(round(hma.next(16.0)), 18.267);
+        assert_eq!(round(hma.next(13.0)), 14.2);
+    }
+
+    #[test]
+    fn test_next_period_1() {
+        let mut hma = HullMovingAverage::new(1).unwrap();
+
+        assert_eq!(hma.next(10.0), 10.0);
+        assert_eq!(hma.next(13.0), 13.0);
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut hma = HullMovingAverage::new(4).unwrap();
+
+        assert_eq!(round(hma.next(10.0)), 10.0);
+        assert_eq!(round(hma.next(13.0)), 11.333);
+
+        hma.reset();
+
+        assert_eq!(round(hma.next(10.0)), 10.0);
+        assert_eq!(round(hma.next(13.0)), 11.333);
+    }
+
+    #[test]
+    fn test_lookback() {
+        let mut hma = HullMovingAverage::new(4).unwrap();
+        assert_eq!(hma.lookback(), 5);
+
+        for _ in 0..4 {
+            hma.next(1.0);
+            assert!(!hma.is_ready());
+        }
+        hma.next(1.0);
+        assert!(hma.is_ready());
+    }
+
+    #[test]
+    fn test_default() {
+        HullMovingAverage::default();
+    }
+
+    #[test]
+    fn test_display() {
+        let hma = HullMovingAverage::new(16).unwrap();
+        assert_eq!(format!("{}", hma), "HMA(16)");
+    }
+}
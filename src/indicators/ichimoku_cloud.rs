@@ -0,0 +1,442 @@
+use alloc::boxed::Box;
+use alloc::vec;
+use core::fmt;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::errors::{Result, TaError};
+use crate::indicators::generic::{Maximum, Minimum};
+use crate::registry::{FromParams, Param};
+use crate::{Close, High, Lookback, Low, Next, Num, Peek, Period, Reset};
+
+/// Ichimoku Kinko Hyo, also known as Ichimoku Cloud.
+///
+/// A trend following indicator, which defines support and resistance, identifies trend direction
+/// and gauges momentum. It is composed of five lines, two of which (Senkou Span A and B) form
+/// the "cloud".
+///
+/// # Formula
+///
+/// * _Tenkan-sen_ (conversion line) = (Max(_tenkan_period_) + Min(_tenkan_period_)) / 2
+/// * _Kijun-sen_ (base line) = (Max(_kijun_period_) + Min(_kijun_period_)) / 2
+/// * _Senkou Span A_ (leading span A) = (Tenkan-sen + Kijun-sen) / 2, plotted _displacement_ periods ahead
+/// * _Senkou Span B_ (leading span B) = (Max(_senkou_b_period_) + Min(_senkou_b_period_)) / 2, plotted _displacement_ periods ahead
+/// * _Chikou Span_ (lagging span) = Close, plotted _displacement_ periods behind
+///
+/// Max and Min are taken over high and low prices respectively.
+///
+/// # Displacement
+///
+/// Since the spans are shifted in time, the output contains both:
+///
+/// * `senkou_span_a` and `senkou_span_b` - the cloud which applies to the current period, i.e.
+///   the spans calculated _displacement_ periods ago. Until _displacement_ periods are
+///   consumed, the spans of the very first period are returned.
+/// * `projected_span_a` and `projected_span_b` - the spans calculated from the current period,
+///   which apply _displacement_ periods ahead.
+/// * `chikou_span` - the current close, which applies _displacement_ periods behind.
+///
+/// # Parameters
+///
+/// * _tenkan_period_ - period for the conversion line (integer greater than 0). Default is 9.
+/// * _kijun_period_ - period for the base line (integer greater than 0). Default is 26.
+/// * _senkou_b_period_ - period for the leading span B (integer greater than 0). Default is 52.
+/// * _displacement_ - number of periods the spans are shifted by (integer greater than 0). Default is 26.
+///
+/// # Example
+///
+/// ```
+/// use ta::indicators::IchimokuCloud;
+/// use ta::{Next, DataItem};
+///
+/// let mut ichimoku = IchimokuCloud::new(2, 3, 4, 2).unwrap();
+///
+/// let item = DataItem::builder()
+///     .open(9.0).high(10.0).low(8.0).close(9.0).volume(1.0).build().unwrap();
+///
+/// let out = ichimoku.next(&item);
+/// assert_eq!(out.tenkan_sen, 9.0);
+/// assert_eq!(out.kijun_sen, 9.0);
+/// assert_eq!(out.senkou_span_a, 9.0);
+/// assert_eq!(out.senkou_span_b, 9.0);
+/// assert_eq!(out.chikou_span, 9.0);
+/// ```
+///
+/// # Links
+///
+/// * [Ichimoku Kinko Hyo, Wikipedia](https://en.wikipedia.org/wiki/Ichimoku_Kink%C5%8D_Hy%C5%8D)
+/// * [Ichimoku Cloud, stockcharts](https://school.stockcharts.com/doku.php?id=technical_indicators:ichimoku_cloud)
+#[doc(alias = "Ichimoku")]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone)]
+pub struct IchimokuCloud<N = f64> {
+    tenkan_max: Maximum<N>,
+    tenkan_min: Minimum<N>,
+    kijun_max: Maximum<N>,
+    kijun_min: Minimum<N>,
+    senkou_b_max: Maximum<N>,
+    senkou_b_min: Minimum<N>,
+    displacement: usize,
+    index: usize,
+    count: usize,
+    senkou_span_a: N,
+    senkou_span_b: N,
+    span_a_deque: Box<[N]>,
+    span_b_deque: Box<[N]>,
+}
+
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, PartialEq)]
+pub struct IchimokuCloudOutput<N = f64> {
+    pub tenkan_sen: N,
+    pub kijun_sen: N,
+    pub senkou_span_a: N,
+    pub senkou_span_b: N,
+    pub projected_span_a: N,
+    pub projected_span_b: N,
+    pub chikou_span: N,
+}
+
+impl_fields!(IchimokuCloudOutput {
+    tenkan_sen,
+    kijun_sen,
+    senkou_span_a,
+    senkou_span_b,
+    projected_span_a,
+    projected_span_b,
+    chikou_span
+});
+
+impl<N: Num> IchimokuCloud<N> {
+    pub fn new(
+        tenkan_period: usize,
+        kijun_period: usize,
+        senkou_b_period: usize,
+        displacement: usize,
+    ) -> Result<Self> {
+        if displacement == 0 {
+            return Err(TaError::InvalidParameter);
+        }
+
+        Ok(Self {
+            tenkan_max: Maximum::new(tenkan_period)?,
+            tenkan_min: Minimum::new(tenkan_period)?,
+            kijun_max: Maximum::new(kijun_period)?,
+            kijun_min: Minimum::new(kijun_period)?,
+            senkou_b_max: Maximum::new(senkou_b_period)?,
+            senkou_b_min: Minimum::new(senkou_b_period)?,
+            displacement,
+            index: 0,
+            count: 0,
+            senkou_span_a: N::zero(),
+            senkou_span_b: N::zero(),
+            span_a_deque: vec![N::zero(); displacement].into_boxed_slice(),
+            span_b_deque: vec![N::zero(); displacement].into_boxed_slice(),
+        })
+    }
+
+    pub fn tenkan_period(&self) -> usize {
+        self.tenkan_max.period()
+    }
+
+    pub fn kijun_period(&self) -> usize {
+        self.kijun_max.period()
+    }
+
+    pub fn senkou_b_period(&self) -> usize {
+        self.senkou_b_max.period()
+    }
+
+    pub fn displacement(&self) -> usize {
+        self.displacement
+    }
+}
+
+impl<N: Num> Lookback for IchimokuCloud<N> {
+    fn lookback(&self) -> usize {
+        self.tenkan_period()
+            .max(self.kijun_period())
+            .max(self.senkou_b_period())
+            + self.displacement
+    }
+
+    fn is_ready(&self) -> bool {
+        self.count == self.lookback()
+    }
+}
+
+impl<N: Num, T: High<N> + Low<N> + Close<N>> Next<&T> for IchimokuCloud<N> {
+    type Output = IchimokuCloudOutput<N>;
+
+    fn next(&mut self, input: &T) -> Self::Output {
+        let tenkan_sen = (self.tenkan_max.next(input.high()) + self.tenkan_min.next(input.low()))
+            / N::from_f64(2.0);
+        let kijun_sen = (self.kijun_max.next(input.high()) + self.kijun_min.next(input.low()))
+            / N::from_f64(2.0);
+        let projected_span_a = (tenkan_sen + kijun_sen) / N::from_f64(2.0);
+        let projected_span_b = (self.senkou_b_max.next(input.high())
+            + self.senkou_b_min.next(input.low()))
+            / N::from_f64(2.0);
+
+        let (senkou_span_a, senkou_span_b) = if self.count == 0 {
+            (projected_span_a, projected_span_b)
+        } else if self.count < self.displacement {
+            (self.span_a_deque[0], self.span_b_deque[0])
+        } else {
+            (self.span_a_deque[self.index], self.span_b_deque[self.index])
+        };
+
+        self.senkou_span_a = senkou_span_a;
+        self.senkou_span_b = senkou_span_b;
+        self.span_a_deque[self.index] = projected_span_a;
+        self.span_b_deque[self.index] = projected_span_b;
+
+        self.index = if self.index + 1 < self.displacement {
+            self.index + 1
+        } else {
+            0
+        };
+
+        if self.count < self.lookback() {
+            self.count += 1;
+        }
+
+        IchimokuCloudOutput {
+            tenkan_sen,
+            kijun_sen,
+            senkou_span_a,
+            senkou_span_b,
+            projected_span_a,
+            projected_span_b,
+            chikou_span: input.close(),
+        }
+    }
+}
+
+impl_next_batch!(IchimokuCloud, High + Low + Close => IchimokuCloudOutput<N>);
+
+impl<N: Num, T: High<N> + Low<N> + Close<N>> Peek<&T> for IchimokuCloud<N> {
+    fn peek(&self, input: &T) -> Self::Output {
+        let tenkan_sen = (self.tenkan_max.peek(input.high()) + self.tenkan_min.peek(input.low()))
+            / N::from_f64(2.0);
+        let kijun_sen = (self.kijun_max.peek(input.high()) + self.kijun_min.peek(input.low()))
+            / N::from_f64(2.0);
+        let projected_span_a = (tenkan_sen + kijun_sen) / N::from_f64(2.0);
+        let projected_span_b = (self.senkou_b_max.peek(input.high())
+            + self.senkou_b_min.peek(input.low()))
+            / N::from_f64(2.0);
+
+        let (senkou_span_a, senkou_span_b) = if self.count == 0 {
+            (projected_span_a, projected_span_b)
+        } else if self.count < self.displacement {
+            (self.span_a_deque[0], self.span_b_deque[0])
+        } else {
+            (self.span_a_deque[self.index], self.span_b_deque[self.index])
+        };
+
+        IchimokuCloudOutput {
+            tenkan_sen,
+            kijun_sen,
+            senkou_span_a,
+            senkou_span_b,
+            projected_span_a,
+            projected_span_b,
+            chikou_span: input.close(),
+        }
+    }
+
+    fn update_last(&mut self, input: &T) -> Self::Output {
+        if self.count == 0 {
+            return self.next(input);
+        }
+
+        let tenkan_sen = (self.tenkan_max.update_last(input.high())
+            + self.tenkan_min.update_last(input.low()))
+            / N::from_f64(2.0);
+        let kijun_sen = (self.kijun_max.update_last(input.high())
+            + self.kijun_min.update_last(input.low()))
+            / N::from_f64(2.0);
+        let projected_span_a = (tenkan_sen + kijun_sen) / N::from_f64(2.0);
+        let projected_span_b = (self.senkou_b_max.update_last(input.high())
+            + self.senkou_b_min.update_last(input.low()))
+            / N::from_f64(2.0);
+
+        // The spans of the first bar are its own projections.
+        if self.count == 1 {
+            self.senkou_span_a = projected_span_a;
+            self.senkou_span_b = projected_span_b;
+        }
+
+        let last = if self.index == 0 {
+            self.displacement - 1
+        } else {
+            self.index - 1
+        };
+        self.span_a_deque[last] = projected_span_a;
+        self.span_b_deque[last] = projected_span_b;
+
+        IchimokuCloudOutput {
+            tenkan_sen,
+            kijun_sen,
+            senkou_span_a: self.senkou_span_a,
+            senkou_span_b: self.senkou_span_b,
+            projected_span_a,
+            projected_span_b,
+            chikou_span: input.close(),
+        }
+    }
+}
+
+impl<N: Num> Reset for IchimokuCloud<N> {
+    fn reset(&mut self) {
+        self.tenkan_max.reset();
+        self.tenkan_min.reset();
+        self.kijun_max.reset();
+        self.kijun_min.reset();
+        self.senkou_b_max.reset();
+        self.senkou_b_min.reset();
+        self.index = 0;
+        self.count = 0;
+        self.senkou_span_a = N::zero();
+        self.senkou_span_b = N::zero();
+        for i in 0..self.displacement {
+            self.span_a_deque[i] = N::zero();
+            self.span_b_deque[i] = N::zero();
+        }
+    }
+}
+
+impl<N: Num> Default for IchimokuCloud<N> {
+    fn default() -> Self {
+        Self::new(9, 26, 52, 26).unwrap()
+    }
+}
+
+impl<N: Num> fmt::Display for IchimokuCloud<N> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "ICHIMOKU({}, {}, {}, {})",
+            self.tenkan_period(),
+            self.kijun_period(),
+            self.senkou_b_period(),
+            self.displacement
+        )
+    }
+}
+
+impl<N: Num> FromParams for IchimokuCloud<N> {
+    const NAME: &'static str = "ICHIMOKU";
+
+    fn from_params(params: &[Param]) -> Result<Self> {
+        match *params {
+            [tenkan_period, kijun_period, senkou_b_period, displacement] => Self::new(
+                tenkan_period.period()?,
+                kijun_period.period()?,
+                senkou_b_period.period()?,
+                displacement.period()?,
+            ),
+            _ => Err(TaError::InvalidParameter),
+        }
+    }
+}
+
+impl_from_str!(IchimokuCloud);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::indicators::IchimokuCloud;
+    use crate::test_helper::*;
+
+    #[test]
+    fn test_new() {
+        assert!(IchimokuCloud::new(0, 26, 52, 26).is_err());
+        assert!(IchimokuCloud::new(9, 0, 52, 26).is_err());
+        assert!(IchimokuCloud::new(9, 26, 0, 26).is_err());
+        assert!(IchimokuCloud::new(9, 26, 52, 0).is_err());
+        assert!(IchimokuCloud::new(1, 1, 1, 1).is_ok());
+        assert!(IchimokuCloud::new(9, 26, 52, 26).is_ok());
+    }
+
+    #[test]
+    fn test_next_bar() {
+        let mut ichimoku = IchimokuCloud::new(2, 3, 4, 2).unwrap();
+
+        let test_data = vec![
+            // high, low, close, tenkan, kijun, span a, span b, projected a, projected b
+            (10.0, 8.0, 9.0, 9.0, 9.0, 9.0, 9.0, 9.0, 9.0),
+            (12.0, 9.0, 11.0, 10.0, 10.0, 9.0, 9.0, 10.0, 10.0),
+            (13.0, 10.0, 12.0, 11.0, 10.5, 9.0, 9.0, 10.75, 10.5),
+            (11.0, 7.0, 8.0, 10.0, 10.0, 10.0, 10.0, 10.0, 10.0),
+            (9.0, 6.0, 7.0, 8.5, 9.5, 10.75, 10.5, 9.0, 9.5),
+        ];
+
+        for (high, low, close, tenkan, kijun, span_a, span_b, proj_a, proj_b) in test_data {
+            let bar = Bar::new().high(high).low(low).close(close);
+            let out = ichimoku.next(&bar);
+            assert_eq!(out.tenkan_sen, tenkan);
+            assert_eq!(out.kijun_sen, kijun);
+            assert_eq!(out.senkou_span_a, span_a);
+            assert_eq!(out.senkou_span_b, span_b);
+            assert_eq!(out.projected_span_a, proj_a);
+            assert_eq!(out.projected_span_b, proj_b);
+            assert_eq!(out.chikou_span, close);
+        }
+    }
+
+    #[test]
+    fn test_lookback() {
+        let mut ichimoku = IchimokuCloud::new(2, 3, 4, 2).unwrap();
+        assert_eq!(ichimoku.lookback(), 6);
+
+        let bar = Bar::new().high(10).low(8).close(9);
+        for _ in 0..5 {
+            ichimoku.next(&bar);
+        }
+        assert!(!ichimoku.is_ready());
+        ichimoku.next(&bar);
+        assert!(ichimoku.is_ready());
+    }
+
+    #[test]
+    fn test_peek() {
+        let bars = peek_bars();
+        let bars: Vec<&Bar> = bars.iter().collect();
+        assert_peek(IchimokuCloud::new(2, 3, 5, 3).unwrap(), &bars);
+        assert_peek(IchimokuCloud::new(1, 1, 1, 1).unwrap(), &bars);
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut ichimoku = IchimokuCloud::new(2, 3, 4, 2).unwrap();
+
+        let bar1 = Bar::new().high(10).low(8).close(9);
+        let bar2 = Bar::new().high(12).low(9).close(11);
+        let bar3 = Bar::new().high(13).low(10).close(12);
+
+        ichimoku.next(&bar1);
+        ichimoku.next(&bar2);
+        ichimoku.next(&bar3);
+
+        ichimoku.reset();
+
+        let out = ichimoku.next(&bar3);
+        assert_eq!(out.tenkan_sen, 11.5);
+        assert_eq!(out.kijun_sen, 11.5);
+        assert_eq!(out.senkou_span_a, 11.5);
+        assert_eq!(out.senkou_span_b, 11.5);
+    }
+
+    #[test]
+    fn test_default() {
+        IchimokuCloud::default();
+    }
+
+    #[test]
+    fn test_display() {
+        let indicator = IchimokuCloud::new(9, 26, 52, 26).unwrap();
+        assert_eq!(format!("{}", indicator), "ICHIMOKU(9, 26, 52, 26)");
+    }
+}
@@ -0,0 +1,313 @@
+use core::fmt;
+
+use crate::errors::{Result, TaError};
+use crate::indicators::generic::EfficiencyRatio;
+use crate::registry::{FromParams, Param};
+use crate::{Close, Lookback, Next, Num, Peek, Period, Reset};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Kaufman's adaptive moving average (KAMA).
+///
+/// An exponential moving average, which smoothing constant is adapted to the market noise.
+/// The smoothing constant is derived from the [Efficiency Ratio](struct.EfficiencyRatio.html):
+/// when the market trends, KAMA follows the price like a fast EMA, when the market is choppy,
+/// it flattens like a slow EMA.
+///
+/// # Formula
+///
+/// SC = (ER × (fast SC - slow SC) + slow SC)<sup>2</sup>
+///
+/// KAMA<sub>t</sub> = KAMA<sub>t-1</sub> + SC × (_p_<sub>t</sub> - KAMA<sub>t-1</sub>)
+///
+/// Where:
+///
+/// * _ER_ - is the [Efficiency Ratio](struct.EfficiencyRatio.html) over _period_
+/// * _fast SC_ - is 2 / (_fast_period_ + 1)
+/// * _slow SC_ - is 2 / (_slow_period_ + 1)
+/// * _p<sub>t</sub>_ - is the input value at a time period t
+///
+/// KAMA starts at the first input value.
+///
+/// # Parameters
+///
+/// * _period_ - period of the efficiency ratio (integer greater than 0). Default is 10.
+/// * _fast_period_ - period of the fast EMA (integer greater than 0). Default is 2.
+/// * _slow_period_ - period of the slow EMA (integer greater than _fast_period_). Default is 30.
+///
+/// # Example
+///
+/// ```
+/// use ta::indicators::KaufmanAdaptiveMovingAverage;
+/// use ta::Next;
+///
+/// let mut kama = KaufmanAdaptiveMovingAverage::new(2, 1, 3).unwrap();
+/// assert_eq!(kama.next(10.0), 10.0);
+/// assert_eq!(kama.next(14.0), 14.0);
+/// assert_eq!(kama.next(18.0), 18.0);
+/// assert_eq!(kama.next(14.0), 17.0);
+/// ```
+///
+/// # Links
+///
+/// * [Kaufman's Adaptive Moving Average, stockcharts](https://school.stockcharts.com/doku.php?id=technical_indicators:kaufman_s_adaptive_moving_average)
+///
+#[doc(alias = "KAMA")]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone)]
+pub struct KaufmanAdaptiveMovingAverage<N = f64> {
+    er: EfficiencyRatio<N>,
+    fast_period: usize,
+    slow_period: usize,
+    fast_sc: N,
+    slow_sc: N,
+    kama: N,
+    prev_kama: N,
+    count: usize,
+}
+
+impl<N: Num> KaufmanAdaptiveMovingAverage<N> {
+    pub fn new(period: usize, fast_period: usize, slow_period: usize) -> Result<Self> {
+        if fast_period == 0 || fast_period >= slow_period {
+            return Err(TaError::InvalidParameter);
+        }
+
+        Ok(Self {
+            er: EfficiencyRatio::new(period)?,
+            fast_period,
+            slow_period,
+            fast_sc: N::from_f64(2.0 / (fast_period + 1) as f64),
+            slow_sc: N::from_f64(2.0 / (slow_period + 1) as f64),
+            kama: N::zero(),
+            prev_kama: N::zero(),
+            count: 0,
+        })
+    }
+
+    pub fn fast_period(&self) -> usize {
+        self.fast_period
+    }
+
+    pub fn slow_period(&self) -> usize {
+        self.slow_period
+    }
+
+    fn kama(&self, prev_kama: N, er: N, input: N) -> N {
+        let sc = er * (self.fast_sc - self.slow_sc) + self.slow_sc;
+        let sc = sc * sc;
+        prev_kama + sc * (input - prev_kama)
+    }
+}
+
+impl<N: Num> Period for KaufmanAdaptiveMovingAverage<N> {
+    fn period(&self) -> usize {
+        self.er.period()
+    }
+}
+
+impl<N: Num> Lookback for KaufmanAdaptiveMovingAverage<N> {
+    fn lookback(&self) -> usize {
+        self.er.lookback()
+    }
+
+    fn is_ready(&self) -> bool {
+        self.count >= self.lookback()
+    }
+}
+
+impl<N: Num> Next<N> for KaufmanAdaptiveMovingAverage<N> {
+    type Output = N;
+
+    fn next(&mut self, input: N) -> Self::Output {
+        let er = self.er.next(input);
+        self.prev_kama = self.kama;
+        self.kama = if self.count == 0 {
+            input
+        } else {
+            self.kama(self.kama, er, input)
+        };
+        // count at least 2 inputs to tell the first one in update_last
+        if self.count < self.lookback().max(2) {
+            self.count += 1;
+        }
+        self.kama
+    }
+}
+
+impl_next_batch!(KaufmanAdaptiveMovingAverage, N => N);
+
+impl<N: Num> Peek<N> for KaufmanAdaptiveMovingAverage<N> {
+    fn peek(&self, input: N) -> Self::Output {
+        if self.count == 0 {
+            input
+        } else {
+            self.kama(self.kama, self.er.peek(input), input)
+        }
+    }
+
+    fn update_last(&mut self, input: N) -> Self::Output {
+        if self.count == 0 {
+            return self.next(input);
+        }
+
+        let er = self.er.update_last(input);
+        self.kama = if self.count == 1 {
+            input
+        } else {
+            self.kama(self.prev_kama, er, input)
+        };
+        self.kama
+    }
+}
+
+impl_for_floats! {
+    impl<T: Close<N>> Next<&T> for KaufmanAdaptiveMovingAverage<N> {
+        type Output = N;
+
+        fn next(&mut self, input: &T) -> Self::Output {
+            self.next(input.close())
+        }
+    }
+
+    impl_next_batch!(KaufmanAdaptiveMovingAverage<N>, Close => N);
+
+    impl<T: Close<N>> Peek<&T> for KaufmanAdaptiveMovingAverage<N> {
+        fn peek(&self, input: &T) -> Self::Output {
+            self.peek(input.close())
+        }
+
+        fn update_last(&mut self, input: &T) -> Self::Output {
+            self.update_last(input.close())
+        }
+    }
+}
+
+impl<N: Num> Reset for KaufmanAdaptiveMovingAverage<N> {
+    fn reset(&mut self) {
+        self.er.reset();
+        self.kama = N::zero();
+        self.prev_kama = N::zero();
+        self.count = 0;
+    }
+}
+
+impl<N: Num> Default for KaufmanAdaptiveMovingAverage<N> {
+    fn default() -> Self {
+        Self::new(10, 2, 30).unwrap()
+    }
+}
+
+impl<N: Num> fmt::Display for KaufmanAdaptiveMovingAverage<N> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "KAMA({}, {}, {})",
+            self.er.period(),
+            self.fast_period,
+            self.slow_period
+        )
+    }
+}
+
+impl<N: Num> FromParams for KaufmanAdaptiveMovingAverage<N> {
+    const NAME: &'static str = "KAMA";
+
+    fn from_params(params: &[Param]) -> Result<Self> {
+        match *params {
+            [period, fast_period, slow_period] => Self::new(
+                period.period()?,
+                fast_period.period()?,
+                slow_period.period()?,
+            ),
+            _ => Err(TaError::InvalidParameter),
+        }
+    }
+}
+
+impl_from_str!(KaufmanAdaptiveMovingAverage);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::indicators::KaufmanAdaptiveMovingAverage;
+    use crate::test_helper::*;
+    type Kama = KaufmanAdaptiveMovingAverage;
+
+    test_indicator!(Kama);
+
+    #[test]
+    fn test_new() {
+        assert!(Kama::new(0, 2, 30).is_err());
+        assert!(Kama::new(10, 0, 30).is_err());
+        assert!(Kama::new(10, 2, 0).is_err());
+        assert!(Kama::new(10, 30, 2).is_err());
+        assert!(Kama::new(10, 2, 2).is_err());
+        assert!(Kama::new(1, 1, 2).is_ok());
+    }
+
+    #[test]
+    fn test_next() {
+        let mut kama = Kama::new(2, 1, 3).unwrap();
+
+        assert_eq!(kama.next(10.0), 10.0);
+        // trending, ER = 1
+        assert_eq!(kama.next(14.0), 14.0);
+        assert_eq!(kama.next(18.0), 18.0);
+        // back to the price of 2 periods ago, ER = 0
+        assert_eq!(kama.next(14.0), 17.0);
+        // ER = 0.6
+        assert_eq!(round(kama.next(15.0)), 15.72);
+    }
+
+    #[test]
+    fn test_next_period_1() {
+        let mut kama = Kama::new(1, 2, 30).unwrap();
+
+        assert_eq!(kama.next(10.0), 10.0);
+        assert_eq!(round(kama.next(13.0)), 11.333);
+    }
+
+    #[test]
+    fn test_peek_period_1() {
+        assert_peek(Kama::new(1, 2, 30).unwrap(), &peek_inputs());
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut kama = Kama::new(2, 1, 3).unwrap();
+
+        assert_eq!(kama.next(10.0), 10.0);
+        assert_eq!(kama.next(14.0), 14.0);
+
+        kama.reset();
+
+        assert_eq!(kama.next(14.0), 14.0);
+        assert_eq!(kama.next(10.0), 10.0);
+    }
+
+    #[test]
+    fn test_lookback() {
+        let mut kama = Kama::new(3, 2, 30).unwrap();
+        assert_eq!(kama.lookback(), 3);
+
+        for _ in 0..2 {
+            kama.next(1.0);
+            assert!(!kama.is_ready());
+        }
+        kama.next(1.0);
+        assert!(kama.is_ready());
+    }
+
+    #[test]
+    fn test_default() {
+        let kama = Kama::default();
+        assert_eq!(kama.fast_period(), 2);
+        assert_eq!(kama.slow_period(), 30);
+    }
+
+    #[test]
+    fn test_display() {
+        let kama = Kama::new(10, 2, 30).unwrap();
+        assert_eq!(format!("{}", kama), "KAMA(10, 2, 30)");
+    }
+}
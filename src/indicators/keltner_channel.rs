@@ -1,8 +1,9 @@
-use std::fmt;
+use core::fmt;
 
-use crate::errors::Result;
-use crate::indicators::{AverageTrueRange, ExponentialMovingAverage};
-use crate::{Close, High, Low, Next, Period, Reset};
+use crate::errors::{Result, TaError};
+use crate::indicators::generic::{AverageTrueRange, MovingAverage, MovingAverageType};
+use crate::registry::{FromParams, Param};
+use crate::{Close, High, Lookback, Low, Next, Num, Peek, Period, Reset};
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
@@ -22,6 +23,12 @@ use serde::{Deserialize, Serialize};
 ///  * _KC<sub>Upper Band</sub>_ = EMA + ATR of observation * multipler (usually 2.0)
 ///  * _KC<sub>Lower Band</sub>_ = EMA - ATR of observation * multipler (usually 2.0)
 ///
+/// Both the middle band and the ATR use an EMA by default. Use
+/// [`with_moving_average`](#method.with_moving_average) to choose another
+/// [moving average type](enum.MovingAverageType.html) for the middle band,
+/// [`with_smoothing`](#method.with_smoothing) for the ATR, or
+/// [`with_moving_averages`](#method.with_moving_averages) for both.
+///
 /// # Example
 ///
 ///```
@@ -49,95 +56,242 @@ use serde::{Deserialize, Serialize};
 #[doc(alias = "KC")]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[derive(Debug, Clone)]
-pub struct KeltnerChannel {
+pub struct KeltnerChannel<N = f64> {
     period: usize,
     multiplier: f64,
-    atr: AverageTrueRange,
-    ema: ExponentialMovingAverage,
+    atr: AverageTrueRange<N>,
+    average: MovingAverage<N>,
 }
 
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[derive(Debug, Clone, PartialEq)]
-pub struct KeltnerChannelOutput {
-    pub average: f64,
-    pub upper: f64,
-    pub lower: f64,
+pub struct KeltnerChannelOutput<N = f64> {
+    pub average: N,
+    pub upper: N,
+    pub lower: N,
 }
 
-impl KeltnerChannel {
+impl_fields!(KeltnerChannelOutput {
+    average,
+    upper,
+    lower
+});
+
+impl<N: Num> KeltnerChannel<N> {
     pub fn new(period: usize, multiplier: f64) -> Result<Self> {
+        Self::with_smoothing(period, multiplier, MovingAverageType::Exponential)
+    }
+
+    pub fn with_moving_average(
+        period: usize,
+        multiplier: f64,
+        moving_average: MovingAverageType,
+    ) -> Result<Self> {
+        Self::with_moving_averages(
+            period,
+            multiplier,
+            moving_average,
+            MovingAverageType::Exponential,
+        )
+    }
+
+    pub fn with_smoothing(
+        period: usize,
+        multiplier: f64,
+        smoothing: MovingAverageType,
+    ) -> Result<Self> {
+        Self::with_moving_averages(
+            period,
+            multiplier,
+            MovingAverageType::Exponential,
+            smoothing,
+        )
+    }
+
+    pub fn with_moving_averages(
+        period: usize,
+        multiplier: f64,
+        moving_average: MovingAverageType,
+        smoothing: MovingAverageType,
+    ) -> Result<Self> {
         Ok(Self {
             period,
             multiplier,
-            atr: AverageTrueRange::new(period)?,
-            ema: ExponentialMovingAverage::new(period)?,
+            atr: AverageTrueRange::with_smoothing(period, smoothing)?,
+            average: MovingAverage::new(moving_average, period)?,
         })
     }
 
+    pub fn moving_average(&self) -> MovingAverageType {
+        self.average.ma_type()
+    }
+
+    pub fn smoothing(&self) -> MovingAverageType {
+        self.atr.smoothing()
+    }
+
     pub fn multiplier(&self) -> f64 {
         self.multiplier
     }
 }
 
-impl Period for KeltnerChannel {
+impl<N: Num> Period for KeltnerChannel<N> {
     fn period(&self) -> usize {
         self.period
     }
 }
 
-impl Next<f64> for KeltnerChannel {
-    type Output = KeltnerChannelOutput;
+impl<N: Num> Lookback for KeltnerChannel<N> {
+    fn lookback(&self) -> usize {
+        self.average.lookback().max(self.atr.lookback())
+    }
+
+    fn is_ready(&self) -> bool {
+        self.average.is_ready() && self.atr.is_ready()
+    }
+}
+
+impl<N: Num> Next<N> for KeltnerChannel<N> {
+    type Output = KeltnerChannelOutput<N>;
 
-    fn next(&mut self, input: f64) -> Self::Output {
+    fn next(&mut self, input: N) -> Self::Output {
         let atr = self.atr.next(input);
-        let average = self.ema.next(input);
+        let average = self.average.next(input);
 
         Self::Output {
             average,
-            upper: average + atr * self.multiplier,
-            lower: average - atr * self.multiplier,
+            upper: average + atr * N::from_f64(self.multiplier),
+            lower: average - atr * N::from_f64(self.multiplier),
         }
     }
 }
 
-impl<T: Close + High + Low> Next<&T> for KeltnerChannel {
-    type Output = KeltnerChannelOutput;
+impl_next_batch!(KeltnerChannel, N => KeltnerChannelOutput<N>);
 
-    fn next(&mut self, input: &T) -> Self::Output {
-        let typical_price = (input.close() + input.high() + input.low()) / 3.0;
+impl<N: Num> Peek<N> for KeltnerChannel<N> {
+    fn peek(&self, input: N) -> Self::Output {
+        let atr = self.atr.peek(input);
+        let average = self.average.peek(input);
 
-        let average = self.ema.next(typical_price);
-        let atr = self.atr.next(input);
+        Self::Output {
+            average,
+            upper: average + atr * N::from_f64(self.multiplier),
+            lower: average - atr * N::from_f64(self.multiplier),
+        }
+    }
+
+    fn update_last(&mut self, input: N) -> Self::Output {
+        let atr = self.atr.update_last(input);
+        let average = self.average.update_last(input);
 
         Self::Output {
             average,
-            upper: average + atr * self.multiplier,
-            lower: average - atr * self.multiplier,
+            upper: average + atr * N::from_f64(self.multiplier),
+            lower: average - atr * N::from_f64(self.multiplier),
         }
     }
 }
 
-impl Reset for KeltnerChannel {
+impl_for_floats! {
+    impl<T: Close<N> + High<N> + Low<N>> Next<&T> for KeltnerChannel<N> {
+        type Output = KeltnerChannelOutput<N>;
+
+        fn next(&mut self, input: &T) -> Self::Output {
+            let typical_price = (input.close() + input.high() + input.low()) / 3.0;
+
+            let average = self.average.next(typical_price);
+            let atr = self.atr.next(input);
+
+            Self::Output {
+                average,
+                upper: average + atr * N::from_f64(self.multiplier),
+                lower: average - atr * N::from_f64(self.multiplier),
+            }
+        }
+    }
+
+    impl_next_batch!(KeltnerChannel<N>, Close + High + Low => KeltnerChannelOutput<N>);
+
+    impl<T: Close<N> + High<N> + Low<N>> Peek<&T> for KeltnerChannel<N> {
+        fn peek(&self, input: &T) -> Self::Output {
+            let typical_price = (input.close() + input.high() + input.low()) / 3.0;
+
+            let average = self.average.peek(typical_price);
+            let atr = self.atr.peek(input);
+
+            Self::Output {
+                average,
+                upper: average + atr * N::from_f64(self.multiplier),
+                lower: average - atr * N::from_f64(self.multiplier),
+            }
+        }
+
+        fn update_last(&mut self, input: &T) -> Self::Output {
+            let typical_price = (input.close() + input.high() + input.low()) / 3.0;
+
+            let average = self.average.update_last(typical_price);
+            let atr = self.atr.update_last(input);
+
+            Self::Output {
+                average,
+                upper: average + atr * N::from_f64(self.multiplier),
+                lower: average - atr * N::from_f64(self.multiplier),
+            }
+        }
+    }
+}
+
+impl<N: Num> Reset for KeltnerChannel<N> {
     fn reset(&mut self) {
         self.atr.reset();
-        self.ema.reset();
+        self.average.reset();
     }
 }
 
-impl Default for KeltnerChannel {
+impl<N: Num> Default for KeltnerChannel<N> {
     fn default() -> Self {
         Self::new(10, 2_f64).unwrap()
     }
 }
 
-impl fmt::Display for KeltnerChannel {
+impl<N: Num> fmt::Display for KeltnerChannel<N> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "KC({}, {})", self.period, self.multiplier)
+        match (self.moving_average(), self.smoothing()) {
+            (MovingAverageType::Exponential, MovingAverageType::Exponential) => {
+                write!(f, "KC({}, {})", self.period, self.multiplier)
+            }
+            (moving_average, smoothing) => write!(
+                f,
+                "KC({}, {}, {}, {})",
+                self.period, self.multiplier, moving_average, smoothing
+            ),
+        }
+    }
+}
+
+impl<N: Num> FromParams for KeltnerChannel<N> {
+    const NAME: &'static str = "KC";
+
+    fn from_params(params: &[Param]) -> Result<Self> {
+        match *params {
+            [period, multiplier] => Self::new(period.period()?, multiplier.number()?),
+            [period, multiplier, moving_average, smoothing] => Self::with_moving_averages(
+                period.period()?,
+                multiplier.number()?,
+                moving_average.moving_average()?,
+                smoothing.moving_average()?,
+            ),
+            _ => Err(TaError::InvalidParameter),
+        }
     }
 }
 
+impl_from_str!(KeltnerChannel);
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::indicators::KeltnerChannel;
     use crate::test_helper::*;
 
     test_indicator!(KeltnerChannel);
@@ -174,6 +328,41 @@ mod tests {
         assert_eq!(round(d.lower), -3.75);
     }
 
+    #[test]
+    fn test_next_wilder() {
+        let mut kc = KeltnerChannel::with_smoothing(3, 2.0_f64, MovingAverageType::Wilder).unwrap();
+        assert_eq!(kc.smoothing(), MovingAverageType::Wilder);
+
+        let a = kc.next(2.0);
+        let b = kc.next(5.0);
+        let c = kc.next(1.0);
+
+        assert_eq!(round(a.upper), 2.0);
+        assert_eq!(round(b.upper), 6.5);
+        assert_eq!(round(c.average), 2.25);
+        assert_eq!(round(c.upper), 6.917);
+        assert_eq!(round(c.lower), -2.417);
+    }
+
+    #[test]
+    fn test_next_wma() {
+        let mut kc =
+            KeltnerChannel::with_moving_average(3, 2.0_f64, MovingAverageType::Weighted).unwrap();
+        assert_eq!(kc.moving_average(), MovingAverageType::Weighted);
+        assert_eq!(kc.smoothing(), MovingAverageType::Exponential);
+
+        let a = kc.next(2.0);
+        let b = kc.next(5.0);
+        let c = kc.next(1.0);
+
+        // WMA: (2 * 1 + 5 * 2 + 1 * 3) / 6 = 2.5, ATR: 2.75
+        assert_eq!(round(a.average), 2.0);
+        assert_eq!(round(b.average), 4.0);
+        assert_eq!(round(c.average), 2.5);
+        assert_eq!(round(c.upper), 8.0);
+        assert_eq!(round(c.lower), -3.0);
+    }
+
     #[test]
     fn test_next_with_data_item() {
         let mut kc = KeltnerChannel::new(3, 2.0_f64).unwrap();
@@ -233,5 +422,10 @@ mod tests {
     fn test_display() {
         let kc = KeltnerChannel::new(10, 3.0_f64).unwrap();
         assert_eq!(format!("{}", kc), "KC(10, 3)");
+        let kc = KeltnerChannel::with_smoothing(10, 3.0_f64, MovingAverageType::Wilder).unwrap();
+        assert_eq!(format!("{}", kc), "KC(10, 3, EMA, RMA)");
+        let kc =
+            KeltnerChannel::with_moving_average(10, 3.0_f64, MovingAverageType::Weighted).unwrap();
+        assert_eq!(format!("{}", kc), "KC(10, 3, WMA, EMA)");
     }
 }
@@ -1,10 +1,13 @@
-use std::fmt;
+use alloc::boxed::Box;
+use alloc::vec;
+use core::fmt;
 
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
 use crate::errors::{Result, TaError};
-use crate::{Close, Next, Period, Reset};
+use crate::registry::{FromParams, Param};
+use crate::{Close, Lookback, Next, Num, Peek, Period, Reset};
 
 /// Mean Absolute Deviation (MAD)
 ///
@@ -29,15 +32,15 @@ use crate::{Close, Next, Period, Reset};
 ///
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[derive(Debug, Clone)]
-pub struct MeanAbsoluteDeviation {
+pub struct MeanAbsoluteDeviation<N = f64> {
     period: usize,
     index: usize,
     count: usize,
-    sum: f64,
-    deque: Box<[f64]>,
+    sum: N,
+    deque: Box<[N]>,
 }
 
-impl MeanAbsoluteDeviation {
+impl<N: Num> MeanAbsoluteDeviation<N> {
     pub fn new(period: usize) -> Result<Self> {
         match period {
             0 => Err(TaError::InvalidParameter),
@@ -45,25 +48,45 @@ impl MeanAbsoluteDeviation {
                 period,
                 index: 0,
                 count: 0,
-                sum: 0.0,
-                deque: vec![0.0; period].into_boxed_slice(),
+                sum: N::zero(),
+                deque: vec![N::zero(); period].into_boxed_slice(),
             }),
         }
     }
+
+    fn deviation(&self) -> N {
+        let mean = self.sum / N::from_usize(self.count);
+
+        let mut mad = N::zero();
+        for value in &self.deque[..self.count] {
+            mad += (*value - mean).abs();
+        }
+        mad / N::from_usize(self.count)
+    }
 }
 
-impl Period for MeanAbsoluteDeviation {
+impl<N: Num> Period for MeanAbsoluteDeviation<N> {
     fn period(&self) -> usize {
         self.period
     }
 }
 
-impl Next<f64> for MeanAbsoluteDeviation {
-    type Output = f64;
+impl<N: Num> Lookback for MeanAbsoluteDeviation<N> {
+    fn lookback(&self) -> usize {
+        self.period
+    }
+
+    fn is_ready(&self) -> bool {
+        self.count == self.period
+    }
+}
+
+impl<N: Num> Next<N> for MeanAbsoluteDeviation<N> {
+    type Output = N;
 
-    fn next(&mut self, input: f64) -> Self::Output {
+    fn next(&mut self, input: N) -> Self::Output {
         self.sum = if self.count < self.period {
-            self.count = self.count + 1;
+            self.count += 1;
             self.sum + input
         } else {
             self.sum + input - self.deque[self.index]
@@ -76,50 +99,115 @@ impl Next<f64> for MeanAbsoluteDeviation {
             0
         };
 
-        let mean = self.sum / self.count as f64;
+        self.deviation()
+    }
+}
 
-        let mut mad = 0.0;
-        for value in &self.deque[..self.count] {
-            mad += (value - mean).abs();
+impl_next_batch!(MeanAbsoluteDeviation, N => N);
+
+impl<N: Num> Peek<N> for MeanAbsoluteDeviation<N> {
+    fn peek(&self, input: N) -> Self::Output {
+        // The value at `index` drops out of the window once it's full.
+        let (count, sum, dropped) = if self.count < self.period {
+            (self.count + 1, self.sum + input, self.period)
+        } else {
+            (
+                self.count,
+                self.sum + input - self.deque[self.index],
+                self.index,
+            )
+        };
+
+        let mean = sum / N::from_usize(count);
+
+        let mut mad = (input - mean).abs();
+        for (i, value) in self.deque[..self.count].iter().enumerate() {
+            if i != dropped {
+                mad += (*value - mean).abs();
+            }
         }
-        mad / self.count as f64
+        mad / N::from_usize(count)
+    }
+
+    fn update_last(&mut self, input: N) -> Self::Output {
+        if self.count == 0 {
+            return self.next(input);
+        }
+
+        let last = if self.index == 0 {
+            self.period - 1
+        } else {
+            self.index - 1
+        };
+        self.sum = self.sum + input - self.deque[last];
+        self.deque[last] = input;
+
+        self.deviation()
     }
 }
 
-impl<T: Close> Next<&T> for MeanAbsoluteDeviation {
-    type Output = f64;
+impl_for_floats! {
+    impl<T: Close<N>> Next<&T> for MeanAbsoluteDeviation<N> {
+        type Output = N;
 
-    fn next(&mut self, input: &T) -> Self::Output {
-        self.next(input.close())
+        fn next(&mut self, input: &T) -> Self::Output {
+            self.next(input.close())
+        }
+    }
+
+    impl_next_batch!(MeanAbsoluteDeviation<N>, Close => N);
+
+    impl<T: Close<N>> Peek<&T> for MeanAbsoluteDeviation<N> {
+        fn peek(&self, input: &T) -> Self::Output {
+            self.peek(input.close())
+        }
+
+        fn update_last(&mut self, input: &T) -> Self::Output {
+            self.update_last(input.close())
+        }
     }
 }
 
-impl Reset for MeanAbsoluteDeviation {
+impl<N: Num> Reset for MeanAbsoluteDeviation<N> {
     fn reset(&mut self) {
         self.index = 0;
         self.count = 0;
-        self.sum = 0.0;
+        self.sum = N::zero();
         for i in 0..self.period {
-            self.deque[i] = 0.0;
+            self.deque[i] = N::zero();
         }
     }
 }
 
-impl Default for MeanAbsoluteDeviation {
+impl<N: Num> Default for MeanAbsoluteDeviation<N> {
     fn default() -> Self {
         Self::new(9).unwrap()
     }
 }
 
-impl fmt::Display for MeanAbsoluteDeviation {
+impl<N: Num> fmt::Display for MeanAbsoluteDeviation<N> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         write!(f, "MAD({})", self.period)
     }
 }
 
+impl<N: Num> FromParams for MeanAbsoluteDeviation<N> {
+    const NAME: &'static str = "MAD";
+
+    fn from_params(params: &[Param]) -> Result<Self> {
+        match *params {
+            [period] => Self::new(period.period()?),
+            _ => Err(TaError::InvalidParameter),
+        }
+    }
+}
+
+impl_from_str!(MeanAbsoluteDeviation);
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::indicators::MeanAbsoluteDeviation;
     use crate::test_helper::*;
 
     test_indicator!(MeanAbsoluteDeviation);
@@ -1,12 +1,19 @@
-use std::fmt;
+use alloc::boxed::Box;
+use alloc::collections::VecDeque;
+use alloc::vec;
+use core::fmt;
 
 use crate::errors::{Result, TaError};
-use crate::{Low, Next, Period, Reset};
+use crate::registry::{FromParams, Param};
+use crate::{Lookback, Low, Next, Num, Peek, Period, Reset};
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
 /// Returns the lowest value in a given time frame.
 ///
+/// It keeps a monotonic deque of the candidates for the minimum, so every input is processed in
+/// amortized constant time, no matter how large the period is.
+///
 /// # Parameters
 ///
 /// * _period_ - size of the time frame (integer greater than 0). Default value is 14.
@@ -25,101 +32,194 @@ use serde::{Deserialize, Serialize};
 /// ```
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[derive(Debug, Clone)]
-pub struct Minimum {
+pub struct Minimum<N = f64> {
     period: usize,
-    min_index: usize,
-    cur_index: usize,
-    deque: Box<[f64]>,
+    // number of consumed inputs, the position of the next input
+    position: usize,
+    // the inputs of the window, indexed by `position % period`
+    values: Box<[N]>,
+    // positions and values of the candidates, the values are increasing from the front,
+    // so the front is the minimum
+    candidates: VecDeque<(usize, N)>,
 }
 
-impl Minimum {
+impl<N: Num> Minimum<N> {
     pub fn new(period: usize) -> Result<Self> {
         match period {
             0 => Err(TaError::InvalidParameter),
             _ => Ok(Self {
                 period,
-                min_index: 0,
-                cur_index: 0,
-                deque: vec![f64::INFINITY; period].into_boxed_slice(),
+                position: 0,
+                values: vec![N::zero(); period].into_boxed_slice(),
+                candidates: VecDeque::with_capacity(period),
             }),
         }
     }
 
-    fn find_min_index(&self) -> usize {
-        let mut min = f64::INFINITY;
-        let mut index: usize = 0;
+    /// Returns the number of inputs consumed since the current minimum, e.g. 0 when the last
+    /// input is the minimum. When the minimum occurs more than once, the latest one counts.
+    pub fn age(&self) -> usize {
+        match self.candidates.front() {
+            Some(&(position, _)) => self.position - 1 - position,
+            None => 0,
+        }
+    }
 
-        for (i, &val) in self.deque.iter().enumerate() {
-            if val < min {
-                min = val;
-                index = i;
+    // Adds the input at `position`, dropping the candidates, which can't be the minimum
+    // anymore.
+    fn push(&mut self, position: usize, input: N) {
+        while let Some(&(_, value)) = self.candidates.back() {
+            if value >= input {
+                self.candidates.pop_back();
+            } else {
+                break;
             }
         }
+        self.candidates.push_back((position, input));
+    }
 
-        index
+    // Returns the minimum and its age, if the input was consumed.
+    pub(super) fn peek_with_age(&self, input: N) -> (N, usize) {
+        // The front candidate leaves the window, when it's `period` inputs old.
+        let mut candidates = self.candidates.iter();
+        let candidate = match candidates.next() {
+            Some(&(position, _)) if position + self.period == self.position => candidates.next(),
+            front => front,
+        };
+
+        match candidate {
+            Some(&(position, value)) if value < input => (value, self.position - position),
+            _ => (input, 0),
+        }
     }
 }
 
-impl Period for Minimum {
+impl<N: Num> Period for Minimum<N> {
     fn period(&self) -> usize {
         self.period
     }
 }
 
-impl Next<f64> for Minimum {
-    type Output = f64;
+impl<N: Num> Lookback for Minimum<N> {
+    fn lookback(&self) -> usize {
+        self.period
+    }
 
-    fn next(&mut self, input: f64) -> Self::Output {
-        self.deque[self.cur_index] = input;
+    fn is_ready(&self) -> bool {
+        self.position >= self.period
+    }
+}
+
+impl<N: Num> Next<N> for Minimum<N> {
+    type Output = N;
+
+    fn next(&mut self, input: N) -> Self::Output {
+        let position = self.position;
+        self.position += 1;
+        self.values[position % self.period] = input;
 
-        if input < self.deque[self.min_index] {
-            self.min_index = self.cur_index;
-        } else if self.min_index == self.cur_index {
-            self.min_index = self.find_min_index();
+        if let Some(&(front, _)) = self.candidates.front() {
+            if front + self.period == position {
+                self.candidates.pop_front();
+            }
         }
+        self.push(position, input);
+
+        self.candidates[0].1
+    }
+}
 
-        self.cur_index = if self.cur_index + 1 < self.period {
-            self.cur_index + 1
-        } else {
-            0
+impl_next_batch!(Minimum, N => N);
+
+impl<N: Num> Peek<N> for Minimum<N> {
+    fn peek(&self, input: N) -> Self::Output {
+        self.peek_with_age(input).0
+    }
+
+    fn update_last(&mut self, input: N) -> Self::Output {
+        if self.position == 0 {
+            return self.next(input);
+        }
+
+        // The last input is always the back candidate. Remove it and bring back the inputs it
+        // has dropped, which are the inputs after the new back candidate.
+        let last = self.position - 1;
+        self.candidates.pop_back();
+        let first = match self.candidates.back() {
+            Some(&(position, _)) => position + 1,
+            None => (last + 1).saturating_sub(self.period),
         };
+        for position in first..last {
+            self.push(position, self.values[position % self.period]);
+        }
 
-        self.deque[self.min_index]
+        self.values[last % self.period] = input;
+        self.push(last, input);
+
+        self.candidates[0].1
     }
 }
 
-impl<T: Low> Next<&T> for Minimum {
-    type Output = f64;
+impl_for_floats! {
+    impl<T: Low<N>> Next<&T> for Minimum<N> {
+        type Output = N;
+
+        fn next(&mut self, input: &T) -> Self::Output {
+            self.next(input.low())
+        }
+    }
+
+    impl_next_batch!(Minimum<N>, Low => N);
+
+    impl<T: Low<N>> Peek<&T> for Minimum<N> {
+        fn peek(&self, input: &T) -> Self::Output {
+            self.peek(input.low())
+        }
 
-    fn next(&mut self, input: &T) -> Self::Output {
-        self.next(input.low())
+        fn update_last(&mut self, input: &T) -> Self::Output {
+            self.update_last(input.low())
+        }
     }
 }
 
-impl Reset for Minimum {
+impl<N: Num> Reset for Minimum<N> {
     fn reset(&mut self) {
-        for i in 0..self.period {
-            self.deque[i] = f64::INFINITY;
-        }
+        self.position = 0;
+        self.candidates.clear();
     }
 }
 
-impl Default for Minimum {
+impl<N: Num> Default for Minimum<N> {
     fn default() -> Self {
         Self::new(14).unwrap()
     }
 }
 
-impl fmt::Display for Minimum {
+impl<N: Num> fmt::Display for Minimum<N> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         write!(f, "MIN({})", self.period)
     }
 }
 
+impl<N: Num> FromParams for Minimum<N> {
+    const NAME: &'static str = "MIN";
+
+    fn from_params(params: &[Param]) -> Result<Self> {
+        match *params {
+            [period] => Self::new(period.period()?),
+            _ => Err(TaError::InvalidParameter),
+        }
+    }
+}
+
+impl_from_str!(Minimum);
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::indicators::Minimum;
     use crate::test_helper::*;
+    use crate::NextBatch;
 
     test_indicator!(Minimum);
 
@@ -159,6 +259,101 @@ mod tests {
         assert_eq!(min.next(&bar(5.0)), 1.2);
     }
 
+    #[test]
+    fn test_age() {
+        let mut min = Minimum::new(3).unwrap();
+        assert_eq!(min.age(), 0);
+
+        min.next(1.0);
+        assert_eq!(min.age(), 0);
+        min.next(3.8);
+        assert_eq!(min.age(), 1);
+        min.next(2.0);
+        assert_eq!(min.age(), 2);
+        // 1.0 is out of the window
+        min.next(3.0);
+        assert_eq!(min.age(), 1);
+        // the latest of equal values
+        min.next(2.0);
+        assert_eq!(min.age(), 0);
+        min.next(4.0);
+        assert_eq!(min.age(), 1);
+        min.next(4.0);
+        assert_eq!(min.age(), 2);
+
+        assert_eq!(min.peek_with_age(5.0), (4.0, 1));
+        assert_eq!(min.peek_with_age(0.0), (0.0, 0));
+
+        min.update_last(1.0);
+        assert_eq!(min.age(), 0);
+        min.update_last(5.0);
+        assert_eq!(min.age(), 2);
+
+        min.reset();
+        min.next(1.0);
+        assert_eq!(min.age(), 0);
+    }
+
+    #[test]
+    fn test_update_last_restores_candidates() {
+        let mut min = Minimum::new(3).unwrap();
+        min.next(1.0);
+        min.next(3.0);
+        assert_eq!(min.next(0.0), 0.0);
+
+        // 1.0 and 3.0 were dropped by 0.0
+        assert_eq!(min.update_last(5.0), 1.0);
+        assert_eq!(min.age(), 2);
+        assert_eq!(min.next(6.0), 3.0);
+        assert_eq!(min.age(), 2);
+    }
+
+    #[test]
+    fn test_next_scan() {
+        // trending up, down and sideways, with replaced inputs
+        let inputs: Vec<f64> = (0..200)
+            .map(|i| match i / 50 {
+                0 => i as f64,
+                1 => (100 - i) as f64,
+                _ => (i * 37 % 23) as f64,
+            })
+            .collect();
+
+        let period = 7;
+        let mut min = Minimum::new(period).unwrap();
+        for (i, &input) in inputs.iter().enumerate() {
+            if i % 3 == 0 {
+                min.next(input + 10.0);
+                min.update_last(input);
+            } else {
+                min.next(input);
+            }
+
+            let window = &inputs[(i + 1).saturating_sub(period)..=i];
+            let expected = window.iter().fold(f64::INFINITY, |acc, &x| acc.min(x));
+            let age = window.len() - 1 - window.iter().rposition(|&x| x == expected).unwrap();
+            assert_eq!((min.update_last(input), min.age()), (expected, age));
+        }
+    }
+
+    #[test]
+    fn test_next_batch() {
+        let input = [4.0, 1.2, 5.0, 3.0, 4.0, 0.0, -1.0, -2.0, -1.5];
+
+        let mut indicator = Minimum::new(3).unwrap();
+        let expected: Vec<f64> = input.iter().map(|&x| indicator.next(x)).collect();
+
+        let mut indicator = Minimum::new(3).unwrap();
+        assert_eq!(indicator.next_batch(&input[..4]), expected[..4]);
+        assert_eq!(indicator.next_batch(&input[4..]), expected[4..]);
+
+        let bars: Vec<Bar> = input.iter().map(|&x| Bar::new().low(x)).collect();
+        let mut output = vec![0.0; bars.len()];
+        let mut indicator = Minimum::new(3).unwrap();
+        indicator.next_batch_into(&bars, &mut output);
+        assert_eq!(output, expected);
+    }
+
     #[test]
     fn test_reset() {
         let mut min = Minimum::new(10).unwrap();
@@ -1,78 +1,260 @@
+macro_rules! f64_indicators {
+    ($($name:ident),* $(,)?) => {
+        $(
+            #[doc = concat!("[", stringify!($name), "](generic/struct.", stringify!($name), ".html) of `f64` values.")]
+            pub type $name = generic::$name;
+        )*
+    };
+}
+
 mod exponential_moving_average;
-pub use self::exponential_moving_average::ExponentialMovingAverage;
+f64_indicators!(ExponentialMovingAverage);
 
 mod double_exponential_moving_average;
-pub use self::double_exponential_moving_average::DoubleExponentialMovingAverage;
+f64_indicators!(DoubleExponentialMovingAverage);
 
 mod triple_exponential_moving_average;
-pub use self::triple_exponential_moving_average::TripleExponentialAverage;
+f64_indicators!(TripleExponentialMovingAverage);
+
+mod triple_exponential_average;
+f64_indicators!(TripleExponentialAverage);
+
+mod triple_exponential_average_with_signal;
+f64_indicators!(
+    TripleExponentialAverageOutput,
+    TripleExponentialAverageWithSignal
+);
 
 mod weighted_moving_average;
-pub use self::weighted_moving_average::WeightedMovingAverage;
+f64_indicators!(WeightedMovingAverage);
 
 mod simple_moving_average;
-pub use self::simple_moving_average::SimpleMovingAverage;
+f64_indicators!(SimpleMovingAverage);
+
+mod wilder_moving_average;
+f64_indicators!(WilderMovingAverage);
+
+mod moving_average;
+pub use self::moving_average::MovingAverageType;
+f64_indicators!(MovingAverage);
 
 mod standard_deviation;
-pub use self::standard_deviation::StandardDeviation;
+f64_indicators!(StandardDeviation);
 
 mod mean_absolute_deviation;
-pub use self::mean_absolute_deviation::MeanAbsoluteDeviation;
+f64_indicators!(MeanAbsoluteDeviation);
 
 mod relative_strength_index;
-pub use self::relative_strength_index::RelativeStrengthIndex;
+f64_indicators!(RelativeStrengthIndex);
 
 mod minimum;
-pub use self::minimum::Minimum;
+f64_indicators!(Minimum);
 
 mod maximum;
-pub use self::maximum::Maximum;
+f64_indicators!(Maximum);
 
 mod fast_stochastic;
-pub use self::fast_stochastic::FastStochastic;
+f64_indicators!(FastStochastic);
 
 mod slow_stochastic;
-pub use self::slow_stochastic::SlowStochastic;
+f64_indicators!(SlowStochastic);
+
+mod full_stochastic;
+f64_indicators!(FullStochastic, FullStochasticOutput);
+
+mod williams_r;
+f64_indicators!(WilliamsR);
+
+mod ultimate_oscillator;
+f64_indicators!(UltimateOscillator);
+
+mod stochastic_rsi;
+f64_indicators!(StochasticRsi, StochasticRsiOutput);
 
 mod true_range;
-pub use self::true_range::TrueRange;
+f64_indicators!(TrueRange);
 
 mod average_true_range;
-pub use self::average_true_range::AverageTrueRange;
+f64_indicators!(AverageTrueRange);
+
+mod aroon;
+f64_indicators!(Aroon, AroonOutput);
+
+mod average_directional_index;
+f64_indicators!(AverageDirectionalIndex, AverageDirectionalIndexOutput);
 
 mod moving_average_convergence_divergence;
-pub use self::moving_average_convergence_divergence::{
-    MovingAverageConvergenceDivergence, MovingAverageConvergenceDivergenceOutput,
-};
+f64_indicators!(
+    MovingAverageConvergenceDivergence,
+    MovingAverageConvergenceDivergenceOutput
+);
 
 mod percentage_price_oscillator;
-pub use self::percentage_price_oscillator::{
-    PercentagePriceOscillator, PercentagePriceOscillatorOutput,
-};
+f64_indicators!(PercentagePriceOscillator, PercentagePriceOscillatorOutput);
 
 mod commodity_channel_index;
-pub use self::commodity_channel_index::CommodityChannelIndex;
+f64_indicators!(CommodityChannelIndex);
 
 mod efficiency_ratio;
-pub use self::efficiency_ratio::EfficiencyRatio;
+f64_indicators!(EfficiencyRatio);
 
 mod bollinger_bands;
-pub use self::bollinger_bands::{BollingerBands, BollingerBandsOutput};
+f64_indicators!(BollingerBands, BollingerBandsOutput);
 
 mod chandelier_exit;
-pub use self::chandelier_exit::{ChandelierExit, ChandelierExitOutput};
+f64_indicators!(ChandelierExit, ChandelierExitOutput);
 
 mod keltner_channel;
-pub use self::keltner_channel::{KeltnerChannel, KeltnerChannelOutput};
+f64_indicators!(KeltnerChannel, KeltnerChannelOutput);
+
+mod parabolic_sar;
+pub use self::parabolic_sar::Trend;
+f64_indicators!(ParabolicSar, ParabolicSarOutput);
+
+mod ichimoku_cloud;
+f64_indicators!(IchimokuCloud, IchimokuCloudOutput);
 
 mod rate_of_change;
-pub use self::rate_of_change::RateOfChange;
+f64_indicators!(RateOfChange);
 
 mod money_flow_index;
-pub use self::money_flow_index::MoneyFlowIndex;
+f64_indicators!(MoneyFlowIndex);
+
+mod volume_weighted_window;
+
+mod accumulation_distribution;
+f64_indicators!(AccumulationDistribution);
+
+mod chaikin_money_flow;
+f64_indicators!(ChaikinMoneyFlow);
+
+mod chaikin_oscillator;
+f64_indicators!(ChaikinOscillator);
 
 mod on_balance_volume;
-pub use self::on_balance_volume::OnBalanceVolume;
+f64_indicators!(OnBalanceVolume);
+
+mod force_index;
+f64_indicators!(ForceIndex);
+
+mod ease_of_movement;
+f64_indicators!(EaseOfMovement);
+
+mod percentage_volume_oscillator;
+f64_indicators!(PercentageVolumeOscillator, PercentageVolumeOscillatorOutput);
+
+mod price_volume_trend;
+f64_indicators!(PriceVolumeTrend);
+
+mod volume_index;
+
+mod negative_volume_index;
+f64_indicators!(NegativeVolumeIndex);
+
+mod positive_volume_index;
+f64_indicators!(PositiveVolumeIndex);
 
 mod volume_weighted_average_price;
-pub use self::volume_weighted_average_price::VolumeWeightedAveragePrice;
+f64_indicators!(VolumeWeightedAveragePrice);
+
+mod rolling_volume_weighted_average_price;
+f64_indicators!(RollingVolumeWeightedAveragePrice);
+
+mod hull_moving_average;
+f64_indicators!(HullMovingAverage);
+
+mod kaufman_adaptive_moving_average;
+f64_indicators!(KaufmanAdaptiveMovingAverage);
+
+mod arnaud_legoux_moving_average;
+f64_indicators!(ArnaudLegouxMovingAverage);
+
+mod volume_weighted_moving_average;
+f64_indicators!(VolumeWeightedMovingAverage);
+
+mod volume_weighted_average_price_bands;
+f64_indicators!(
+    VolumeWeightedAveragePriceBands,
+    VolumeWeightedAveragePriceBandsOutput
+);
+
+/// Indicators generic over the [numeric type](../../trait.Num.html) of their values.
+///
+/// The types with the same names in [indicators](../index.html) are their `f64` versions.
+///
+/// # Example
+///
+/// ```
+/// use ta::indicators::generic::ExponentialMovingAverage;
+/// use ta::Next;
+///
+/// let mut ema = ExponentialMovingAverage::<f32>::new(3).unwrap();
+/// assert_eq!(ema.next(2.0), 2.0);
+/// assert_eq!(ema.next(5.0), 3.5);
+/// ```
+pub mod generic {
+    pub use super::accumulation_distribution::AccumulationDistribution;
+    pub use super::arnaud_legoux_moving_average::ArnaudLegouxMovingAverage;
+    pub use super::aroon::{Aroon, AroonOutput};
+    pub use super::average_directional_index::{
+        AverageDirectionalIndex, AverageDirectionalIndexOutput,
+    };
+    pub use super::average_true_range::AverageTrueRange;
+    pub use super::bollinger_bands::{BollingerBands, BollingerBandsOutput};
+    pub use super::chaikin_money_flow::ChaikinMoneyFlow;
+    pub use super::chaikin_oscillator::ChaikinOscillator;
+    pub use super::chandelier_exit::{ChandelierExit, ChandelierExitOutput};
+    pub use super::commodity_channel_index::CommodityChannelIndex;
+    pub use super::double_exponential_moving_average::DoubleExponentialMovingAverage;
+    pub use super::ease_of_movement::EaseOfMovement;
+    pub use super::efficiency_ratio::EfficiencyRatio;
+    pub use super::exponential_moving_average::ExponentialMovingAverage;
+    pub use super::fast_stochastic::FastStochastic;
+    pub use super::force_index::ForceIndex;
+    pub use super::full_stochastic::{FullStochastic, FullStochasticOutput};
+    pub use super::hull_moving_average::HullMovingAverage;
+    pub use super::ichimoku_cloud::{IchimokuCloud, IchimokuCloudOutput};
+    pub use super::kaufman_adaptive_moving_average::KaufmanAdaptiveMovingAverage;
+    pub use super::keltner_channel::{KeltnerChannel, KeltnerChannelOutput};
+    pub use super::maximum::Maximum;
+    pub use super::mean_absolute_deviation::MeanAbsoluteDeviation;
+    pub use super::minimum::Minimum;
+    pub use super::money_flow_index::MoneyFlowIndex;
+    pub use super::moving_average::{MovingAverage, MovingAverageType};
+    pub use super::moving_average_convergence_divergence::{
+        MovingAverageConvergenceDivergence, MovingAverageConvergenceDivergenceOutput,
+    };
+    pub use super::negative_volume_index::NegativeVolumeIndex;
+    pub use super::on_balance_volume::OnBalanceVolume;
+    pub use super::parabolic_sar::{ParabolicSar, ParabolicSarOutput, Trend};
+    pub use super::percentage_price_oscillator::{
+        PercentagePriceOscillator, PercentagePriceOscillatorOutput,
+    };
+    pub use super::percentage_volume_oscillator::{
+        PercentageVolumeOscillator, PercentageVolumeOscillatorOutput,
+    };
+    pub use super::positive_volume_index::PositiveVolumeIndex;
+    pub use super::price_volume_trend::PriceVolumeTrend;
+    pub use super::rate_of_change::RateOfChange;
+    pub use super::relative_strength_index::RelativeStrengthIndex;
+    pub use super::rolling_volume_weighted_average_price::RollingVolumeWeightedAveragePrice;
+    pub use super::simple_moving_average::SimpleMovingAverage;
+    pub use super::slow_stochastic::SlowStochastic;
+    pub use super::standard_deviation::StandardDeviation;
+    pub use super::stochastic_rsi::{StochasticRsi, StochasticRsiOutput};
+    pub use super::triple_exponential_average::TripleExponentialAverage;
+    pub use super::triple_exponential_average_with_signal::{
+        TripleExponentialAverageOutput, TripleExponentialAverageWithSignal,
+    };
+    pub use super::triple_exponential_moving_average::TripleExponentialMovingAverage;
+    pub use super::true_range::TrueRange;
+    pub use super::ultimate_oscillator::UltimateOscillator;
+    pub use super::volume_weighted_average_price::VolumeWeightedAveragePrice;
+    pub use super::volume_weighted_average_price_bands::{
+        VolumeWeightedAveragePriceBands, VolumeWeightedAveragePriceBandsOutput,
+    };
+    pub use super::volume_weighted_moving_average::VolumeWeightedMovingAverage;
+    pub use super::weighted_moving_average::WeightedMovingAverage;
+    pub use super::wilder_moving_average::WilderMovingAverage;
+    pub use super::williams_r::WilliamsR;
+}
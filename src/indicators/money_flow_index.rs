@@ -1,7 +1,10 @@
-use std::fmt;
+use alloc::boxed::Box;
+use alloc::vec;
+use core::fmt;
 
 use crate::errors::{Result, TaError};
-use crate::{Close, High, Low, Next, Period, Reset, Volume};
+use crate::registry::{FromParams, Param};
+use crate::{Close, High, Lookback, Low, Next, Num, Peek, Period, Reset, Volume};
 
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
@@ -52,21 +55,21 @@ use serde::{Deserialize, Serialize};
 /// # Links
 /// * [Money Flow Index, Wikipedia](https://en.wikipedia.org/wiki/Money_flow_index)
 /// * [Money Flow Index, stockcharts](https://stockcharts.com/school/doku.php?id=chart_school:technical_indicators:money_flow_index_mfi)
-
 #[doc(alias = "MFI")]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[derive(Debug, Clone)]
-pub struct MoneyFlowIndex {
+pub struct MoneyFlowIndex<N = f64> {
     period: usize,
     index: usize,
     count: usize,
-    previous_typical_price: f64,
-    total_positive_money_flow: f64,
-    total_negative_money_flow: f64,
-    deque: Box<[f64]>,
+    previous_typical_price: N,
+    prev_previous_typical_price: N,
+    total_positive_money_flow: N,
+    total_negative_money_flow: N,
+    deque: Box<[N]>,
 }
 
-impl MoneyFlowIndex {
+impl<N: Num> MoneyFlowIndex<N> {
     pub fn new(period: usize) -> Result<Self> {
         match period {
             0 => Err(TaError::InvalidParameter),
@@ -74,26 +77,66 @@ impl MoneyFlowIndex {
                 period,
                 index: 0,
                 count: 0,
-                previous_typical_price: 0.0,
-                total_positive_money_flow: 0.0,
-                total_negative_money_flow: 0.0,
-                deque: vec![0.0; period].into_boxed_slice(),
+                previous_typical_price: N::zero(),
+                prev_previous_typical_price: N::zero(),
+                total_positive_money_flow: N::zero(),
+                total_negative_money_flow: N::zero(),
+                deque: vec![N::zero(); period].into_boxed_slice(),
             }),
         }
     }
+
+    fn remove_money_flow(&mut self, money_flow: N) {
+        if money_flow >= N::zero() {
+            self.total_positive_money_flow -= money_flow;
+        } else {
+            self.total_negative_money_flow += money_flow;
+        }
+    }
+
+    // Adds the money flow of a bar to the totals and stores it at `index`.
+    fn add_money_flow(&mut self, tp: N, previous_tp: N, volume: N) {
+        if tp > previous_tp {
+            let raw_money_flow = tp * volume;
+            self.total_positive_money_flow += raw_money_flow;
+            self.deque[self.index] = raw_money_flow;
+        } else if tp < previous_tp {
+            let raw_money_flow = tp * volume;
+            self.total_negative_money_flow += raw_money_flow;
+            self.deque[self.index] = -raw_money_flow;
+        } else {
+            self.deque[self.index] = N::zero();
+        }
+    }
+
+    fn mfi(&self) -> N {
+        self.total_positive_money_flow
+            / (self.total_positive_money_flow + self.total_negative_money_flow)
+            * N::from_f64(100.0)
+    }
 }
 
-impl Period for MoneyFlowIndex {
+impl<N: Num> Period for MoneyFlowIndex<N> {
     fn period(&self) -> usize {
         self.period
     }
 }
 
-impl<T: High + Low + Close + Volume> Next<&T> for MoneyFlowIndex {
-    type Output = f64;
+impl<N: Num> Lookback for MoneyFlowIndex<N> {
+    fn lookback(&self) -> usize {
+        self.period + 1
+    }
+
+    fn is_ready(&self) -> bool {
+        self.count > self.period
+    }
+}
+
+impl<N: Num, T: High<N> + Low<N> + Close<N> + Volume<N>> Next<&T> for MoneyFlowIndex<N> {
+    type Output = N;
 
-    fn next(&mut self, input: &T) -> f64 {
-        let tp = (input.close() + input.high() + input.low()) / 3.0;
+    fn next(&mut self, input: &T) -> N {
+        let tp = (input.close() + input.high() + input.low()) / N::from_f64(3.0);
 
         self.index = if self.index + 1 < self.period {
             self.index + 1
@@ -101,68 +144,123 @@ impl<T: High + Low + Close + Volume> Next<&T> for MoneyFlowIndex {
             0
         };
 
-        if self.count < self.period {
-            self.count = self.count + 1;
+        if self.count <= self.period {
+            self.count += 1;
             if self.count == 1 {
                 self.previous_typical_price = tp;
-                return 50.0;
+                return N::from_f64(50.0);
             }
         } else {
             let popped = self.deque[self.index];
-            if popped.is_sign_positive() {
-                self.total_positive_money_flow -= popped;
+            self.remove_money_flow(popped);
+        }
+
+        self.add_money_flow(tp, self.previous_typical_price, input.volume());
+        self.prev_previous_typical_price = self.previous_typical_price;
+        self.previous_typical_price = tp;
+
+        self.mfi()
+    }
+}
+
+impl<N: Num, T: High<N> + Low<N> + Close<N> + Volume<N>> Peek<&T> for MoneyFlowIndex<N> {
+    fn peek(&self, input: &T) -> N {
+        let tp = (input.close() + input.high() + input.low()) / N::from_f64(3.0);
+        if self.count == 0 {
+            return N::from_f64(50.0);
+        }
+
+        let mut positive = self.total_positive_money_flow;
+        let mut negative = self.total_negative_money_flow;
+        if self.count > self.period {
+            let index = if self.index + 1 < self.period {
+                self.index + 1
+            } else {
+                0
+            };
+            let popped = self.deque[index];
+            if popped >= N::zero() {
+                positive -= popped;
             } else {
-                self.total_negative_money_flow += popped;
+                negative += popped;
             }
         }
 
         if tp > self.previous_typical_price {
-            let raw_money_flow = tp * input.volume();
-            self.total_positive_money_flow += raw_money_flow;
-            self.deque[self.index] = raw_money_flow;
+            positive += tp * input.volume();
         } else if tp < self.previous_typical_price {
-            let raw_money_flow = tp * input.volume();
-            self.total_negative_money_flow += raw_money_flow;
-            self.deque[self.index] = -raw_money_flow;
-        } else {
-            self.deque[self.index] = 0.0;
+            negative += tp * input.volume();
+        }
+
+        positive / (positive + negative) * N::from_f64(100.0)
+    }
+
+    fn update_last(&mut self, input: &T) -> N {
+        let tp = (input.close() + input.high() + input.low()) / N::from_f64(3.0);
+        match self.count {
+            0 => return self.next(input),
+            1 => {
+                self.previous_typical_price = tp;
+                return N::from_f64(50.0);
+            }
+            _ => {}
         }
+
+        // The last money flow is stored at `index`.
+        let last = self.deque[self.index];
+        self.remove_money_flow(last);
+        self.add_money_flow(tp, self.prev_previous_typical_price, input.volume());
         self.previous_typical_price = tp;
 
-        self.total_positive_money_flow
-            / (self.total_positive_money_flow + self.total_negative_money_flow)
-            * 100.0
+        self.mfi()
     }
 }
 
-impl Default for MoneyFlowIndex {
+impl_next_batch!(MoneyFlowIndex, High + Low + Close + Volume => N);
+
+impl<N: Num> Default for MoneyFlowIndex<N> {
     fn default() -> Self {
         Self::new(14).unwrap()
     }
 }
 
-impl fmt::Display for MoneyFlowIndex {
+impl<N: Num> fmt::Display for MoneyFlowIndex<N> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         write!(f, "MFI({})", self.period)
     }
 }
 
-impl Reset for MoneyFlowIndex {
+impl<N: Num> Reset for MoneyFlowIndex<N> {
     fn reset(&mut self) {
         self.index = 0;
         self.count = 0;
-        self.previous_typical_price = 0.0;
-        self.total_positive_money_flow = 0.0;
-        self.total_negative_money_flow = 0.0;
+        self.previous_typical_price = N::zero();
+        self.prev_previous_typical_price = N::zero();
+        self.total_positive_money_flow = N::zero();
+        self.total_negative_money_flow = N::zero();
         for i in 0..self.period {
-            self.deque[i] = 0.0;
+            self.deque[i] = N::zero();
+        }
+    }
+}
+
+impl<N: Num> FromParams for MoneyFlowIndex<N> {
+    const NAME: &'static str = "MFI";
+
+    fn from_params(params: &[Param]) -> Result<Self> {
+        match *params {
+            [period] => Self::new(period.period()?),
+            _ => Err(TaError::InvalidParameter),
         }
     }
 }
 
+impl_from_str!(MoneyFlowIndex);
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::indicators::MoneyFlowIndex;
     use crate::test_helper::*;
 
     #[test]
@@ -200,6 +298,13 @@ mod tests {
         assert_eq!(round(mfi.next(&bar8)), 60.87);
     }
 
+    #[test]
+    fn test_peek() {
+        let bars = peek_bars();
+        let bars: Vec<&Bar> = bars.iter().collect();
+        assert_peek(MoneyFlowIndex::new(3).unwrap(), &bars);
+    }
+
     #[test]
     fn test_reset() {
         let mut mfi = MoneyFlowIndex::new(3).unwrap();
@@ -216,6 +321,23 @@ mod tests {
         assert_eq!(round(mfi.next(&bar2)), 100.0);
     }
 
+    #[test]
+    fn test_lookback() {
+        let mut mfi = MoneyFlowIndex::new(3).unwrap();
+        assert_eq!(mfi.lookback(), 4);
+
+        let bar = Bar::new().high(3).low(1).close(2).volume(500.0);
+        for _ in 0..3 {
+            mfi.next(&bar);
+            assert!(!mfi.is_ready());
+        }
+        mfi.next(&bar);
+        assert!(mfi.is_ready());
+
+        mfi.reset();
+        assert!(!mfi.is_ready());
+    }
+
     #[test]
     fn test_default() {
         MoneyFlowIndex::default();
@@ -0,0 +1,420 @@
+use core::fmt;
+use core::str::FromStr;
+
+use crate::errors::{Result, TaError};
+use crate::indicators::generic::{
+    DoubleExponentialMovingAverage, ExponentialMovingAverage, HullMovingAverage,
+    SimpleMovingAverage, TripleExponentialMovingAverage, WeightedMovingAverage,
+    WilderMovingAverage,
+};
+use crate::registry::parse_call;
+use crate::{Close, Lookback, Next, Num, Peek, Period, Reset};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Type of a moving average, used to choose the averages composite indicators are built on.
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MovingAverageType {
+    /// [Simple Moving Average (SMA)](struct.SimpleMovingAverage.html)
+    Simple,
+    /// [Exponential Moving Average (EMA)](struct.ExponentialMovingAverage.html)
+    Exponential,
+    /// [Wilder's Moving Average (RMA)](struct.WilderMovingAverage.html)
+    Wilder,
+    /// [Weighted Moving Average (WMA)](struct.WeightedMovingAverage.html)
+    Weighted,
+    /// [Double Exponential Moving Average (DEMA)](struct.DoubleExponentialMovingAverage.html)
+    DoubleExponential,
+    /// [Triple Exponential Moving Average (TEMA)](struct.TripleExponentialMovingAverage.html)
+    TripleExponential,
+    /// [Hull Moving Average (HMA)](struct.HullMovingAverage.html)
+    Hull,
+}
+
+impl MovingAverageType {
+    const ALL: [MovingAverageType; 7] = [
+        MovingAverageType::Simple,
+        MovingAverageType::Exponential,
+        MovingAverageType::Wilder,
+        MovingAverageType::Weighted,
+        MovingAverageType::DoubleExponential,
+        MovingAverageType::TripleExponential,
+        MovingAverageType::Hull,
+    ];
+
+    fn name(self) -> &'static str {
+        match self {
+            MovingAverageType::Simple => "SMA",
+            MovingAverageType::Exponential => "EMA",
+            MovingAverageType::Wilder => "RMA",
+            MovingAverageType::Weighted => "WMA",
+            MovingAverageType::DoubleExponential => "DEMA",
+            MovingAverageType::TripleExponential => "TEMA",
+            MovingAverageType::Hull => "HMA",
+        }
+    }
+}
+
+impl fmt::Display for MovingAverageType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+/// Parses the name, which the type prints with `Display`, case insensitive.
+impl FromStr for MovingAverageType {
+    type Err = TaError;
+
+    fn from_str(s: &str) -> Result<Self> {
+        Self::ALL
+            .iter()
+            .copied()
+            .find(|ma_type| s.eq_ignore_ascii_case(ma_type.name()))
+            .ok_or(TaError::InvalidParameter)
+    }
+}
+
+/// A moving average of a type chosen at runtime.
+///
+/// Composite indicators, such as [MACD](struct.MovingAverageConvergenceDivergence.html) or
+/// [Keltner Channel](struct.KeltnerChannel.html), use it for their inner averages, so that
+/// any [moving average type](enum.MovingAverageType.html) can be plugged into them.
+///
+/// # Example
+///
+/// ```
+/// use ta::indicators::{MovingAverage, MovingAverageType};
+/// use ta::Next;
+///
+/// let mut ma = MovingAverage::new(MovingAverageType::Wilder, 3).unwrap();
+/// assert_eq!(ma.next(3.0), 3.0);
+/// assert_eq!(ma.next(6.0), 4.5);
+/// assert_eq!(ma.next(9.0), 6.0);
+/// assert_eq!(ma.next(3.0), 5.0);
+/// ```
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone)]
+pub enum MovingAverage<N = f64> {
+    Simple(SimpleMovingAverage<N>),
+    Exponential(ExponentialMovingAverage<N>),
+    Wilder(WilderMovingAverage<N>),
+    Weighted(WeightedMovingAverage<N>),
+    DoubleExponential(DoubleExponentialMovingAverage<N>),
+    TripleExponential(TripleExponentialMovingAverage<N>),
+    Hull(HullMovingAverage<N>),
+}
+
+impl<N: Num> MovingAverage<N> {
+    pub fn new(ma_type: MovingAverageType, period: usize) -> Result<Self> {
+        Ok(match ma_type {
+            MovingAverageType::Simple => MovingAverage::Simple(SimpleMovingAverage::new(period)?),
+            MovingAverageType::Exponential => {
+                MovingAverage::Exponential(ExponentialMovingAverage::new(period)?)
+            }
+            MovingAverageType::Wilder => MovingAverage::Wilder(WilderMovingAverage::new(period)?),
+            MovingAverageType::Weighted => {
+                MovingAverage::Weighted(WeightedMovingAverage::new(period)?)
+            }
+            MovingAverageType::DoubleExponential => {
+                MovingAverage::DoubleExponential(DoubleExponentialMovingAverage::new(period)?)
+            }
+            MovingAverageType::TripleExponential => {
+                MovingAverage::TripleExponential(TripleExponentialMovingAverage::new(period)?)
+            }
+            MovingAverageType::Hull => MovingAverage::Hull(HullMovingAverage::new(period)?),
+        })
+    }
+
+    pub fn ma_type(&self) -> MovingAverageType {
+        match self {
+            MovingAverage::Simple(_) => MovingAverageType::Simple,
+            MovingAverage::Exponential(_) => MovingAverageType::Exponential,
+            MovingAverage::Wilder(_) => MovingAverageType::Wilder,
+            MovingAverage::Weighted(_) => MovingAverageType::Weighted,
+            MovingAverage::DoubleExponential(_) => MovingAverageType::DoubleExponential,
+            MovingAverage::TripleExponential(_) => MovingAverageType::TripleExponential,
+            MovingAverage::Hull(_) => MovingAverageType::Hull,
+        }
+    }
+}
+
+impl<N: Num> Period for MovingAverage<N> {
+    fn period(&self) -> usize {
+        match self {
+            MovingAverage::Simple(ma) => ma.period(),
+            MovingAverage::Exponential(ma) => ma.period(),
+            MovingAverage::Wilder(ma) => ma.period(),
+            MovingAverage::Weighted(ma) => ma.period(),
+            MovingAverage::DoubleExponential(ma) => ma.period(),
+            MovingAverage::TripleExponential(ma) => ma.period(),
+            MovingAverage::Hull(ma) => ma.period(),
+        }
+    }
+}
+
+impl<N: Num> Lookback for MovingAverage<N> {
+    fn lookback(&self) -> usize {
+        match self {
+            MovingAverage::Simple(ma) => ma.lookback(),
+            MovingAverage::Exponential(ma) => ma.lookback(),
+            MovingAverage::Wilder(ma) => ma.lookback(),
+            MovingAverage::Weighted(ma) => ma.lookback(),
+            MovingAverage::DoubleExponential(ma) => ma.lookback(),
+            MovingAverage::TripleExponential(ma) => ma.lookback(),
+            MovingAverage::Hull(ma) => ma.lookback(),
+        }
+    }
+
+    fn is_ready(&self) -> bool {
+        match self {
+            MovingAverage::Simple(ma) => ma.is_ready(),
+            MovingAverage::Exponential(ma) => ma.is_ready(),
+            MovingAverage::Wilder(ma) => ma.is_ready(),
+            MovingAverage::Weighted(ma) => ma.is_ready(),
+            MovingAverage::DoubleExponential(ma) => ma.is_ready(),
+            MovingAverage::TripleExponential(ma) => ma.is_ready(),
+            MovingAverage::Hull(ma) => ma.is_ready(),
+        }
+    }
+}
+
+impl<N: Num> Next<N> for MovingAverage<N> {
+    type Output = N;
+
+    fn next(&mut self, input: N) -> Self::Output {
+        match self {
+            MovingAverage::Simple(ma) => ma.next(input),
+            MovingAverage::Exponential(ma) => ma.next(input),
+            MovingAverage::Wilder(ma) => ma.next(input),
+            MovingAverage::Weighted(ma) => ma.next(input),
+            MovingAverage::DoubleExponential(ma) => ma.next(input),
+            MovingAverage::TripleExponential(ma) => ma.next(input),
+            MovingAverage::Hull(ma) => ma.next(input),
+        }
+    }
+}
+
+impl_next_batch!(MovingAverage, N => N);
+
+impl<N: Num> Peek<N> for MovingAverage<N> {
+    fn peek(&self, input: N) -> Self::Output {
+        match self {
+            MovingAverage::Simple(ma) => ma.peek(input),
+            MovingAverage::Exponential(ma) => ma.peek(input),
+            MovingAverage::Wilder(ma) => ma.peek(input),
+            MovingAverage::Weighted(ma) => ma.peek(input),
+            MovingAverage::DoubleExponential(ma) => ma.peek(input),
+            MovingAverage::TripleExponential(ma) => ma.peek(input),
+            MovingAverage::Hull(ma) => ma.peek(input),
+        }
+    }
+
+    fn update_last(&mut self, input: N) -> Self::Output {
+        match self {
+            MovingAverage::Simple(ma) => ma.update_last(input),
+            MovingAverage::Exponential(ma) => ma.update_last(input),
+            MovingAverage::Wilder(ma) => ma.update_last(input),
+            MovingAverage::Weighted(ma) => ma.update_last(input),
+            MovingAverage::DoubleExponential(ma) => ma.update_last(input),
+            MovingAverage::TripleExponential(ma) => ma.update_last(input),
+            MovingAverage::Hull(ma) => ma.update_last(input),
+        }
+    }
+}
+
+impl_for_floats! {
+    impl<T: Close<N>> Next<&T> for MovingAverage<N> {
+        type Output = N;
+
+        fn next(&mut self, input: &T) -> Self::Output {
+            self.next(input.close())
+        }
+    }
+
+    impl_next_batch!(MovingAverage<N>, Close => N);
+
+    impl<T: Close<N>> Peek<&T> for MovingAverage<N> {
+        fn peek(&self, input: &T) -> Self::Output {
+            self.peek(input.close())
+        }
+
+        fn update_last(&mut self, input: &T) -> Self::Output {
+            self.update_last(input.close())
+        }
+    }
+}
+
+impl<N: Num> Reset for MovingAverage<N> {
+    fn reset(&mut self) {
+        match self {
+            MovingAverage::Simple(ma) => ma.reset(),
+            MovingAverage::Exponential(ma) => ma.reset(),
+            MovingAverage::Wilder(ma) => ma.reset(),
+            MovingAverage::Weighted(ma) => ma.reset(),
+            MovingAverage::DoubleExponential(ma) => ma.reset(),
+            MovingAverage::TripleExponential(ma) => ma.reset(),
+            MovingAverage::Hull(ma) => ma.reset(),
+        }
+    }
+}
+
+impl<N: Num> Default for MovingAverage<N> {
+    fn default() -> Self {
+        MovingAverage::Exponential(ExponentialMovingAverage::default())
+    }
+}
+
+impl<N: Num> fmt::Display for MovingAverage<N> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            MovingAverage::Simple(ma) => write!(f, "{}", ma),
+            MovingAverage::Exponential(ma) => write!(f, "{}", ma),
+            MovingAverage::Wilder(ma) => write!(f, "{}", ma),
+            MovingAverage::Weighted(ma) => write!(f, "{}", ma),
+            MovingAverage::DoubleExponential(ma) => write!(f, "{}", ma),
+            MovingAverage::TripleExponential(ma) => write!(f, "{}", ma),
+            MovingAverage::Hull(ma) => write!(f, "{}", ma),
+        }
+    }
+}
+
+/// Parses the average, which it prints with `Display`, e.g. `"SMA(9)"`.
+impl<N: Num> FromStr for MovingAverage<N> {
+    type Err = TaError;
+
+    fn from_str(s: &str) -> Result<Self> {
+        let (name, params) = parse_call(s)?;
+        let ma_type = name.parse().map_err(|_| TaError::UnknownIndicator)?;
+        match *params {
+            [period] => Self::new(ma_type, period.period()?),
+            _ => Err(TaError::InvalidParameter),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::indicators::MovingAverage;
+    use crate::test_helper::*;
+
+    test_indicator!(MovingAverage);
+
+    #[test]
+    fn test_new() {
+        assert!(MovingAverage::new(MovingAverageType::Simple, 0).is_err());
+        assert!(MovingAverage::new(MovingAverageType::Exponential, 0).is_err());
+        assert!(MovingAverage::new(MovingAverageType::Wilder, 0).is_err());
+        assert!(MovingAverage::new(MovingAverageType::Weighted, 0).is_err());
+        assert!(MovingAverage::new(MovingAverageType::DoubleExponential, 0).is_err());
+        assert!(MovingAverage::new(MovingAverageType::TripleExponential, 0).is_err());
+        assert!(MovingAverage::new(MovingAverageType::Hull, 0).is_err());
+        assert!(MovingAverage::new(MovingAverageType::Wilder, 1).is_ok());
+    }
+
+    #[test]
+    fn test_next() {
+        let types = [
+            MovingAverageType::Simple,
+            MovingAverageType::Exponential,
+            MovingAverageType::Wilder,
+            MovingAverageType::Weighted,
+            MovingAverageType::DoubleExponential,
+            MovingAverageType::TripleExponential,
+            MovingAverageType::Hull,
+        ];
+
+        for &ma_type in types.iter() {
+            let mut ma = MovingAverage::new(ma_type, 3).unwrap();
+            assert_eq!(ma.ma_type(), ma_type);
+            assert_eq!(ma.period(), 3);
+            assert_eq!(ma.next(2.0), 2.0);
+        }
+
+        let mut ma = MovingAverage::new(MovingAverageType::Simple, 3).unwrap();
+        let mut sma = SimpleMovingAverage::new(3).unwrap();
+        for &x in [2.0, 5.0, 1.0, 6.25].iter() {
+            assert_eq!(ma.next(x), sma.next(x));
+        }
+
+        let mut ma = MovingAverage::new(MovingAverageType::Exponential, 3).unwrap();
+        let mut ema = ExponentialMovingAverage::new(3).unwrap();
+        for &x in [2.0, 5.0, 1.0, 6.25].iter() {
+            assert_eq!(ma.next(&Bar::new().close(x)), ema.next(x));
+        }
+
+        let mut ma = MovingAverage::new(MovingAverageType::Weighted, 3).unwrap();
+        let mut wma = WeightedMovingAverage::new(3).unwrap();
+        for &x in [2.0, 5.0, 1.0, 6.25].iter() {
+            assert_eq!(ma.next(x), wma.next(x));
+        }
+
+        let mut ma = MovingAverage::new(MovingAverageType::Hull, 4).unwrap();
+        let mut hma = HullMovingAverage::new(4).unwrap();
+        for &x in [2.0, 5.0, 1.0, 6.25, 3.0].iter() {
+            assert_eq!(ma.next(x), hma.next(x));
+        }
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut ma = MovingAverage::new(MovingAverageType::Wilder, 3).unwrap();
+        assert_eq!(ma.next(3.0), 3.0);
+        assert_eq!(ma.next(6.0), 4.5);
+
+        ma.reset();
+        assert_eq!(ma.next(6.0), 6.0);
+    }
+
+    #[test]
+    fn test_default() {
+        MovingAverage::default();
+    }
+
+    #[test]
+    fn test_display() {
+        let ma = MovingAverage::new(MovingAverageType::Simple, 5).unwrap();
+        assert_eq!(format!("{}", ma), "SMA(5)");
+        let ma = MovingAverage::new(MovingAverageType::Wilder, 5).unwrap();
+        assert_eq!(format!("{}", ma), "RMA(5)");
+        let ma = MovingAverage::new(MovingAverageType::DoubleExponential, 5).unwrap();
+        assert_eq!(format!("{}", ma), "DEMA(5)");
+        let ma = MovingAverage::new(MovingAverageType::TripleExponential, 5).unwrap();
+        assert_eq!(format!("{}", ma), "TEMA(5)");
+        let ma = MovingAverage::new(MovingAverageType::Hull, 5).unwrap();
+        assert_eq!(format!("{}", ma), "HMA(5)");
+        assert_eq!(format!("{}", MovingAverageType::Exponential), "EMA");
+        assert_eq!(format!("{}", MovingAverageType::Weighted), "WMA");
+    }
+
+    #[test]
+    fn test_from_str() {
+        for &ma_type in MovingAverageType::ALL.iter() {
+            assert_eq!(ma_type.to_string().parse(), Ok(ma_type));
+
+            let ma = MovingAverage::new(ma_type, 5).unwrap();
+            let parsed: MovingAverage = ma.to_string().parse().unwrap();
+            assert_eq!(parsed.ma_type(), ma_type);
+            assert_eq!(parsed.period(), 5);
+        }
+
+        assert_eq!("rma".parse(), Ok(MovingAverageType::Wilder));
+        assert_eq!(
+            "XMA".parse::<MovingAverageType>(),
+            Err(TaError::InvalidParameter)
+        );
+        assert_eq!(
+            "XMA(5)".parse::<MovingAverage>().unwrap_err(),
+            TaError::UnknownIndicator
+        );
+        assert_eq!(
+            "SMA(0)".parse::<MovingAverage>().unwrap_err(),
+            TaError::InvalidParameter
+        );
+        assert_eq!(
+            "SMA(5".parse::<MovingAverage>().unwrap_err(),
+            TaError::InvalidFormat
+        );
+    }
+}
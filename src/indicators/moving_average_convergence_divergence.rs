@@ -1,8 +1,9 @@
-use std::fmt;
+use core::fmt;
 
-use crate::errors::Result;
-use crate::indicators::ExponentialMovingAverage as Ema;
-use crate::{Close, Next, Period, Reset};
+use crate::errors::{Result, TaError};
+use crate::indicators::generic::{MovingAverage, MovingAverageType};
+use crate::registry::{FromParams, Param};
+use crate::{Close, Lookback, Next, Num, Peek, Period, Reset};
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
@@ -27,6 +28,9 @@ use serde::{Deserialize, Serialize};
 /// * _fast_period_ - period for the fast EMA. Default is 12.
 /// * _slow_period_ - period for the slow EMA. Default is 26.
 /// * _signal_period_ - period for the signal EMA. Default is 9.
+/// * _moving_average_ - type of the fast, slow and signal moving averages. Default is
+///   [`MovingAverageType::Exponential`](enum.MovingAverageType.html), see
+///   [`with_moving_average`](#method.with_moving_average).
 ///
 /// # Example
 ///
@@ -53,44 +57,85 @@ use serde::{Deserialize, Serialize};
 #[doc(alias = "MACD")]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[derive(Debug, Clone)]
-pub struct MovingAverageConvergenceDivergence {
-    fast_ema: Ema,
-    slow_ema: Ema,
-    signal_ema: Ema,
+pub struct MovingAverageConvergenceDivergence<N = f64> {
+    fast_ma: MovingAverage<N>,
+    slow_ma: MovingAverage<N>,
+    signal_ma: MovingAverage<N>,
+    count: usize,
 }
 
-impl MovingAverageConvergenceDivergence {
+impl<N: Num> MovingAverageConvergenceDivergence<N> {
     pub fn new(fast_period: usize, slow_period: usize, signal_period: usize) -> Result<Self> {
+        Self::with_moving_average(
+            fast_period,
+            slow_period,
+            signal_period,
+            MovingAverageType::Exponential,
+        )
+    }
+
+    pub fn with_moving_average(
+        fast_period: usize,
+        slow_period: usize,
+        signal_period: usize,
+        moving_average: MovingAverageType,
+    ) -> Result<Self> {
         Ok(Self {
-            fast_ema: Ema::new(fast_period)?,
-            slow_ema: Ema::new(slow_period)?,
-            signal_ema: Ema::new(signal_period)?,
+            fast_ma: MovingAverage::new(moving_average, fast_period)?,
+            slow_ma: MovingAverage::new(moving_average, slow_period)?,
+            signal_ma: MovingAverage::new(moving_average, signal_period)?,
+            count: 0,
         })
     }
+
+    pub fn moving_average(&self) -> MovingAverageType {
+        self.fast_ma.ma_type()
+    }
 }
 
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[derive(Debug, Clone, PartialEq)]
-pub struct MovingAverageConvergenceDivergenceOutput {
-    pub macd: f64,
-    pub signal: f64,
-    pub histogram: f64,
+pub struct MovingAverageConvergenceDivergenceOutput<N = f64> {
+    pub macd: N,
+    pub signal: N,
+    pub histogram: N,
 }
 
-impl From<MovingAverageConvergenceDivergenceOutput> for (f64, f64, f64) {
-    fn from(mo: MovingAverageConvergenceDivergenceOutput) -> Self {
+impl_fields!(MovingAverageConvergenceDivergenceOutput {
+    macd,
+    signal,
+    histogram
+});
+
+impl<N> From<MovingAverageConvergenceDivergenceOutput<N>> for (N, N, N) {
+    fn from(mo: MovingAverageConvergenceDivergenceOutput<N>) -> Self {
         (mo.macd, mo.signal, mo.histogram)
     }
 }
 
-impl Next<f64> for MovingAverageConvergenceDivergence {
-    type Output = MovingAverageConvergenceDivergenceOutput;
+impl<N: Num> Lookback for MovingAverageConvergenceDivergence<N> {
+    fn lookback(&self) -> usize {
+        self.fast_ma.lookback().max(self.slow_ma.lookback()) + self.signal_ma.lookback() - 1
+    }
+
+    fn is_ready(&self) -> bool {
+        self.count == self.lookback()
+    }
+}
+
+impl<N: Num> Next<N> for MovingAverageConvergenceDivergence<N> {
+    type Output = MovingAverageConvergenceDivergenceOutput<N>;
 
-    fn next(&mut self, input: f64) -> Self::Output {
-        let fast_val = self.fast_ema.next(input);
-        let slow_val = self.slow_ema.next(input);
+    fn next(&mut self, input: N) -> Self::Output {
+        let fast_val = self.fast_ma.next(input);
+        let slow_val = self.slow_ma.next(input);
 
         let macd = fast_val - slow_val;
-        let signal = self.signal_ema.next(macd);
+        let signal = self.signal_ma.next(macd);
+        if self.count < self.lookback() {
+            self.count += 1;
+        }
+
         let histogram = macd - signal;
 
         MovingAverageConvergenceDivergenceOutput {
@@ -101,44 +146,130 @@ impl Next<f64> for MovingAverageConvergenceDivergence {
     }
 }
 
-impl<T: Close> Next<&T> for MovingAverageConvergenceDivergence {
-    type Output = MovingAverageConvergenceDivergenceOutput;
+impl_next_batch!(MovingAverageConvergenceDivergence, N => MovingAverageConvergenceDivergenceOutput<N>);
+
+impl<N: Num> Peek<N> for MovingAverageConvergenceDivergence<N> {
+    fn peek(&self, input: N) -> Self::Output {
+        let fast_val = self.fast_ma.peek(input);
+        let slow_val = self.slow_ma.peek(input);
+
+        let macd = fast_val - slow_val;
+        let signal = self.signal_ma.peek(macd);
+
+        MovingAverageConvergenceDivergenceOutput {
+            macd,
+            signal,
+            histogram: macd - signal,
+        }
+    }
+
+    fn update_last(&mut self, input: N) -> Self::Output {
+        if self.count == 0 {
+            return self.next(input);
+        }
+
+        let fast_val = self.fast_ma.update_last(input);
+        let slow_val = self.slow_ma.update_last(input);
+
+        let macd = fast_val - slow_val;
+        let signal = self.signal_ma.update_last(macd);
+
+        MovingAverageConvergenceDivergenceOutput {
+            macd,
+            signal,
+            histogram: macd - signal,
+        }
+    }
+}
+
+impl_for_floats! {
+    impl<T: Close<N>> Next<&T> for MovingAverageConvergenceDivergence<N> {
+        type Output = MovingAverageConvergenceDivergenceOutput<N>;
+
+        fn next(&mut self, input: &T) -> Self::Output {
+            self.next(input.close())
+        }
+    }
+
+    impl_next_batch!(MovingAverageConvergenceDivergence<N>, Close => MovingAverageConvergenceDivergenceOutput<N>);
+
+    impl<T: Close<N>> Peek<&T> for MovingAverageConvergenceDivergence<N> {
+        fn peek(&self, input: &T) -> Self::Output {
+            self.peek(input.close())
+        }
 
-    fn next(&mut self, input: &T) -> Self::Output {
-        self.next(input.close())
+        fn update_last(&mut self, input: &T) -> Self::Output {
+            self.update_last(input.close())
+        }
     }
 }
 
-impl Reset for MovingAverageConvergenceDivergence {
+impl<N: Num> Reset for MovingAverageConvergenceDivergence<N> {
     fn reset(&mut self) {
-        self.fast_ema.reset();
-        self.slow_ema.reset();
-        self.signal_ema.reset();
+        self.fast_ma.reset();
+        self.slow_ma.reset();
+        self.signal_ma.reset();
+        self.count = 0;
     }
 }
 
-impl Default for MovingAverageConvergenceDivergence {
+impl<N: Num> Default for MovingAverageConvergenceDivergence<N> {
     fn default() -> Self {
         Self::new(12, 26, 9).unwrap()
     }
 }
 
-impl fmt::Display for MovingAverageConvergenceDivergence {
+impl<N: Num> fmt::Display for MovingAverageConvergenceDivergence<N> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(
-            f,
-            "MACD({}, {}, {})",
-            self.fast_ema.period(),
-            self.slow_ema.period(),
-            self.signal_ema.period()
-        )
+        match self.moving_average() {
+            MovingAverageType::Exponential => write!(
+                f,
+                "MACD({}, {}, {})",
+                self.fast_ma.period(),
+                self.slow_ma.period(),
+                self.signal_ma.period()
+            ),
+            moving_average => write!(
+                f,
+                "MACD({}, {}, {}, {})",
+                self.fast_ma.period(),
+                self.slow_ma.period(),
+                self.signal_ma.period(),
+                moving_average
+            ),
+        }
     }
 }
 
+impl<N: Num> FromParams for MovingAverageConvergenceDivergence<N> {
+    const NAME: &'static str = "MACD";
+
+    fn from_params(params: &[Param]) -> Result<Self> {
+        match *params {
+            [fast_period, slow_period, signal_period] => Self::new(
+                fast_period.period()?,
+                slow_period.period()?,
+                signal_period.period()?,
+            ),
+            [fast_period, slow_period, signal_period, moving_average] => Self::with_moving_average(
+                fast_period.period()?,
+                slow_period.period()?,
+                signal_period.period()?,
+                moving_average.moving_average()?,
+            ),
+            _ => Err(TaError::InvalidParameter),
+        }
+    }
+}
+
+impl_from_str!(MovingAverageConvergenceDivergence);
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::indicators::MovingAverageConvergenceDivergence;
     use crate::test_helper::*;
+    use crate::Fields;
     type Macd = MovingAverageConvergenceDivergence;
 
     test_indicator!(Macd);
@@ -170,6 +301,33 @@ mod tests {
         assert_eq!(round(macd.next(6.5).into()), (0.94, 0.87, 0.07));
     }
 
+    #[test]
+    fn test_output_fields() {
+        let mut macd = Macd::new(3, 6, 4).unwrap();
+        macd.next(2.0);
+        let output = macd.next(3.0);
+
+        let names: Vec<_> = output.fields().iter().map(|&(name, _)| name).collect();
+        assert_eq!(names, vec!["macd", "signal", "histogram"]);
+        assert_eq!(output.get("histogram"), Some(output.histogram));
+
+        let values: [f64; 3] = output.clone().into();
+        assert_eq!(values, [output.macd, output.signal, output.histogram]);
+        assert_eq!(output.values(), values);
+    }
+
+    #[test]
+    fn test_next_sma() {
+        let mut macd = Macd::with_moving_average(2, 3, 2, MovingAverageType::Simple).unwrap();
+        assert_eq!(macd.moving_average(), MovingAverageType::Simple);
+
+        assert_eq!(round(macd.next(1.0).into()), (0.0, 0.0, 0.0));
+        assert_eq!(round(macd.next(2.0).into()), (0.0, 0.0, 0.0));
+        assert_eq!(round(macd.next(3.0).into()), (0.5, 0.25, 0.25));
+        assert_eq!(round(macd.next(4.0).into()), (0.5, 0.5, 0.0));
+        assert_eq!(round(macd.next(5.0).into()), (0.5, 0.5, 0.0));
+    }
+
     #[test]
     fn test_reset() {
         let mut macd = Macd::new(3, 6, 4).unwrap();
@@ -192,5 +350,7 @@ mod tests {
     fn test_display() {
         let indicator = Macd::new(13, 30, 10).unwrap();
         assert_eq!(format!("{}", indicator), "MACD(13, 30, 10)");
+        let indicator = Macd::with_moving_average(13, 30, 10, MovingAverageType::Simple).unwrap();
+        assert_eq!(format!("{}", indicator), "MACD(13, 30, 10, SMA)");
     }
 }
@@ -0,0 +1,207 @@
+use core::fmt;
+
+use crate::errors::{Result, TaError};
+use crate::indicators::volume_index::{VolumeChange, VolumeIndex};
+use crate::registry::{FromParams, Param};
+use crate::{Close, Lookback, Next, Num, Peek, Reset, Volume};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Negative Volume Index (NVI).
+///
+/// A cumulative indicator, which only changes on bars, where the volume decreases compared to
+/// the prior bar. The idea is that the smart money is active on quiet days, when the volume is
+/// low. See also [Positive Volume Index](struct.PositiveVolumeIndex.html).
+///
+/// # Formula
+///
+/// If the volume is below the prior volume then:
+/// NVI<sub>t</sub> = NVI<sub>t-1</sub> × close<sub>t</sub> / close<sub>t-1</sub>
+///
+/// Otherwise:
+/// NVI<sub>t</sub> = NVI<sub>t-1</sub>
+///
+/// NVI starts at 1000. When the prior close is 0, NVI doesn't change.
+///
+/// # Example
+///
+/// ```
+/// use ta::indicators::NegativeVolumeIndex;
+/// use ta::{DataItem, Next};
+///
+/// let item = |close: f64, volume: f64| {
+///     DataItem::builder()
+///         .open(close)
+///         .high(close)
+///         .low(close)
+///         .close(close)
+///         .volume(volume)
+///         .build()
+///         .unwrap()
+/// };
+///
+/// let mut nvi = NegativeVolumeIndex::new();
+/// assert_eq!(nvi.next(&item(10.0, 100.0)), 1000.0);
+/// assert_eq!(nvi.next(&item(11.0, 50.0)), 1100.0);
+/// assert_eq!(nvi.next(&item(12.0, 80.0)), 1100.0);
+/// ```
+///
+/// # Links
+///
+/// * [Negative volume index, Wikipedia](https://en.wikipedia.org/wiki/Negative_volume_index)
+///
+#[doc(alias = "NVI")]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone)]
+pub struct NegativeVolumeIndex<N = f64> {
+    index: VolumeIndex<N>,
+}
+
+impl<N: Num> NegativeVolumeIndex<N> {
+    pub fn new() -> Self {
+        Self {
+            index: VolumeIndex::new(VolumeChange::Decrease),
+        }
+    }
+}
+
+impl<N: Num> Lookback for NegativeVolumeIndex<N> {
+    fn lookback(&self) -> usize {
+        2
+    }
+
+    fn is_ready(&self) -> bool {
+        self.index.count() == self.lookback()
+    }
+}
+
+impl<N: Num, T: Close<N> + Volume<N>> Next<&T> for NegativeVolumeIndex<N> {
+    type Output = N;
+
+    fn next(&mut self, input: &T) -> N {
+        self.index.next(input.close(), input.volume())
+    }
+}
+
+impl_next_batch!(NegativeVolumeIndex, Close + Volume => N);
+
+impl<N: Num, T: Close<N> + Volume<N>> Peek<&T> for NegativeVolumeIndex<N> {
+    fn peek(&self, input: &T) -> N {
+        self.index.peek(input.close(), input.volume())
+    }
+
+    fn update_last(&mut self, input: &T) -> N {
+        self.index.update_last(input.close(), input.volume())
+    }
+}
+
+impl<N: Num> Default for NegativeVolumeIndex<N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<N: Num> fmt::Display for NegativeVolumeIndex<N> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "NVI")
+    }
+}
+
+impl<N: Num> Reset for NegativeVolumeIndex<N> {
+    fn reset(&mut self) {
+        self.index.reset();
+    }
+}
+
+impl<N: Num> FromParams for NegativeVolumeIndex<N> {
+    const NAME: &'static str = "NVI";
+
+    fn from_params(params: &[Param]) -> Result<Self> {
+        match *params {
+            [] => Ok(Self::new()),
+            _ => Err(TaError::InvalidParameter),
+        }
+    }
+}
+
+impl_from_str!(NegativeVolumeIndex);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::indicators::NegativeVolumeIndex;
+    use crate::test_helper::*;
+
+    #[test]
+    fn test_next_bar() {
+        let mut nvi = NegativeVolumeIndex::new();
+
+        let bar1 = Bar::new().close(10).volume(100.0);
+        let bar2 = Bar::new().close(11).volume(50.0);
+        let bar3 = Bar::new().close(12).volume(80.0);
+        let bar4 = Bar::new().close(9).volume(40.0);
+        let bar5 = Bar::new().close(9).volume(40.0);
+
+        assert_eq!(nvi.next(&bar1), 1000.0);
+
+        // volume < prev_volume
+        assert_eq!(nvi.next(&bar2), 1100.0);
+
+        // volume > prev_volume
+        assert_eq!(nvi.next(&bar3), 1100.0);
+
+        assert_eq!(nvi.next(&bar4), 825.0);
+
+        // volume == prev_volume
+        assert_eq!(nvi.next(&bar5), 825.0);
+    }
+
+    #[test]
+    fn test_peek() {
+        let bars = peek_bars();
+        let bars: Vec<&Bar> = bars.iter().collect();
+        assert_peek(NegativeVolumeIndex::new(), &bars);
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut nvi = NegativeVolumeIndex::new();
+
+        let bar1 = Bar::new().close(10).volume(100.0);
+        let bar2 = Bar::new().close(11).volume(50.0);
+
+        assert_eq!(nvi.next(&bar1), 1000.0);
+        assert_eq!(nvi.next(&bar2), 1100.0);
+
+        nvi.reset();
+
+        assert_eq!(nvi.next(&bar1), 1000.0);
+        assert_eq!(nvi.next(&bar2), 1100.0);
+    }
+
+    #[test]
+    fn test_lookback() {
+        let mut nvi = NegativeVolumeIndex::new();
+        assert_eq!(nvi.lookback(), 2);
+
+        let bar = Bar::new().close(10).volume(100.0);
+        nvi.next(&bar);
+        assert!(!nvi.is_ready());
+        nvi.next(&bar);
+        assert!(nvi.is_ready());
+
+        nvi.reset();
+        assert!(!nvi.is_ready());
+    }
+
+    #[test]
+    fn test_default() {
+        NegativeVolumeIndex::default();
+    }
+
+    #[test]
+    fn test_display() {
+        let nvi = NegativeVolumeIndex::new();
+        assert_eq!(format!("{}", nvi), "NVI");
+    }
+}
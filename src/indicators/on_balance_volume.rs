@@ -1,6 +1,8 @@
-use std::fmt;
+use core::fmt;
 
-use crate::{Close, Next, Reset, Volume};
+use crate::errors::{Result, TaError};
+use crate::registry::{FromParams, Param};
+use crate::{Close, Lookback, Next, Num, Peek, Reset, Volume};
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
@@ -57,60 +59,120 @@ use serde::{Deserialize, Serialize};
 ///
 /// * [On Balance Volume, Wikipedia](https://en.wikipedia.org/wiki/On-balance_volume)
 /// * [On Balance Volume, stockcharts](https://stockcharts.com/school/doku.php?id=chart_school:technical_indicators:on_balance_volume_obv)
-
 #[doc(alias = "OBV")]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[derive(Debug, Clone)]
-pub struct OnBalanceVolume {
-    obv: f64,
-    prev_close: f64,
+pub struct OnBalanceVolume<N = f64> {
+    obv: N,
+    prev_obv: N,
+    prev_close: N,
+    prev_prev_close: N,
+    count: usize,
 }
 
-impl OnBalanceVolume {
+impl<N: Num> OnBalanceVolume<N> {
     pub fn new() -> Self {
         Self {
-            obv: 0.0,
-            prev_close: 0.0,
+            obv: N::zero(),
+            prev_obv: N::zero(),
+            prev_close: N::zero(),
+            prev_prev_close: N::zero(),
+            count: 0,
         }
     }
+
+    fn calc<T: Close<N> + Volume<N>>(obv: N, prev_close: N, input: &T) -> N {
+        if input.close() > prev_close {
+            obv + input.volume()
+        } else if input.close() < prev_close {
+            obv - input.volume()
+        } else {
+            obv
+        }
+    }
+}
+
+impl<N: Num> Lookback for OnBalanceVolume<N> {
+    fn lookback(&self) -> usize {
+        2
+    }
+
+    fn is_ready(&self) -> bool {
+        self.count == self.lookback()
+    }
 }
 
-impl<T: Close + Volume> Next<&T> for OnBalanceVolume {
-    type Output = f64;
+impl<N: Num, T: Close<N> + Volume<N>> Next<&T> for OnBalanceVolume<N> {
+    type Output = N;
+
+    fn next(&mut self, input: &T) -> N {
+        self.prev_obv = self.obv;
+        self.obv = Self::calc(self.obv, self.prev_close, input);
+        self.prev_prev_close = self.prev_close;
+        self.prev_close = input.close();
+        if self.count < self.lookback() {
+            self.count += 1;
+        }
+        self.obv
+    }
+}
+
+impl_next_batch!(OnBalanceVolume, Close + Volume => N);
+
+impl<N: Num, T: Close<N> + Volume<N>> Peek<&T> for OnBalanceVolume<N> {
+    fn peek(&self, input: &T) -> N {
+        Self::calc(self.obv, self.prev_close, input)
+    }
 
-    fn next(&mut self, input: &T) -> f64 {
-        if input.close() > self.prev_close {
-            self.obv = self.obv + input.volume();
-        } else if input.close() < self.prev_close {
-            self.obv = self.obv - input.volume();
+    fn update_last(&mut self, input: &T) -> N {
+        if self.count == 0 {
+            return self.next(input);
         }
+        self.obv = Self::calc(self.prev_obv, self.prev_prev_close, input);
         self.prev_close = input.close();
         self.obv
     }
 }
 
-impl Default for OnBalanceVolume {
+impl<N: Num> Default for OnBalanceVolume<N> {
     fn default() -> Self {
         Self::new()
     }
 }
 
-impl fmt::Display for OnBalanceVolume {
+impl<N: Num> fmt::Display for OnBalanceVolume<N> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         write!(f, "OBV")
     }
 }
 
-impl Reset for OnBalanceVolume {
+impl<N: Num> Reset for OnBalanceVolume<N> {
     fn reset(&mut self) {
-        self.obv = 0.0;
-        self.prev_close = 0.0;
+        self.obv = N::zero();
+        self.prev_obv = N::zero();
+        self.prev_close = N::zero();
+        self.prev_prev_close = N::zero();
+        self.count = 0;
     }
 }
 
+impl<N: Num> FromParams for OnBalanceVolume<N> {
+    const NAME: &'static str = "OBV";
+
+    fn from_params(params: &[Param]) -> Result<Self> {
+        match *params {
+            [] => Ok(Self::new()),
+            _ => Err(TaError::InvalidParameter),
+        }
+    }
+}
+
+impl_from_str!(OnBalanceVolume);
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::indicators::OnBalanceVolume;
     use crate::test_helper::*;
 
     #[test]
@@ -134,6 +196,13 @@ mod tests {
         assert_eq!(obv.next(&bar4), -3000.0);
     }
 
+    #[test]
+    fn test_peek() {
+        let bars = peek_bars();
+        let bars: Vec<&Bar> = bars.iter().collect();
+        assert_peek(OnBalanceVolume::new(), &bars);
+    }
+
     #[test]
     fn test_reset() {
         let mut obv = OnBalanceVolume::new();
@@ -153,6 +222,21 @@ mod tests {
         assert_eq!(obv.next(&bar3), 6000.0);
     }
 
+    #[test]
+    fn test_lookback() {
+        let mut obv = OnBalanceVolume::new();
+        assert_eq!(obv.lookback(), 2);
+
+        let bar = Bar::new().close(1.5).volume(1000.0);
+        obv.next(&bar);
+        assert!(!obv.is_ready());
+        obv.next(&bar);
+        assert!(obv.is_ready());
+
+        obv.reset();
+        assert!(!obv.is_ready());
+    }
+
     #[test]
     fn test_default() {
         OnBalanceVolume::default();
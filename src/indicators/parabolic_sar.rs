@@ -0,0 +1,450 @@
+use alloc::vec;
+use alloc::vec::Vec;
+use core::fmt;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::errors::{Result, TaError};
+use crate::registry::{FromParams, Param};
+use crate::{Fields, High, Lookback, Low, Next, Num, Peek, Reset};
+
+/// Parabolic SAR (stop and reverse).
+///
+/// Developed by J. Welles Wilder, the Parabolic SAR is a trailing stop which follows the price.
+/// In an uptrend it stays below the price and moves up every period, accelerating when new highs
+/// are made. When the price falls below it, the trend reverses, and the SAR jumps above the price.
+///
+/// # Formula
+///
+/// SAR<sub>t+1</sub> = SAR<sub>t</sub> + AF * (EP - SAR<sub>t</sub>)
+///
+/// Where:
+///
+/// * _EP_ - extreme point, the highest high in an uptrend or the lowest low in a downtrend
+/// * _AF_ - acceleration factor. It starts at _step_ and increases by _step_ every time a new
+///   extreme point is made, up to _maximum_.
+///
+/// In an uptrend the SAR is never above the two previous lows, in a downtrend it's never below
+/// the two previous highs. When the price crosses the SAR the trend reverses: the SAR is set to
+/// the extreme point of the previous trend, and AF is reset to _step_.
+///
+/// The first period is assumed to start an uptrend with the SAR at its low.
+///
+/// # Parameters
+///
+/// * _step_ - acceleration factor step (greater than 0). Default is 0.02.
+/// * _maximum_ - maximum acceleration factor (not less than _step_). Default is 0.2.
+///
+/// # Example
+///
+/// ```
+/// use ta::indicators::{ParabolicSar, Trend};
+/// use ta::{Next, DataItem};
+///
+/// let mut psar = ParabolicSar::new(0.02, 0.2).unwrap();
+///
+/// let item = DataItem::builder()
+///     .open(9.5).high(10.0).low(9.0).close(9.5).volume(1.0).build().unwrap();
+///
+/// let out = psar.next(&item);
+/// assert_eq!(out.sar, 9.0);
+/// assert_eq!(out.trend, Trend::Up);
+/// ```
+///
+/// # Links
+///
+/// * [Parabolic SAR, Wikipedia](https://en.wikipedia.org/wiki/Parabolic_SAR)
+/// * [Parabolic SAR, stockcharts](https://school.stockcharts.com/doku.php?id=technical_indicators:parabolic_sar)
+#[doc(alias = "PSAR")]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone)]
+pub struct ParabolicSar<N = f64> {
+    step: f64,
+    maximum: f64,
+    state: State<N>,
+    prev_state: State<N>,
+    count: usize,
+}
+
+/// Direction of a trend.
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Trend {
+    Up,
+    Down,
+}
+
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, PartialEq)]
+pub struct ParabolicSarOutput<N = f64> {
+    pub sar: N,
+    pub trend: Trend,
+}
+
+impl<N: Num> ParabolicSarOutput<N> {
+    // The trend as a value: 1 for an uptrend, -1 for a downtrend.
+    fn trend_value(&self) -> N {
+        match self.trend {
+            Trend::Up => N::one(),
+            Trend::Down => -N::one(),
+        }
+    }
+}
+
+// Fields of the output, with the trend as 1 for an uptrend and -1 for a downtrend.
+impl<N: Num> Fields for ParabolicSarOutput<N> {
+    type Value = N;
+
+    const NAMES: &'static [&'static str] = &["sar", "trend"];
+
+    fn values(&self) -> Vec<N> {
+        vec![self.sar, self.trend_value()]
+    }
+
+    fn get(&self, name: &str) -> Option<N> {
+        match name {
+            "sar" => Some(self.sar),
+            "trend" => Some(self.trend_value()),
+            _ => None,
+        }
+    }
+}
+
+impl<N: Num> From<ParabolicSarOutput<N>> for [N; 2] {
+    fn from(output: ParabolicSarOutput<N>) -> Self {
+        [output.sar, output.trend_value()]
+    }
+}
+
+// State carried from one bar to the next one.
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, Copy)]
+struct State<N> {
+    af: N,
+    trend: Trend,
+    sar: N,
+    extreme_point: N,
+    prev_high: N,
+    prev_low: N,
+}
+
+impl<N: Num> State<N> {
+    fn new(af: f64) -> Self {
+        Self {
+            af: N::from_f64(af),
+            trend: Trend::Up,
+            sar: N::zero(),
+            extreme_point: N::zero(),
+            prev_high: N::zero(),
+            prev_low: N::zero(),
+        }
+    }
+}
+
+impl<N: Num> ParabolicSar<N> {
+    pub fn new(step: f64, maximum: f64) -> Result<Self> {
+        if step.is_nan() || maximum.is_nan() || step <= 0.0 || maximum < step {
+            return Err(TaError::InvalidParameter);
+        }
+
+        Ok(Self {
+            step,
+            maximum,
+            state: State::new(step),
+            prev_state: State::new(step),
+            count: 0,
+        })
+    }
+
+    pub fn step(&self) -> f64 {
+        self.step
+    }
+
+    pub fn maximum(&self) -> f64 {
+        self.maximum
+    }
+
+    // Calculates the output for a bar and the state for the next one.
+    fn advance(
+        &self,
+        mut state: State<N>,
+        high: N,
+        low: N,
+        is_first: bool,
+    ) -> (State<N>, ParabolicSarOutput<N>) {
+        if is_first {
+            state.trend = Trend::Up;
+            state.sar = low;
+            state.extreme_point = high;
+            state.af = N::from_f64(self.step);
+            state.prev_high = high;
+            state.prev_low = low;
+
+            let output = ParabolicSarOutput {
+                sar: low,
+                trend: Trend::Up,
+            };
+            return (state, output);
+        }
+
+        match state.trend {
+            Trend::Up => {
+                if low <= state.sar {
+                    state.trend = Trend::Down;
+                    state.sar = state.extreme_point.max(high).max(state.prev_high);
+                    state.extreme_point = low;
+                    state.af = N::from_f64(self.step);
+                } else if high > state.extreme_point {
+                    state.extreme_point = high;
+                    state.af = (state.af + N::from_f64(self.step)).min(N::from_f64(self.maximum));
+                }
+            }
+            Trend::Down => {
+                if high >= state.sar {
+                    state.trend = Trend::Up;
+                    state.sar = state.extreme_point.min(low).min(state.prev_low);
+                    state.extreme_point = high;
+                    state.af = N::from_f64(self.step);
+                } else if low < state.extreme_point {
+                    state.extreme_point = low;
+                    state.af = (state.af + N::from_f64(self.step)).min(N::from_f64(self.maximum));
+                }
+            }
+        }
+
+        let output = ParabolicSarOutput {
+            sar: state.sar,
+            trend: state.trend,
+        };
+
+        let next_sar = state.sar + state.af * (state.extreme_point - state.sar);
+        state.sar = match state.trend {
+            Trend::Up => next_sar.min(low).min(state.prev_low),
+            Trend::Down => next_sar.max(high).max(state.prev_high),
+        };
+        state.prev_high = high;
+        state.prev_low = low;
+
+        (state, output)
+    }
+}
+
+impl<N: Num> Lookback for ParabolicSar<N> {
+    fn lookback(&self) -> usize {
+        2
+    }
+
+    fn is_ready(&self) -> bool {
+        self.count == self.lookback()
+    }
+}
+
+impl<N: Num, T: High<N> + Low<N>> Next<&T> for ParabolicSar<N> {
+    type Output = ParabolicSarOutput<N>;
+
+    fn next(&mut self, input: &T) -> Self::Output {
+        if self.count < self.lookback() {
+            self.count += 1;
+        }
+
+        let (state, output) = self.advance(self.state, input.high(), input.low(), self.count == 1);
+        self.prev_state = self.state;
+        self.state = state;
+        output
+    }
+}
+
+impl_next_batch!(ParabolicSar, High + Low => ParabolicSarOutput<N>);
+
+impl<N: Num, T: High<N> + Low<N>> Peek<&T> for ParabolicSar<N> {
+    fn peek(&self, input: &T) -> Self::Output {
+        self.advance(self.state, input.high(), input.low(), self.count == 0)
+            .1
+    }
+
+    fn update_last(&mut self, input: &T) -> Self::Output {
+        if self.count == 0 {
+            return self.next(input);
+        }
+
+        let (state, output) =
+            self.advance(self.prev_state, input.high(), input.low(), self.count == 1);
+        self.state = state;
+        output
+    }
+}
+
+impl<N: Num> Reset for ParabolicSar<N> {
+    fn reset(&mut self) {
+        self.state = State::new(self.step);
+        self.prev_state = State::new(self.step);
+        self.count = 0;
+    }
+}
+
+impl<N: Num> Default for ParabolicSar<N> {
+    fn default() -> Self {
+        Self::new(0.02, 0.2).unwrap()
+    }
+}
+
+impl<N: Num> fmt::Display for ParabolicSar<N> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "PSAR({}, {})", self.step, self.maximum)
+    }
+}
+
+impl<N: Num> FromParams for ParabolicSar<N> {
+    const NAME: &'static str = "PSAR";
+
+    fn from_params(params: &[Param]) -> Result<Self> {
+        match *params {
+            [step, maximum] => Self::new(step.number()?, maximum.number()?),
+            _ => Err(TaError::InvalidParameter),
+        }
+    }
+}
+
+impl_from_str!(ParabolicSar);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::indicators::ParabolicSar;
+    use crate::test_helper::*;
+
+    #[test]
+    fn test_new() {
+        assert!(ParabolicSar::new(0.0, 0.2).is_err());
+        assert!(ParabolicSar::new(-0.02, 0.2).is_err());
+        assert!(ParabolicSar::new(0.02, 0.01).is_err());
+        assert!(ParabolicSar::new(f64::NAN, f64::NAN).is_err());
+        assert!(ParabolicSar::new(f64::NAN, 0.2).is_err());
+        assert!(ParabolicSar::new(0.02, f64::NAN).is_err());
+        assert!(ParabolicSar::new(0.02, 0.02).is_ok());
+        assert!(ParabolicSar::new(0.02, 0.2).is_ok());
+    }
+
+    #[test]
+    fn test_next_bar() {
+        let mut psar = ParabolicSar::new(0.02, 0.2).unwrap();
+
+        let test_data = vec![
+            // high, low, sar, trend
+            (10.0, 9.0, 9.0, Trend::Up),
+            (11.0, 9.5, 9.0, Trend::Up),
+            (12.0, 10.5, 9.0, Trend::Up),
+            (11.5, 9.0, 12.0, Trend::Down), // low crosses SAR of 9.18
+            (10.0, 8.0, 12.0, Trend::Down),
+            (9.0, 7.5, 11.84, Trend::Down),
+            (8.5, 7.0, 11.58, Trend::Down),
+        ];
+
+        for (high, low, sar, trend) in test_data {
+            let bar = Bar::new().high(high).low(low);
+            let out = psar.next(&bar);
+            assert_eq!(round(out.sar), round(sar));
+            assert_eq!(out.trend, trend);
+        }
+    }
+
+    #[test]
+    fn test_next_bar_reverse_to_up() {
+        let mut psar = ParabolicSar::new(0.1, 0.2).unwrap();
+
+        let test_data = vec![
+            // high, low, sar, trend
+            (10.0, 9.0, 9.0, Trend::Up),
+            (9.5, 8.0, 10.0, Trend::Down),
+            (9.0, 7.0, 10.0, Trend::Down),
+            (11.0, 9.5, 7.0, Trend::Up), // high crosses SAR of 9.5
+        ];
+
+        for (high, low, sar, trend) in test_data {
+            let bar = Bar::new().high(high).low(low);
+            let out = psar.next(&bar);
+            assert_eq!(round(out.sar), round(sar));
+            assert_eq!(out.trend, trend);
+        }
+    }
+
+    #[test]
+    fn test_max_acceleration() {
+        let mut psar = ParabolicSar::new(0.1, 0.15).unwrap();
+
+        psar.next(&Bar::new().high(10).low(9));
+        psar.next(&Bar::new().high(11).low(10));
+        psar.next(&Bar::new().high(12).low(11));
+        psar.next(&Bar::new().high(13).low(12));
+
+        assert_eq!(psar.state.af, 0.15);
+    }
+
+    #[test]
+    fn test_lookback() {
+        let mut psar = ParabolicSar::default();
+        assert_eq!(psar.lookback(), 2);
+
+        psar.next(&Bar::new().high(10).low(9));
+        assert!(!psar.is_ready());
+        psar.next(&Bar::new().high(11).low(10));
+        assert!(psar.is_ready());
+    }
+
+    #[test]
+    fn test_peek() {
+        let bars = peek_bars();
+        let bars: Vec<&Bar> = bars.iter().collect();
+        assert_peek(ParabolicSar::new(0.02, 0.2).unwrap(), &bars);
+        assert_peek(ParabolicSar::new(0.1, 0.3).unwrap(), &bars);
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut psar = ParabolicSar::new(0.02, 0.2).unwrap();
+
+        let bar1 = Bar::new().high(10).low(9);
+        let bar2 = Bar::new().high(9.5).low(8);
+
+        assert_eq!(psar.next(&bar1).trend, Trend::Up);
+        assert_eq!(psar.next(&bar2).trend, Trend::Down);
+
+        psar.reset();
+
+        let out = psar.next(&bar2);
+        assert_eq!(out.sar, 8.0);
+        assert_eq!(out.trend, Trend::Up);
+    }
+
+    #[test]
+    fn test_output_fields() {
+        let up = ParabolicSarOutput {
+            sar: 8.0,
+            trend: Trend::Up,
+        };
+        let down = ParabolicSarOutput {
+            sar: 12.0,
+            trend: Trend::Down,
+        };
+
+        assert_eq!(ParabolicSarOutput::<f64>::NAMES, ["sar", "trend"]);
+        assert_eq!(up.fields(), vec![("sar", 8.0), ("trend", 1.0)]);
+        assert_eq!(down.values(), vec![12.0, -1.0]);
+        assert_eq!(down.get("trend"), Some(-1.0));
+        assert_eq!(down.get("af"), None);
+        assert_eq!(<[f64; 2]>::from(up), [8.0, 1.0]);
+        assert_eq!(<[f64; 2]>::from(down), [12.0, -1.0]);
+    }
+
+    #[test]
+    fn test_default() {
+        ParabolicSar::default();
+    }
+
+    #[test]
+    fn test_display() {
+        let indicator = ParabolicSar::new(0.02, 0.2).unwrap();
+        assert_eq!(format!("{}", indicator), "PSAR(0.02, 0.2)");
+    }
+}
@@ -1,8 +1,9 @@
-use std::fmt;
+use core::fmt;
 
-use crate::errors::Result;
-use crate::indicators::ExponentialMovingAverage as Ema;
-use crate::{Close, Next, Period, Reset};
+use crate::errors::{Result, TaError};
+use crate::indicators::generic::{MovingAverage, MovingAverageType};
+use crate::registry::{FromParams, Param};
+use crate::{Close, Lookback, Next, Num, Peek, Period, Reset};
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
@@ -27,6 +28,9 @@ use serde::{Deserialize, Serialize};
 /// * _fast_period_ - period for the fast EMA. Default is 12.
 /// * _slow_period_ - period for the slow EMA. Default is 26.
 /// * _signal_period_ - period for the signal EMA. Default is 9.
+/// * _moving_average_ - type of the fast, slow and signal moving averages. Default is
+///   [`MovingAverageType::Exponential`](enum.MovingAverageType.html), see
+///   [`with_moving_average`](#method.with_moving_average).
 ///
 /// # Example
 ///
@@ -53,44 +57,85 @@ use serde::{Deserialize, Serialize};
 #[doc(alias = "PPO")]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[derive(Debug, Clone)]
-pub struct PercentagePriceOscillator {
-    fast_ema: Ema,
-    slow_ema: Ema,
-    signal_ema: Ema,
+pub struct PercentagePriceOscillator<N = f64> {
+    fast_ma: MovingAverage<N>,
+    slow_ma: MovingAverage<N>,
+    signal_ma: MovingAverage<N>,
+    count: usize,
 }
 
-impl PercentagePriceOscillator {
+impl<N: Num> PercentagePriceOscillator<N> {
     pub fn new(fast_period: usize, slow_period: usize, signal_period: usize) -> Result<Self> {
-        Ok(PercentagePriceOscillator {
-            fast_ema: Ema::new(fast_period)?,
-            slow_ema: Ema::new(slow_period)?,
-            signal_ema: Ema::new(signal_period)?,
+        Self::with_moving_average(
+            fast_period,
+            slow_period,
+            signal_period,
+            MovingAverageType::Exponential,
+        )
+    }
+
+    pub fn with_moving_average(
+        fast_period: usize,
+        slow_period: usize,
+        signal_period: usize,
+        moving_average: MovingAverageType,
+    ) -> Result<Self> {
+        Ok(Self {
+            fast_ma: MovingAverage::new(moving_average, fast_period)?,
+            slow_ma: MovingAverage::new(moving_average, slow_period)?,
+            signal_ma: MovingAverage::new(moving_average, signal_period)?,
+            count: 0,
         })
     }
+
+    pub fn moving_average(&self) -> MovingAverageType {
+        self.fast_ma.ma_type()
+    }
 }
 
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[derive(Debug, Clone, PartialEq)]
-pub struct PercentagePriceOscillatorOutput {
-    pub ppo: f64,
-    pub signal: f64,
-    pub histogram: f64,
+pub struct PercentagePriceOscillatorOutput<N = f64> {
+    pub ppo: N,
+    pub signal: N,
+    pub histogram: N,
 }
 
-impl From<PercentagePriceOscillatorOutput> for (f64, f64, f64) {
-    fn from(po: PercentagePriceOscillatorOutput) -> Self {
+impl_fields!(PercentagePriceOscillatorOutput {
+    ppo,
+    signal,
+    histogram
+});
+
+impl<N> From<PercentagePriceOscillatorOutput<N>> for (N, N, N) {
+    fn from(po: PercentagePriceOscillatorOutput<N>) -> Self {
         (po.ppo, po.signal, po.histogram)
     }
 }
 
-impl Next<f64> for PercentagePriceOscillator {
-    type Output = PercentagePriceOscillatorOutput;
+impl<N: Num> Lookback for PercentagePriceOscillator<N> {
+    fn lookback(&self) -> usize {
+        self.fast_ma.lookback().max(self.slow_ma.lookback()) + self.signal_ma.lookback() - 1
+    }
+
+    fn is_ready(&self) -> bool {
+        self.count == self.lookback()
+    }
+}
 
-    fn next(&mut self, input: f64) -> Self::Output {
-        let fast_val = self.fast_ema.next(input);
-        let slow_val = self.slow_ema.next(input);
+impl<N: Num> Next<N> for PercentagePriceOscillator<N> {
+    type Output = PercentagePriceOscillatorOutput<N>;
+
+    fn next(&mut self, input: N) -> Self::Output {
+        let fast_val = self.fast_ma.next(input);
+        let slow_val = self.slow_ma.next(input);
+
+        let ppo = (fast_val - slow_val) / slow_val * N::from_f64(100.0);
+        let signal = self.signal_ma.next(ppo);
+        if self.count < self.lookback() {
+            self.count += 1;
+        }
 
-        let ppo = (fast_val - slow_val) / slow_val * 100.0;
-        let signal = self.signal_ema.next(ppo);
         let histogram = ppo - signal;
 
         PercentagePriceOscillatorOutput {
@@ -101,43 +146,128 @@ impl Next<f64> for PercentagePriceOscillator {
     }
 }
 
-impl<T: Close> Next<&T> for PercentagePriceOscillator {
-    type Output = PercentagePriceOscillatorOutput;
+impl_next_batch!(PercentagePriceOscillator, N => PercentagePriceOscillatorOutput<N>);
+
+impl<N: Num> Peek<N> for PercentagePriceOscillator<N> {
+    fn peek(&self, input: N) -> Self::Output {
+        let fast_val = self.fast_ma.peek(input);
+        let slow_val = self.slow_ma.peek(input);
+
+        let ppo = (fast_val - slow_val) / slow_val * N::from_f64(100.0);
+        let signal = self.signal_ma.peek(ppo);
+
+        PercentagePriceOscillatorOutput {
+            ppo,
+            signal,
+            histogram: ppo - signal,
+        }
+    }
+
+    fn update_last(&mut self, input: N) -> Self::Output {
+        if self.count == 0 {
+            return self.next(input);
+        }
+
+        let fast_val = self.fast_ma.update_last(input);
+        let slow_val = self.slow_ma.update_last(input);
+
+        let ppo = (fast_val - slow_val) / slow_val * N::from_f64(100.0);
+        let signal = self.signal_ma.update_last(ppo);
+
+        PercentagePriceOscillatorOutput {
+            ppo,
+            signal,
+            histogram: ppo - signal,
+        }
+    }
+}
+
+impl_for_floats! {
+    impl<T: Close<N>> Next<&T> for PercentagePriceOscillator<N> {
+        type Output = PercentagePriceOscillatorOutput<N>;
 
-    fn next(&mut self, input: &T) -> Self::Output {
-        self.next(input.close())
+        fn next(&mut self, input: &T) -> Self::Output {
+            self.next(input.close())
+        }
+    }
+
+    impl_next_batch!(PercentagePriceOscillator<N>, Close => PercentagePriceOscillatorOutput<N>);
+
+    impl<T: Close<N>> Peek<&T> for PercentagePriceOscillator<N> {
+        fn peek(&self, input: &T) -> Self::Output {
+            self.peek(input.close())
+        }
+
+        fn update_last(&mut self, input: &T) -> Self::Output {
+            self.update_last(input.close())
+        }
     }
 }
 
-impl Reset for PercentagePriceOscillator {
+impl<N: Num> Reset for PercentagePriceOscillator<N> {
     fn reset(&mut self) {
-        self.fast_ema.reset();
-        self.slow_ema.reset();
-        self.signal_ema.reset();
+        self.fast_ma.reset();
+        self.slow_ma.reset();
+        self.signal_ma.reset();
+        self.count = 0;
     }
 }
 
-impl Default for PercentagePriceOscillator {
+impl<N: Num> Default for PercentagePriceOscillator<N> {
     fn default() -> Self {
         Self::new(12, 26, 9).unwrap()
     }
 }
 
-impl fmt::Display for PercentagePriceOscillator {
+impl<N: Num> fmt::Display for PercentagePriceOscillator<N> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(
-            f,
-            "PPO({}, {}, {})",
-            self.fast_ema.period(),
-            self.slow_ema.period(),
-            self.signal_ema.period()
-        )
+        match self.moving_average() {
+            MovingAverageType::Exponential => write!(
+                f,
+                "PPO({}, {}, {})",
+                self.fast_ma.period(),
+                self.slow_ma.period(),
+                self.signal_ma.period()
+            ),
+            moving_average => write!(
+                f,
+                "PPO({}, {}, {}, {})",
+                self.fast_ma.period(),
+                self.slow_ma.period(),
+                self.signal_ma.period(),
+                moving_average
+            ),
+        }
+    }
+}
+
+impl<N: Num> FromParams for PercentagePriceOscillator<N> {
+    const NAME: &'static str = "PPO";
+
+    fn from_params(params: &[Param]) -> Result<Self> {
+        match *params {
+            [fast_period, slow_period, signal_period] => Self::new(
+                fast_period.period()?,
+                slow_period.period()?,
+                signal_period.period()?,
+            ),
+            [fast_period, slow_period, signal_period, moving_average] => Self::with_moving_average(
+                fast_period.period()?,
+                slow_period.period()?,
+                signal_period.period()?,
+                moving_average.moving_average()?,
+            ),
+            _ => Err(TaError::InvalidParameter),
+        }
     }
 }
 
+impl_from_str!(PercentagePriceOscillator);
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::indicators::PercentagePriceOscillator;
     use crate::test_helper::*;
     type Ppo = PercentagePriceOscillator;
 
@@ -170,6 +300,18 @@ mod tests {
         assert_eq!(round(ppo.next(6.5).into()), (17.84, 19.08, -1.24));
     }
 
+    #[test]
+    fn test_next_sma() {
+        let mut ppo = Ppo::with_moving_average(2, 3, 2, MovingAverageType::Simple).unwrap();
+        assert_eq!(ppo.moving_average(), MovingAverageType::Simple);
+
+        assert_eq!(round(ppo.next(1.0).into()), (0.0, 0.0, 0.0));
+        assert_eq!(round(ppo.next(2.0).into()), (0.0, 0.0, 0.0));
+        assert_eq!(round(ppo.next(3.0).into()), (25.0, 12.5, 12.5));
+        assert_eq!(round(ppo.next(4.0).into()), (16.67, 20.83, -4.17));
+        assert_eq!(round(ppo.next(5.0).into()), (12.5, 14.58, -2.08));
+    }
+
     #[test]
     fn test_reset() {
         let mut ppo = Ppo::new(3, 6, 4).unwrap();
@@ -192,5 +334,7 @@ mod tests {
     fn test_display() {
         let indicator = Ppo::new(13, 30, 10).unwrap();
         assert_eq!(format!("{}", indicator), "PPO(13, 30, 10)");
+        let indicator = Ppo::with_moving_average(13, 30, 10, MovingAverageType::Simple).unwrap();
+        assert_eq!(format!("{}", indicator), "PPO(13, 30, 10, SMA)");
     }
 }
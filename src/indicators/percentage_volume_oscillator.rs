@@ -0,0 +1,367 @@
+use core::fmt;
+
+use crate::errors::{Result, TaError};
+use crate::indicators::generic::{MovingAverage, MovingAverageType};
+use crate::registry::{FromParams, Param};
+use crate::{Lookback, Next, Num, Peek, Period, Reset, Volume};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Percentage Volume Oscillator (PVO).
+///
+/// The same as [PPO](struct.PercentagePriceOscillator.html), but calculated over the volume
+/// instead of the price. It returns three series:
+///
+/// * The PVO series proper, the difference between a fast and a slow EMA of the volume
+///   in percents of the slow EMA
+/// * The "signal" series, an EMA of the PVO series
+/// * The "histogram" series, the difference between the two
+///
+/// # Formula
+///
+/// PVO = (EMA<sub>fast</sub>(volume) - EMA<sub>slow</sub>(volume)) / EMA<sub>slow</sub>(volume) × 100
+///
+/// Signal = EMA<sub>signal</sub>(PVO)
+///
+/// Histogram = PVO - Signal
+///
+/// When the slow EMA is 0 (no volume so far), PVO is 0.
+///
+/// # Parameters
+///
+/// * _fast_period_ - period for the fast EMA. Default is 12.
+/// * _slow_period_ - period for the slow EMA. Default is 26.
+/// * _signal_period_ - period for the signal EMA. Default is 9.
+/// * _moving_average_ - type of the fast, slow and signal moving averages. Default is
+///   [`MovingAverageType::Exponential`](enum.MovingAverageType.html), see
+///   [`with_moving_average`](#method.with_moving_average).
+///
+/// # Example
+///
+/// ```
+/// use ta::indicators::{MovingAverageType, PercentageVolumeOscillator as Pvo};
+/// use ta::{DataItem, Next};
+///
+/// let item = |volume: f64| {
+///     DataItem::builder()
+///         .open(1.0)
+///         .high(1.0)
+///         .low(1.0)
+///         .close(1.0)
+///         .volume(volume)
+///         .build()
+///         .unwrap()
+/// };
+///
+/// let mut pvo = Pvo::with_moving_average(1, 2, 2, MovingAverageType::Simple).unwrap();
+///
+/// assert_eq!(pvo.next(&item(100.0)).pvo, 0.0);
+/// let out = pvo.next(&item(300.0));
+/// assert_eq!(out.pvo, 50.0);
+/// assert_eq!(out.signal, 25.0);
+/// assert_eq!(out.histogram, 25.0);
+/// ```
+///
+/// # Links
+///
+/// * [Percentage Volume Oscillator, stockcharts](https://school.stockcharts.com/doku.php?id=technical_indicators:percentage_volume_oscillator_pvo)
+///
+#[doc(alias = "PVO")]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone)]
+pub struct PercentageVolumeOscillator<N = f64> {
+    fast_ma: MovingAverage<N>,
+    slow_ma: MovingAverage<N>,
+    signal_ma: MovingAverage<N>,
+    count: usize,
+}
+
+impl<N: Num> PercentageVolumeOscillator<N> {
+    pub fn new(fast_period: usize, slow_period: usize, signal_period: usize) -> Result<Self> {
+        Self::with_moving_average(
+            fast_period,
+            slow_period,
+            signal_period,
+            MovingAverageType::Exponential,
+        )
+    }
+
+    pub fn with_moving_average(
+        fast_period: usize,
+        slow_period: usize,
+        signal_period: usize,
+        moving_average: MovingAverageType,
+    ) -> Result<Self> {
+        Ok(Self {
+            fast_ma: MovingAverage::new(moving_average, fast_period)?,
+            slow_ma: MovingAverage::new(moving_average, slow_period)?,
+            signal_ma: MovingAverage::new(moving_average, signal_period)?,
+            count: 0,
+        })
+    }
+
+    pub fn moving_average(&self) -> MovingAverageType {
+        self.fast_ma.ma_type()
+    }
+
+    fn pvo(fast_val: N, slow_val: N) -> N {
+        if slow_val == N::zero() {
+            N::zero()
+        } else {
+            (fast_val - slow_val) / slow_val * N::from_f64(100.0)
+        }
+    }
+}
+
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, PartialEq)]
+pub struct PercentageVolumeOscillatorOutput<N = f64> {
+    pub pvo: N,
+    pub signal: N,
+    pub histogram: N,
+}
+
+impl_fields!(PercentageVolumeOscillatorOutput {
+    pvo,
+    signal,
+    histogram
+});
+
+impl<N> From<PercentageVolumeOscillatorOutput<N>> for (N, N, N) {
+    fn from(po: PercentageVolumeOscillatorOutput<N>) -> Self {
+        (po.pvo, po.signal, po.histogram)
+    }
+}
+
+impl<N: Num> Lookback for PercentageVolumeOscillator<N> {
+    fn lookback(&self) -> usize {
+        self.fast_ma.lookback().max(self.slow_ma.lookback()) + self.signal_ma.lookback() - 1
+    }
+
+    fn is_ready(&self) -> bool {
+        self.count == self.lookback()
+    }
+}
+
+impl<N: Num, T: Volume<N>> Next<&T> for PercentageVolumeOscillator<N> {
+    type Output = PercentageVolumeOscillatorOutput<N>;
+
+    fn next(&mut self, input: &T) -> Self::Output {
+        let fast_val = self.fast_ma.next(input.volume());
+        let slow_val = self.slow_ma.next(input.volume());
+
+        let pvo = Self::pvo(fast_val, slow_val);
+        let signal = self.signal_ma.next(pvo);
+        if self.count < self.lookback() {
+            self.count += 1;
+        }
+
+        PercentageVolumeOscillatorOutput {
+            pvo,
+            signal,
+            histogram: pvo - signal,
+        }
+    }
+}
+
+impl_next_batch!(PercentageVolumeOscillator, Volume => PercentageVolumeOscillatorOutput<N>);
+
+impl<N: Num, T: Volume<N>> Peek<&T> for PercentageVolumeOscillator<N> {
+    fn peek(&self, input: &T) -> Self::Output {
+        let fast_val = self.fast_ma.peek(input.volume());
+        let slow_val = self.slow_ma.peek(input.volume());
+
+        let pvo = Self::pvo(fast_val, slow_val);
+        let signal = self.signal_ma.peek(pvo);
+
+        PercentageVolumeOscillatorOutput {
+            pvo,
+            signal,
+            histogram: pvo - signal,
+        }
+    }
+
+    fn update_last(&mut self, input: &T) -> Self::Output {
+        if self.count == 0 {
+            return self.next(input);
+        }
+
+        let fast_val = self.fast_ma.update_last(input.volume());
+        let slow_val = self.slow_ma.update_last(input.volume());
+
+        let pvo = Self::pvo(fast_val, slow_val);
+        let signal = self.signal_ma.update_last(pvo);
+
+        PercentageVolumeOscillatorOutput {
+            pvo,
+            signal,
+            histogram: pvo - signal,
+        }
+    }
+}
+
+impl<N: Num> Reset for PercentageVolumeOscillator<N> {
+    fn reset(&mut self) {
+        self.fast_ma.reset();
+        self.slow_ma.reset();
+        self.signal_ma.reset();
+        self.count = 0;
+    }
+}
+
+impl<N: Num> Default for PercentageVolumeOscillator<N> {
+    fn default() -> Self {
+        Self::new(12, 26, 9).unwrap()
+    }
+}
+
+impl<N: Num> fmt::Display for PercentageVolumeOscillator<N> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self.moving_average() {
+            MovingAverageType::Exponential => write!(
+                f,
+                "PVO({}, {}, {})",
+                self.fast_ma.period(),
+                self.slow_ma.period(),
+                self.signal_ma.period()
+            ),
+            moving_average => write!(
+                f,
+                "PVO({}, {}, {}, {})",
+                self.fast_ma.period(),
+                self.slow_ma.period(),
+                self.signal_ma.period(),
+                moving_average
+            ),
+        }
+    }
+}
+
+impl<N: Num> FromParams for PercentageVolumeOscillator<N> {
+    const NAME: &'static str = "PVO";
+
+    fn from_params(params: &[Param]) -> Result<Self> {
+        match *params {
+            [fast_period, slow_period, signal_period] => Self::new(
+                fast_period.period()?,
+                slow_period.period()?,
+                signal_period.period()?,
+            ),
+            [fast_period, slow_period, signal_period, moving_average] => Self::with_moving_average(
+                fast_period.period()?,
+                slow_period.period()?,
+                signal_period.period()?,
+                moving_average.moving_average()?,
+            ),
+            _ => Err(TaError::InvalidParameter),
+        }
+    }
+}
+
+impl_from_str!(PercentageVolumeOscillator);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::indicators::PercentageVolumeOscillator;
+    use crate::test_helper::*;
+    type Pvo = PercentageVolumeOscillator;
+
+    fn round(nums: (f64, f64, f64)) -> (f64, f64, f64) {
+        let n0 = (nums.0 * 100.0).round() / 100.0;
+        let n1 = (nums.1 * 100.0).round() / 100.0;
+        let n2 = (nums.2 * 100.0).round() / 100.0;
+        (n0, n1, n2)
+    }
+
+    fn bar(volume: f64) -> Bar {
+        Bar::new().volume(volume)
+    }
+
+    #[test]
+    fn test_new() {
+        assert!(Pvo::new(0, 1, 1).is_err());
+        assert!(Pvo::new(1, 0, 1).is_err());
+        assert!(Pvo::new(1, 1, 0).is_err());
+        assert!(Pvo::new(1, 1, 1).is_ok());
+    }
+
+    #[test]
+    fn test_next_bar() {
+        let mut pvo = Pvo::new(3, 6, 4).unwrap();
+
+        assert_eq!(round(pvo.next(&bar(200.0)).into()), (0.0, 0.0, 0.0));
+        assert_eq!(round(pvo.next(&bar(300.0)).into()), (9.38, 3.75, 5.63));
+        assert_eq!(round(pvo.next(&bar(420.0)).into()), (18.26, 9.56, 8.71));
+        assert_eq!(round(pvo.next(&bar(700.0)).into()), (28.62, 17.18, 11.44));
+    }
+
+    #[test]
+    fn test_next_zero_volume() {
+        let mut pvo = Pvo::new(3, 6, 4).unwrap();
+
+        assert_eq!(round(pvo.next(&bar(0.0)).into()), (0.0, 0.0, 0.0));
+        assert_eq!(round(pvo.next(&bar(0.0)).into()), (0.0, 0.0, 0.0));
+        assert_eq!(round(pvo.next(&bar(100.0)).into()), (75.0, 30.0, 45.0));
+    }
+
+    #[test]
+    fn test_next_sma() {
+        let mut pvo = Pvo::with_moving_average(2, 3, 2, MovingAverageType::Simple).unwrap();
+        assert_eq!(pvo.moving_average(), MovingAverageType::Simple);
+
+        assert_eq!(round(pvo.next(&bar(100.0)).into()), (0.0, 0.0, 0.0));
+        assert_eq!(round(pvo.next(&bar(200.0)).into()), (0.0, 0.0, 0.0));
+        assert_eq!(round(pvo.next(&bar(300.0)).into()), (25.0, 12.5, 12.5));
+    }
+
+    #[test]
+    fn test_peek() {
+        let bars = peek_bars();
+        let bars: Vec<&Bar> = bars.iter().collect();
+        assert_peek(Pvo::new(3, 6, 4).unwrap(), &bars);
+        assert_peek(
+            Pvo::with_moving_average(2, 3, 2, MovingAverageType::Simple).unwrap(),
+            &bars,
+        );
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut pvo = Pvo::new(3, 6, 4).unwrap();
+
+        assert_eq!(round(pvo.next(&bar(200.0)).into()), (0.0, 0.0, 0.0));
+        assert_eq!(round(pvo.next(&bar(300.0)).into()), (9.38, 3.75, 5.63));
+
+        pvo.reset();
+
+        assert_eq!(round(pvo.next(&bar(200.0)).into()), (0.0, 0.0, 0.0));
+        assert_eq!(round(pvo.next(&bar(300.0)).into()), (9.38, 3.75, 5.63));
+    }
+
+    #[test]
+    fn test_lookback() {
+        let mut pvo = Pvo::new(3, 6, 4).unwrap();
+        assert_eq!(pvo.lookback(), 9);
+
+        for _ in 0..8 {
+            pvo.next(&bar(100.0));
+            assert!(!pvo.is_ready());
+        }
+        pvo.next(&bar(100.0));
+        assert!(pvo.is_ready());
+    }
+
+    #[test]
+    fn test_default() {
+        Pvo::default();
+    }
+
+    #[test]
+    fn test_display() {
+        let indicator = Pvo::new(13, 30, 10).unwrap();
+        assert_eq!(format!("{}", indicator), "PVO(13, 30, 10)");
+        let indicator = Pvo::with_moving_average(13, 30, 10, MovingAverageType::Simple).unwrap();
+        assert_eq!(format!("{}", indicator), "PVO(13, 30, 10, SMA)");
+    }
+}
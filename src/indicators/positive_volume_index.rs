@@ -0,0 +1,207 @@
+use core::fmt;
+
+use crate::errors::{Result, TaError};
+use crate::indicators::volume_index::{VolumeChange, VolumeIndex};
+use crate::registry::{FromParams, Param};
+use crate::{Close, Lookback, Next, Num, Peek, Reset, Volume};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Positive Volume Index (PVI).
+///
+/// A cumulative indicator, which only changes on bars, where the volume increases compared to
+/// the prior bar. The idea is that the crowd follows the price on busy days, when the volume is
+/// high. See also [Negative Volume Index](struct.NegativeVolumeIndex.html).
+///
+/// # Formula
+///
+/// If the volume is above the prior volume then:
+/// PVI<sub>t</sub> = PVI<sub>t-1</sub> × close<sub>t</sub> / close<sub>t-1</sub>
+///
+/// Otherwise:
+/// PVI<sub>t</sub> = PVI<sub>t-1</sub>
+///
+/// PVI starts at 1000. When the prior close is 0, PVI doesn't change.
+///
+/// # Example
+///
+/// ```
+/// use ta::indicators::PositiveVolumeIndex;
+/// use ta::{DataItem, Next};
+///
+/// let item = |close: f64, volume: f64| {
+///     DataItem::builder()
+///         .open(close)
+///         .high(close)
+///         .low(close)
+///         .close(close)
+///         .volume(volume)
+///         .build()
+///         .unwrap()
+/// };
+///
+/// let mut pvi = PositiveVolumeIndex::new();
+/// assert_eq!(pvi.next(&item(10.0, 100.0)), 1000.0);
+/// assert_eq!(pvi.next(&item(10.0, 50.0)), 1000.0);
+/// assert_eq!(pvi.next(&item(12.0, 80.0)), 1200.0);
+/// ```
+///
+/// # Links
+///
+/// * [Positive volume index, Wikipedia](https://en.wikipedia.org/wiki/Positive_volume_index)
+///
+#[doc(alias = "PVI")]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone)]
+pub struct PositiveVolumeIndex<N = f64> {
+    index: VolumeIndex<N>,
+}
+
+impl<N: Num> PositiveVolumeIndex<N> {
+    pub fn new() -> Self {
+        Self {
+            index: VolumeIndex::new(VolumeChange::Increase),
+        }
+    }
+}
+
+impl<N: Num> Lookback for PositiveVolumeIndex<N> {
+    fn lookback(&self) -> usize {
+        2
+    }
+
+    fn is_ready(&self) -> bool {
+        self.index.count() == self.lookback()
+    }
+}
+
+impl<N: Num, T: Close<N> + Volume<N>> Next<&T> for PositiveVolumeIndex<N> {
+    type Output = N;
+
+    fn next(&mut self, input: &T) -> N {
+        self.index.next(input.close(), input.volume())
+    }
+}
+
+impl_next_batch!(PositiveVolumeIndex, Close + Volume => N);
+
+impl<N: Num, T: Close<N> + Volume<N>> Peek<&T> for PositiveVolumeIndex<N> {
+    fn peek(&self, input: &T) -> N {
+        self.index.peek(input.close(), input.volume())
+    }
+
+    fn update_last(&mut self, input: &T) -> N {
+        self.index.update_last(input.close(), input.volume())
+    }
+}
+
+impl<N: Num> Default for PositiveVolumeIndex<N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<N: Num> fmt::Display for PositiveVolumeIndex<N> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "PVI")
+    }
+}
+
+impl<N: Num> Reset for PositiveVolumeIndex<N> {
+    fn reset(&mut self) {
+        self.index.reset();
+    }
+}
+
+impl<N: Num> FromParams for PositiveVolumeIndex<N> {
+    const NAME: &'static str = "PVI";
+
+    fn from_params(params: &[Param]) -> Result<Self> {
+        match *params {
+            [] => Ok(Self::new()),
+            _ => Err(TaError::InvalidParameter),
+        }
+    }
+}
+
+impl_from_str!(PositiveVolumeIndex);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::indicators::PositiveVolumeIndex;
+    use crate::test_helper::*;
+
+    #[test]
+    fn test_next_bar() {
+        let mut pvi = PositiveVolumeIndex::new();
+
+        let bar1 = Bar::new().close(10).volume(100.0);
+        let bar2 = Bar::new().close(10).volume(50.0);
+        let bar3 = Bar::new().close(12).volume(80.0);
+        let bar4 = Bar::new().close(9).volume(160.0);
+        let bar5 = Bar::new().close(12).volume(160.0);
+
+        assert_eq!(pvi.next(&bar1), 1000.0);
+
+        // volume < prev_volume
+        assert_eq!(pvi.next(&bar2), 1000.0);
+
+        // volume > prev_volume
+        assert_eq!(pvi.next(&bar3), 1200.0);
+
+        assert_eq!(pvi.next(&bar4), 900.0);
+
+        // volume == prev_volume
+        assert_eq!(pvi.next(&bar5), 900.0);
+    }
+
+    #[test]
+    fn test_peek() {
+        let bars = peek_bars();
+        let bars: Vec<&Bar> = bars.iter().collect();
+        assert_peek(PositiveVolumeIndex::new(), &bars);
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut pvi = PositiveVolumeIndex::new();
+
+        let bar1 = Bar::new().close(10).volume(100.0);
+        let bar2 = Bar::new().close(11).volume(200.0);
+
+        assert_eq!(pvi.next(&bar1), 1000.0);
+        assert_eq!(pvi.next(&bar2), 1100.0);
+
+        pvi.reset();
+
+        assert_eq!(pvi.next(&bar1), 1000.0);
+        assert_eq!(pvi.next(&bar2), 1100.0);
+    }
+
+    #[test]
+    fn test_lookback() {
+        let mut pvi = PositiveVolumeIndex::new();
+        assert_eq!(pvi.lookback(), 2);
+
+        let bar = Bar::new().close(10).volume(100.0);
+        pvi.next(&bar);
+        assert!(!pvi.is_ready());
+        pvi.next(&bar);
+        assert!(pvi.is_ready());
+
+        pvi.reset();
+        assert!(!pvi.is_ready());
+    }
+
+    #[test]
+    fn test_default() {
+        PositiveVolumeIndex::default();
+    }
+
+    #[test]
+    fn test_display() {
+        let pvi = PositiveVolumeIndex::new();
+        assert_eq!(format!("{}", pvi), "PVI");
+    }
+}
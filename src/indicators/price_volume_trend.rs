@@ -0,0 +1,237 @@
+use core::fmt;
+
+use crate::errors::{Result, TaError};
+use crate::registry::{FromParams, Param};
+use crate::{Close, Lookback, Next, Num, Peek, Reset, Volume};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Price Volume Trend (PVT).
+///
+/// A cumulative volume based indicator similar to
+/// [OBV](struct.OnBalanceVolume.html), but instead of adding or subtracting the whole volume,
+/// it adds the volume multiplied by the percentage change of the close price.
+///
+/// # Formula
+///
+/// PVT<sub>t</sub> = PVT<sub>t-1</sub> + volume<sub>t</sub> × (close<sub>t</sub> - close<sub>t-1</sub>) / close<sub>t-1</sub>
+///
+/// The first bar has no prior close, so PVT starts at 0. When the prior close is 0, the
+/// volume of the bar is not added.
+///
+/// # Example
+///
+/// ```
+/// use ta::indicators::PriceVolumeTrend;
+/// use ta::{DataItem, Next};
+///
+/// let item = |close: f64, volume: f64| {
+///     DataItem::builder()
+///         .open(close)
+///         .high(close)
+///         .low(close)
+///         .close(close)
+///         .volume(volume)
+///         .build()
+///         .unwrap()
+/// };
+///
+/// let mut pvt = PriceVolumeTrend::new();
+/// assert_eq!(pvt.next(&item(10.0, 100.0)), 0.0);
+/// assert_eq!(pvt.next(&item(11.0, 200.0)), 20.0);
+/// assert_eq!(pvt.next(&item(8.8, 100.0)).round(), 0.0);
+/// ```
+///
+/// # Links
+///
+/// * [Volume–price trend, Wikipedia](https://en.wikipedia.org/wiki/Volume%E2%80%93price_trend)
+///
+#[doc(alias = "PVT")]
+#[doc(alias = "VPT")]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone)]
+pub struct PriceVolumeTrend<N = f64> {
+    pvt: N,
+    prev_pvt: N,
+    prev_close: N,
+    prev_prev_close: N,
+    count: usize,
+}
+
+impl<N: Num> PriceVolumeTrend<N> {
+    pub fn new() -> Self {
+        Self {
+            pvt: N::zero(),
+            prev_pvt: N::zero(),
+            prev_close: N::zero(),
+            prev_prev_close: N::zero(),
+            count: 0,
+        }
+    }
+
+    fn calc<T: Close<N> + Volume<N>>(pvt: N, prev_close: N, input: &T) -> N {
+        if prev_close == N::zero() {
+            pvt
+        } else {
+            pvt + input.volume() * (input.close() - prev_close) / prev_close
+        }
+    }
+}
+
+impl<N: Num> Lookback for PriceVolumeTrend<N> {
+    fn lookback(&self) -> usize {
+        2
+    }
+
+    fn is_ready(&self) -> bool {
+        self.count == self.lookback()
+    }
+}
+
+impl<N: Num, T: Close<N> + Volume<N>> Next<&T> for PriceVolumeTrend<N> {
+    type Output = N;
+
+    fn next(&mut self, input: &T) -> N {
+        self.prev_pvt = self.pvt;
+        if self.count > 0 {
+            self.pvt = Self::calc(self.pvt, self.prev_close, input);
+        }
+        self.prev_prev_close = self.prev_close;
+        self.prev_close = input.close();
+        if self.count < self.lookback() {
+            self.count += 1;
+        }
+        self.pvt
+    }
+}
+
+impl_next_batch!(PriceVolumeTrend, Close + Volume => N);
+
+impl<N: Num, T: Close<N> + Volume<N>> Peek<&T> for PriceVolumeTrend<N> {
+    fn peek(&self, input: &T) -> N {
+        if self.count == 0 {
+            return self.pvt;
+        }
+        Self::calc(self.pvt, self.prev_close, input)
+    }
+
+    fn update_last(&mut self, input: &T) -> N {
+        match self.count {
+            0 => return self.next(input),
+            1 => {}
+            _ => self.pvt = Self::calc(self.prev_pvt, self.prev_prev_close, input),
+        }
+        self.prev_close = input.close();
+        self.pvt
+    }
+}
+
+impl<N: Num> Default for PriceVolumeTrend<N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<N: Num> fmt::Display for PriceVolumeTrend<N> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "PVT")
+    }
+}
+
+impl<N: Num> Reset for PriceVolumeTrend<N> {
+    fn reset(&mut self) {
+        self.pvt = N::zero();
+        self.prev_pvt = N::zero();
+        self.prev_close = N::zero();
+        self.prev_prev_close = N::zero();
+        self.count = 0;
+    }
+}
+
+impl<N: Num> FromParams for PriceVolumeTrend<N> {
+    const NAME: &'static str = "PVT";
+
+    fn from_params(params: &[Param]) -> Result<Self> {
+        match *params {
+            [] => Ok(Self::new()),
+            _ => Err(TaError::InvalidParameter),
+        }
+    }
+}
+
+impl_from_str!(PriceVolumeTrend);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::indicators::PriceVolumeTrend;
+    use crate::test_helper::*;
+
+    #[test]
+    fn test_next_bar() {
+        let mut pvt = PriceVolumeTrend::new();
+
+        let bar1 = Bar::new().close(10).volume(100.0);
+        let bar2 = Bar::new().close(11).volume(200.0);
+        let bar3 = Bar::new().close(8.8).volume(100.0);
+        let bar4 = Bar::new().close(0).volume(100.0);
+        let bar5 = Bar::new().close(5).volume(500.0);
+
+        assert_eq!(pvt.next(&bar1), 0.0);
+        assert_eq!(pvt.next(&bar2), 20.0);
+        assert_eq!(round(pvt.next(&bar3)), 0.0);
+        assert_eq!(round(pvt.next(&bar4)), -100.0);
+
+        // prev_close == 0
+        assert_eq!(round(pvt.next(&bar5)), -100.0);
+    }
+
+    #[test]
+    fn test_peek() {
+        let bars = peek_bars();
+        let bars: Vec<&Bar> = bars.iter().collect();
+        assert_peek(PriceVolumeTrend::new(), &bars);
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut pvt = PriceVolumeTrend::new();
+
+        let bar1 = Bar::new().close(10).volume(100.0);
+        let bar2 = Bar::new().close(11).volume(200.0);
+
+        assert_eq!(pvt.next(&bar1), 0.0);
+        assert_eq!(pvt.next(&bar2), 20.0);
+
+        pvt.reset();
+
+        assert_eq!(pvt.next(&bar1), 0.0);
+        assert_eq!(pvt.next(&bar2), 20.0);
+    }
+
+    #[test]
+    fn test_lookback() {
+        let mut pvt = PriceVolumeTrend::new();
+        assert_eq!(pvt.lookback(), 2);
+
+        let bar = Bar::new().close(10).volume(100.0);
+        pvt.next(&bar);
+        assert!(!pvt.is_ready());
+        pvt.next(&bar);
+        assert!(pvt.is_ready());
+
+        pvt.reset();
+        assert!(!pvt.is_ready());
+    }
+
+    #[test]
+    fn test_default() {
+        PriceVolumeTrend::default();
+    }
+
+    #[test]
+    fn test_display() {
+        let pvt = PriceVolumeTrend::new();
+        assert_eq!(format!("{}", pvt), "PVT");
+    }
+}
@@ -1,7 +1,11 @@
-use std::fmt;
+use alloc::boxed::Box;
+use alloc::vec;
+use core::fmt;
 
 use crate::errors::{Result, TaError};
-use crate::traits::{Close, Next, Period, Reset};
+use crate::registry::{FromParams, Param};
+use crate::traits::{Close, Lookback, Next, Peek, Period, Reset};
+use crate::Num;
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
@@ -41,14 +45,15 @@ use serde::{Deserialize, Serialize};
 #[doc(alias = "ROC")]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[derive(Debug, Clone)]
-pub struct RateOfChange {
+pub struct RateOfChange<N = f64> {
     period: usize,
     index: usize,
     count: usize,
-    deque: Box<[f64]>,
+    previous: N,
+    deque: Box<[N]>,
 }
 
-impl RateOfChange {
+impl<N: Num> RateOfChange<N> {
     pub fn new(period: usize) -> Result<Self> {
         match period {
             0 => Err(TaError::InvalidParameter),
@@ -56,22 +61,33 @@ impl RateOfChange {
                 period,
                 index: 0,
                 count: 0,
-                deque: vec![0.0; period].into_boxed_slice(),
+                previous: N::zero(),
+                deque: vec![N::zero(); period].into_boxed_slice(),
             }),
         }
     }
 }
 
-impl Period for RateOfChange {
+impl<N: Num> Period for RateOfChange<N> {
     fn period(&self) -> usize {
         self.period
     }
 }
 
-impl Next<f64> for RateOfChange {
-    type Output = f64;
+impl<N: Num> Lookback for RateOfChange<N> {
+    fn lookback(&self) -> usize {
+        self.period + 1
+    }
+
+    fn is_ready(&self) -> bool {
+        self.count > self.period
+    }
+}
+
+impl<N: Num> Next<N> for RateOfChange<N> {
+    type Output = N;
 
-    fn next(&mut self, input: f64) -> f64 {
+    fn next(&mut self, input: N) -> N {
         let previous = if self.count > self.period {
             self.deque[self.index]
         } else {
@@ -89,44 +105,108 @@ impl Next<f64> for RateOfChange {
         } else {
             0
         };
+        self.previous = previous;
 
-        (input - previous) / previous * 100.0
+        (input - previous) / previous * N::from_f64(100.0)
     }
 }
 
-impl<T: Close> Next<&T> for RateOfChange {
-    type Output = f64;
+impl_next_batch!(RateOfChange, N => N);
+
+impl<N: Num> Peek<N> for RateOfChange<N> {
+    fn peek(&self, input: N) -> N {
+        let previous = if self.count > self.period {
+            self.deque[self.index]
+        } else if self.count == 0 {
+            input
+        } else {
+            self.deque[0]
+        };
+
+        (input - previous) / previous * N::from_f64(100.0)
+    }
+
+    fn update_last(&mut self, input: N) -> N {
+        if self.count == 0 {
+            return self.next(input);
+        }
+
+        let last = if self.index == 0 {
+            self.period - 1
+        } else {
+            self.index - 1
+        };
+        self.deque[last] = input;
+        if self.count == 1 {
+            self.previous = input;
+        }
 
-    fn next(&mut self, input: &T) -> f64 {
-        self.next(input.close())
+        (input - self.previous) / self.previous * N::from_f64(100.0)
     }
 }
 
-impl Default for RateOfChange {
+impl<N: Num> Default for RateOfChange<N> {
     fn default() -> Self {
         Self::new(9).unwrap()
     }
 }
 
-impl fmt::Display for RateOfChange {
+impl<N: Num> fmt::Display for RateOfChange<N> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         write!(f, "ROC({})", self.period)
     }
 }
 
-impl Reset for RateOfChange {
+impl_for_floats! {
+    impl<T: Close<N>> Next<&T> for RateOfChange<N> {
+        type Output = N;
+
+        fn next(&mut self, input: &T) -> N {
+            self.next(input.close())
+        }
+    }
+
+    impl_next_batch!(RateOfChange<N>, Close => N);
+
+    impl<T: Close<N>> Peek<&T> for RateOfChange<N> {
+        fn peek(&self, input: &T) -> N {
+            self.peek(input.close())
+        }
+
+        fn update_last(&mut self, input: &T) -> N {
+            self.update_last(input.close())
+        }
+    }
+}
+
+impl<N: Num> Reset for RateOfChange<N> {
     fn reset(&mut self) {
         self.index = 0;
         self.count = 0;
+        self.previous = N::zero();
         for i in 0..self.period {
-            self.deque[i] = 0.0;
+            self.deque[i] = N::zero();
+        }
+    }
+}
+
+impl<N: Num> FromParams for RateOfChange<N> {
+    const NAME: &'static str = "ROC";
+
+    fn from_params(params: &[Param]) -> Result<Self> {
+        match *params {
+            [period] => Self::new(period.period()?),
+            _ => Err(TaError::InvalidParameter),
         }
     }
 }
 
+impl_from_str!(RateOfChange);
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::indicators::RateOfChange;
     use crate::test_helper::*;
 
     test_indicator!(RateOfChange);
@@ -1,8 +1,9 @@
-use std::fmt;
+use core::fmt;
 
-use crate::errors::Result;
-use crate::indicators::ExponentialMovingAverage as Ema;
-use crate::{Close, Next, Period, Reset};
+use crate::errors::{Result, TaError};
+use crate::indicators::generic::{MovingAverage, MovingAverageType};
+use crate::registry::{FromParams, Param};
+use crate::{Close, Lookback, Next, Num, Peek, Period, Reset};
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
@@ -47,9 +48,19 @@ use serde::{Deserialize, Serialize};
 /// * p<sub>t</sub> - input value in a moment of time _t_
 /// * p<sub>t-1</sub> - input value in a moment of time _t-1_
 ///
+/// # Smoothing
+///
+/// By default U and D are smoothed with an EMA, and the very first period feeds a small seed
+/// value of 0.1 to both of them. Use [`with_smoothing`](#method.with_smoothing) to pick another
+/// [moving average type](enum.MovingAverageType.html), e.g. Wilder's smoothing as in the original
+/// RSI definition. Other types don't use the seed: the first period only records the price and
+/// returns 50, and if there were no price changes at all 50 is returned as well.
+///
 /// # Parameters
 ///
 /// * _period_ - number of periods (integer greater than 0). Default value is 14.
+/// * _smoothing_ - type of moving average used to smooth U and D. Default is
+///   [`MovingAverageType::Exponential`](enum.MovingAverageType.html).
 ///
 /// # Example
 ///
@@ -64,6 +75,19 @@ use serde::{Deserialize, Serialize};
 /// assert_eq!(rsi.next(9.5).round(), 16.0);
 /// ```
 ///
+/// With Wilder's smoothing:
+///
+/// ```
+/// use ta::indicators::{MovingAverageType, RelativeStrengthIndex};
+/// use ta::Next;
+///
+/// let mut rsi = RelativeStrengthIndex::with_smoothing(3, MovingAverageType::Wilder).unwrap();
+/// assert_eq!(rsi.next(10.0), 50.0);
+/// assert_eq!(rsi.next(10.5), 100.0);
+/// assert_eq!(rsi.next(10.0), 50.0);
+/// assert_eq!(rsi.next(9.5).round(), 33.0);
+/// ```
+///
 /// # Links
 /// * [Relative strength index (Wikipedia)](https://en.wikipedia.org/wiki/Relative_strength_index)
 /// * [RSI (Investopedia)](http://www.investopedia.com/terms/r/rsi.asp)
@@ -71,91 +95,204 @@ use serde::{Deserialize, Serialize};
 #[doc(alias = "RSI")]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[derive(Debug, Clone)]
-pub struct RelativeStrengthIndex {
+pub struct RelativeStrengthIndex<N = f64> {
     period: usize,
-    up_ema_indicator: Ema,
-    down_ema_indicator: Ema,
-    prev_val: f64,
+    up_indicator: MovingAverage<N>,
+    down_indicator: MovingAverage<N>,
+    prev_val: N,
+    prev_prev_val: N,
     is_new: bool,
+    count: usize,
 }
 
-impl RelativeStrengthIndex {
+impl<N: Num> RelativeStrengthIndex<N> {
     pub fn new(period: usize) -> Result<Self> {
+        Self::with_smoothing(period, MovingAverageType::Exponential)
+    }
+
+    pub fn with_smoothing(period: usize, smoothing: MovingAverageType) -> Result<Self> {
         Ok(Self {
             period,
-            up_ema_indicator: Ema::new(period)?,
-            down_ema_indicator: Ema::new(period)?,
-            prev_val: 0.0,
+            up_indicator: MovingAverage::new(smoothing, period)?,
+            down_indicator: MovingAverage::new(smoothing, period)?,
+            prev_val: N::zero(),
+            prev_prev_val: N::zero(),
             is_new: true,
+            count: 0,
         })
     }
+
+    pub fn smoothing(&self) -> MovingAverageType {
+        self.up_indicator.ma_type()
+    }
+
+    // Returns the upward and the downward change.
+    fn changes(prev_val: N, input: N) -> (N, N) {
+        if input > prev_val {
+            (input - prev_val, N::zero())
+        } else {
+            (N::zero(), prev_val - input)
+        }
+    }
+
+    fn rsi(up_avg: N, down_avg: N) -> N {
+        if up_avg + down_avg == N::zero() {
+            return N::from_f64(50.0);
+        }
+        N::from_f64(100.0) * up_avg / (up_avg + down_avg)
+    }
 }
 
-impl Period for RelativeStrengthIndex {
+impl<N: Num> Period for RelativeStrengthIndex<N> {
     fn period(&self) -> usize {
         self.period
     }
 }
 
-impl Next<f64> for RelativeStrengthIndex {
-    type Output = f64;
+impl<N: Num> Lookback for RelativeStrengthIndex<N> {
+    fn lookback(&self) -> usize {
+        self.period + 1
+    }
 
-    fn next(&mut self, input: f64) -> Self::Output {
-        let mut up = 0.0;
-        let mut down = 0.0;
+    fn is_ready(&self) -> bool {
+        self.count == self.lookback()
+    }
+}
 
-        if self.is_new {
+impl<N: Num> Next<N> for RelativeStrengthIndex<N> {
+    type Output = N;
+
+    fn next(&mut self, input: N) -> Self::Output {
+        let (up, down) = if self.is_new {
             self.is_new = false;
+            if self.smoothing() != MovingAverageType::Exponential {
+                self.prev_val = input;
+                self.count += 1;
+                return N::from_f64(50.0);
+            }
             // Initialize with some small seed numbers to avoid division by zero
-            up = 0.1;
-            down = 0.1;
+            (N::from_f64(0.1), N::from_f64(0.1))
         } else {
-            if input > self.prev_val {
-                up = input - self.prev_val;
-            } else {
-                down = self.prev_val - input;
+            Self::changes(self.prev_val, input)
+        };
+
+        self.prev_prev_val = self.prev_val;
+        self.prev_val = input;
+        if self.count < self.lookback() {
+            self.count += 1;
+        }
+        let up_avg = self.up_indicator.next(up);
+        let down_avg = self.down_indicator.next(down);
+        Self::rsi(up_avg, down_avg)
+    }
+}
+
+impl_next_batch!(RelativeStrengthIndex, N => N);
+
+impl<N: Num> Peek<N> for RelativeStrengthIndex<N> {
+    fn peek(&self, input: N) -> Self::Output {
+        let (up, down) = if self.is_new {
+            if self.smoothing() != MovingAverageType::Exponential {
+                return N::from_f64(50.0);
             }
+            (N::from_f64(0.1), N::from_f64(0.1))
+        } else {
+            Self::changes(self.prev_val, input)
+        };
+
+        Self::rsi(self.up_indicator.peek(up), self.down_indicator.peek(down))
+    }
+
+    fn update_last(&mut self, input: N) -> Self::Output {
+        if self.is_new {
+            return self.next(input);
         }
 
+        let (up, down) = if self.count == 1 {
+            self.prev_val = input;
+            if self.smoothing() != MovingAverageType::Exponential {
+                return N::from_f64(50.0);
+            }
+            (N::from_f64(0.1), N::from_f64(0.1))
+        } else {
+            Self::changes(self.prev_prev_val, input)
+        };
+
         self.prev_val = input;
-        let up_ema = self.up_ema_indicator.next(up);
-        let down_ema = self.down_ema_indicator.next(down);
-        100.0 * up_ema / (up_ema + down_ema)
+        let up_avg = self.up_indicator.update_last(up);
+        let down_avg = self.down_indicator.update_last(down);
+        Self::rsi(up_avg, down_avg)
     }
 }
 
-impl<T: Close> Next<&T> for RelativeStrengthIndex {
-    type Output = f64;
+impl_for_floats! {
+    impl<T: Close<N>> Next<&T> for RelativeStrengthIndex<N> {
+        type Output = N;
+
+        fn next(&mut self, input: &T) -> Self::Output {
+            self.next(input.close())
+        }
+    }
+
+    impl_next_batch!(RelativeStrengthIndex<N>, Close => N);
+
+    impl<T: Close<N>> Peek<&T> for RelativeStrengthIndex<N> {
+        fn peek(&self, input: &T) -> Self::Output {
+            self.peek(input.close())
+        }
 
-    fn next(&mut self, input: &T) -> Self::Output {
-        self.next(input.close())
+        fn update_last(&mut self, input: &T) -> Self::Output {
+            self.update_last(input.close())
+        }
     }
 }
 
-impl Reset for RelativeStrengthIndex {
+impl<N: Num> Reset for RelativeStrengthIndex<N> {
     fn reset(&mut self) {
         self.is_new = true;
-        self.prev_val = 0.0;
-        self.up_ema_indicator.reset();
-        self.down_ema_indicator.reset();
+        self.prev_val = N::zero();
+        self.prev_prev_val = N::zero();
+        self.count = 0;
+        self.up_indicator.reset();
+        self.down_indicator.reset();
     }
 }
 
-impl Default for RelativeStrengthIndex {
+impl<N: Num> Default for RelativeStrengthIndex<N> {
     fn default() -> Self {
         Self::new(14).unwrap()
     }
 }
 
-impl fmt::Display for RelativeStrengthIndex {
+impl<N: Num> fmt::Display for RelativeStrengthIndex<N> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "RSI({})", self.period)
+        match self.smoothing() {
+            MovingAverageType::Exponential => write!(f, "RSI({})", self.period),
+            smoothing => write!(f, "RSI({}, {})", self.period, smoothing),
+        }
     }
 }
 
+impl<N: Num> FromParams for RelativeStrengthIndex<N> {
+    const NAME: &'static str = "RSI";
+
+    fn from_params(params: &[Param]) -> Result<Self> {
+        match *params {
+            [period] => Self::new(period.period()?),
+            [period, smoothing] => {
+                Self::with_smoothing(period.period()?, smoothing.moving_average()?)
+            }
+            _ => Err(TaError::InvalidParameter),
+        }
+    }
+}
+
+impl_from_str!(RelativeStrengthIndex);
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::indicators::RelativeStrengthIndex;
     use crate::test_helper::*;
 
     test_indicator!(RelativeStrengthIndex);
@@ -175,6 +312,35 @@ mod tests {
         assert_eq!(rsi.next(9.5).round(), 16.0);
     }
 
+    #[test]
+    fn test_next_wilder() {
+        let mut rsi = RelativeStrengthIndex::with_smoothing(3, MovingAverageType::Wilder).unwrap();
+        assert_eq!(rsi.smoothing(), MovingAverageType::Wilder);
+        assert_eq!(rsi.next(10.0), 50.0);
+        assert_eq!(rsi.next(10.5), 100.0);
+        assert_eq!(rsi.next(10.0), 50.0);
+        assert_eq!(round(rsi.next(9.5)), 33.333);
+        assert!(rsi.is_ready());
+        assert_eq!(round(rsi.next(11.0)), 73.333);
+    }
+
+    #[test]
+    fn test_next_simple() {
+        let mut rsi = RelativeStrengthIndex::with_smoothing(2, MovingAverageType::Simple).unwrap();
+        assert_eq!(rsi.next(10.0), 50.0);
+        assert_eq!(rsi.next(11.0), 100.0);
+        assert_eq!(rsi.next(10.0), 50.0);
+        assert_eq!(rsi.next(9.0), 0.0);
+    }
+
+    #[test]
+    fn test_next_flat() {
+        let mut rsi = RelativeStrengthIndex::with_smoothing(3, MovingAverageType::Wilder).unwrap();
+        for _ in 0..5 {
+            assert_eq!(rsi.next(10.0), 50.0);
+        }
+    }
+
     #[test]
     fn test_reset() {
         let mut rsi = RelativeStrengthIndex::new(3).unwrap();
@@ -195,5 +361,7 @@ mod tests {
     fn test_display() {
         let rsi = RelativeStrengthIndex::new(16).unwrap();
         assert_eq!(format!("{}", rsi), "RSI(16)");
+        let rsi = RelativeStrengthIndex::with_smoothing(16, MovingAverageType::Wilder).unwrap();
+        assert_eq!(format!("{}", rsi), "RSI(16, RMA)");
     }
 }
@@ -0,0 +1,271 @@
+use core::fmt;
+
+use crate::errors::{Result, TaError};
+use crate::indicators::volume_weighted_window::VolumeWeightedWindow;
+use crate::registry::{FromParams, Param};
+use crate::{Close, High, Lookback, Low, Next, Num, Peek, Period, Reset, Volume};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Rolling Volume Weighted Average Price (VWAP).
+///
+/// Unlike the cumulative [VWAP](struct.VolumeWeightedAveragePrice.html), which accumulates
+/// every bar since the start (or the last reset), the rolling VWAP only takes into account
+/// the last _period_ bars.
+///
+/// # Formula
+///
+/// VWAP = Σ(_tp_ × _volume_) / Σ(_volume_)
+///
+/// Where the sums are taken over the last _period_ bars and _tp_ is the typical price of a
+/// bar, (high + low + close) / 3. When the total volume of the window is 0, the typical price
+/// of the last bar is returned.
+///
+/// # Parameters
+///
+/// * _period_ - number of periods (integer greater than 0). Default is 20.
+///
+/// # Example
+///
+/// ```
+/// use ta::indicators::RollingVolumeWeightedAveragePrice;
+/// use ta::{DataItem, Next};
+///
+/// let item = |close: f64, volume: f64| {
+///     DataItem::builder()
+///         .open(close)
+///         .high(close)
+///         .low(close)
+///         .close(close)
+///         .volume(volume)
+///         .build()
+///         .unwrap()
+/// };
+///
+/// let mut vwap = RollingVolumeWeightedAveragePrice::new(2).unwrap();
+/// assert_eq!(vwap.next(&item(10.0, 100.0)), 10.0);
+/// assert_eq!(vwap.next(&item(20.0, 300.0)), 17.5);
+/// assert_eq!(vwap.next(&item(30.0, 100.0)), 22.5);
+/// ```
+///
+/// # Links
+///
+/// * [Volume-weighted average price, Wikipedia](https://en.wikipedia.org/wiki/Volume-weighted_average_price)
+///
+#[doc(alias = "VWAP")]
+#[doc(alias = "Rolling VWAP")]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone)]
+pub struct RollingVolumeWeightedAveragePrice<N = f64> {
+    window: VolumeWeightedWindow<N>,
+}
+
+impl<N: Num> RollingVolumeWeightedAveragePrice<N> {
+    pub fn new(period: usize) -> Result<Self> {
+        Ok(Self {
+            window: VolumeWeightedWindow::new(period)?,
+        })
+    }
+
+    fn typical_price<T: High<N> + Low<N> + Close<N>>(input: &T) -> N {
+        (input.high() + input.low() + input.close()) / N::from_f64(3.0)
+    }
+}
+
+impl<N: Num> Period for RollingVolumeWeightedAveragePrice<N> {
+    fn period(&self) -> usize {
+        self.window.period()
+    }
+}
+
+impl<N: Num> Lookback for RollingVolumeWeightedAveragePrice<N> {
+    fn lookback(&self) -> usize {
+        self.window.period()
+    }
+
+    fn is_ready(&self) -> bool {
+        self.window.count() == self.window.period()
+    }
+}
+
+impl<N: Num, T: High<N> + Low<N> + Close<N> + Volume<N>> Next<&T>
+    for RollingVolumeWeightedAveragePrice<N>
+{
+    type Output = N;
+
+    fn next(&mut self, input: &T) -> Self::Output {
+        let price = Self::typical_price(input);
+        self.window.next(price, input.volume()).unwrap_or(price)
+    }
+}
+
+impl_next_batch!(RollingVolumeWeightedAveragePrice, High + Low + Close + Volume => N);
+
+impl<N: Num, T: High<N> + Low<N> + Close<N> + Volume<N>> Peek<&T>
+    for RollingVolumeWeightedAveragePrice<N>
+{
+    fn peek(&self, input: &T) -> Self::Output {
+        let price = Self::typical_price(input);
+        self.window.peek(price, input.volume()).unwrap_or(price)
+    }
+
+    fn update_last(&mut self, input: &T) -> Self::Output {
+        if self.window.count() == 0 {
+            return self.next(input);
+        }
+
+        let price = Self::typical_price(input);
+        self.window
+            .update_last(price, input.volume())
+            .unwrap_or(price)
+    }
+}
+
+impl<N: Num> Reset for RollingVolumeWeightedAveragePrice<N> {
+    fn reset(&mut self) {
+        self.window.reset();
+    }
+}
+
+impl<N: Num> Default for RollingVolumeWeightedAveragePrice<N> {
+    fn default() -> Self {
+        Self::new(20).unwrap()
+    }
+}
+
+impl<N: Num> fmt::Display for RollingVolumeWeightedAveragePrice<N> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "VWAP({})", self.period())
+    }
+}
+
+impl<N: Num> FromParams for RollingVolumeWeightedAveragePrice<N> {
+    const NAME: &'static str = "VWAP";
+
+    fn from_params(params: &[Param]) -> Result<Self> {
+        match *params {
+            [period] => Self::new(period.period()?),
+            _ => Err(TaError::InvalidParameter),
+        }
+    }
+}
+
+impl_from_str!(RollingVolumeWeightedAveragePrice);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::indicators::RollingVolumeWeightedAveragePrice;
+    use crate::indicators::VolumeWeightedAveragePrice;
+    use crate::test_helper::*;
+
+    fn bar(close: f64, volume: f64) -> Bar {
+        Bar::new()
+            .high(close)
+            .low(close)
+            .close(close)
+            .volume(volume)
+    }
+
+    #[test]
+    fn test_new() {
+        assert!(RollingVolumeWeightedAveragePrice::new(0).is_err());
+        assert!(RollingVolumeWeightedAveragePrice::new(1).is_ok());
+    }
+
+    #[test]
+    fn test_next() {
+        let mut vwap = RollingVolumeWeightedAveragePrice::new(3).unwrap();
+
+        assert_eq!(vwap.next(&bar(4.0, 100.0)), 4.0);
+        assert_eq!(vwap.next(&bar(8.0, 300.0)), 7.0);
+        assert_eq!(vwap.next(&bar(2.0, 400.0)), 4.5);
+        assert_eq!(vwap.next(&bar(6.0, 100.0)), 4.75);
+        assert_eq!(vwap.next(&bar(3.0, 0.0)), 2.8);
+        assert_eq!(vwap.next(&bar(5.0, 0.0)), 6.0);
+        assert_eq!(vwap.next(&bar(7.0, 0.0)), 7.0);
+    }
+
+    #[test]
+    fn test_next_typical_price() {
+        let mut vwap = RollingVolumeWeightedAveragePrice::new(2).unwrap();
+
+        assert_eq!(
+            vwap.next(&Bar::new().high(12).low(9).close(9).volume(100.0)),
+            10.0
+        );
+        assert_eq!(
+            vwap.next(&Bar::new().high(14).low(12).close(13).volume(300.0)),
+            12.25
+        );
+        assert_eq!(
+            vwap.next(&Bar::new().high(9).low(6).close(6).volume(100.0)),
+            11.5
+        );
+    }
+
+    #[test]
+    fn test_next_same_as_vwap() {
+        let mut rolling = RollingVolumeWeightedAveragePrice::new(1000).unwrap();
+        let mut vwap = VolumeWeightedAveragePrice::new();
+
+        for bar in peek_bars().iter() {
+            assert_eq!(round(rolling.next(bar)), round(vwap.next(bar)));
+        }
+    }
+
+    #[test]
+    fn test_next_no_volume_after_rounding_errors() {
+        let mut vwap = RollingVolumeWeightedAveragePrice::new(2).unwrap();
+
+        vwap.next(&bar(10.0, 0.1));
+        vwap.next(&bar(20.0, 0.2));
+        vwap.next(&bar(30.0, 0.0));
+        // the running sum of the volume isn't exactly 0 here
+        assert_eq!(vwap.next(&bar(40.0, 0.0)), 40.0);
+        assert_eq!(vwap.peek(&bar(50.0, 0.0)), 50.0);
+        assert_eq!(vwap.update_last(&bar(45.0, 0.0)), 45.0);
+    }
+
+    #[test]
+    fn test_peek() {
+        let bars = peek_bars();
+        let bars: Vec<&Bar> = bars.iter().collect();
+        assert_peek(RollingVolumeWeightedAveragePrice::new(3).unwrap(), &bars);
+        assert_peek(RollingVolumeWeightedAveragePrice::new(1).unwrap(), &bars);
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut vwap = RollingVolumeWeightedAveragePrice::new(3).unwrap();
+
+        assert_eq!(vwap.next(&bar(4.0, 100.0)), 4.0);
+        assert_eq!(vwap.next(&bar(8.0, 300.0)), 7.0);
+
+        vwap.reset();
+        assert_eq!(vwap.next(&bar(2.0, 400.0)), 2.0);
+        assert_eq!(vwap.next(&bar(6.0, 100.0)), 2.8);
+    }
+
+    #[test]
+    fn test_lookback() {
+        let mut vwap = RollingVolumeWeightedAveragePrice::new(2).unwrap();
+        assert_eq!(vwap.lookback(), 2);
+
+        vwap.next(&bar(4.0, 100.0));
+        assert!(!vwap.is_ready());
+        vwap.next(&bar(4.0, 100.0));
+        assert!(vwap.is_ready());
+    }
+
+    #[test]
+    fn test_default() {
+        RollingVolumeWeightedAveragePrice::default();
+    }
+
+    #[test]
+    fn test_display() {
+        let vwap = RollingVolumeWeightedAveragePrice::new(14).unwrap();
+        assert_eq!(format!("{}", vwap), "VWAP(14)");
+    }
+}
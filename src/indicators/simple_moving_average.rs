@@ -1,7 +1,11 @@
-use std::fmt;
+use alloc::boxed::Box;
+use alloc::vec;
+use alloc::vec::Vec;
+use core::fmt;
 
 use crate::errors::{Result, TaError};
-use crate::{Close, Next, Period, Reset};
+use crate::registry::{FromParams, Param};
+use crate::{Close, Lookback, Next, NextBatch, Num, Peek, Period, Reset};
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
@@ -41,15 +45,15 @@ use serde::{Deserialize, Serialize};
 #[doc(alias = "SMA")]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[derive(Debug, Clone)]
-pub struct SimpleMovingAverage {
+pub struct SimpleMovingAverage<N = f64> {
     period: usize,
     index: usize,
     count: usize,
-    sum: f64,
-    deque: Box<[f64]>,
+    sum: N,
+    deque: Box<[N]>,
 }
 
-impl SimpleMovingAverage {
+impl<N: Num> SimpleMovingAverage<N> {
     pub fn new(period: usize) -> Result<Self> {
         match period {
             0 => Err(TaError::InvalidParameter),
@@ -57,23 +61,60 @@ impl SimpleMovingAverage {
                 period,
                 index: 0,
                 count: 0,
-                sum: 0.0,
-                deque: vec![0.0; period].into_boxed_slice(),
+                sum: N::zero(),
+                deque: vec![N::zero(); period].into_boxed_slice(),
             }),
         }
     }
+
+    fn next_batch_iter<I: Iterator<Item = N>>(&mut self, mut input: I, output: &mut [N]) {
+        let mut output = output.iter_mut();
+
+        // Fill the window first, so the loop below doesn't need to check `count`.
+        while self.count < self.period {
+            match (input.next(), output.next()) {
+                (Some(item), Some(out)) => *out = self.next(item),
+                _ => return,
+            }
+        }
+
+        let period = N::from_usize(self.period);
+        for (item, out) in input.zip(output) {
+            let old_val = self.deque[self.index];
+            self.deque[self.index] = item;
+
+            self.index = if self.index + 1 < self.period {
+                self.index + 1
+            } else {
+                0
+            };
+
+            self.sum = self.sum - old_val + item;
+            *out = self.sum / period;
+        }
+    }
 }
 
-impl Period for SimpleMovingAverage {
+impl<N: Num> Period for SimpleMovingAverage<N> {
     fn period(&self) -> usize {
         self.period
     }
 }
 
-impl Next<f64> for SimpleMovingAverage {
-    type Output = f64;
+impl<N: Num> Lookback for SimpleMovingAverage<N> {
+    fn lookback(&self) -> usize {
+        self.period
+    }
+
+    fn is_ready(&self) -> bool {
+        self.count == self.period
+    }
+}
+
+impl<N: Num> Next<N> for SimpleMovingAverage<N> {
+    type Output = N;
 
-    fn next(&mut self, input: f64) -> Self::Output {
+    fn next(&mut self, input: N) -> Self::Output {
         let old_val = self.deque[self.index];
         self.deque[self.index] = input;
 
@@ -88,44 +129,122 @@ impl Next<f64> for SimpleMovingAverage {
         }
 
         self.sum = self.sum - old_val + input;
-        self.sum / (self.count as f64)
+        self.sum / N::from_usize(self.count)
+    }
+}
+
+impl<N: Num> NextBatch<N> for SimpleMovingAverage<N> {
+    type Output = N;
+
+    fn next_batch(&mut self, input: &[N]) -> Vec<Self::Output> {
+        let mut output = vec![N::zero(); input.len()];
+        self.next_batch_iter(input.iter().copied(), &mut output);
+        output
+    }
+
+    fn next_batch_into(&mut self, input: &[N], output: &mut [Self::Output]) {
+        assert_eq!(input.len(), output.len());
+        self.next_batch_iter(input.iter().copied(), output);
+    }
+}
+
+impl<N: Num> Peek<N> for SimpleMovingAverage<N> {
+    fn peek(&self, input: N) -> Self::Output {
+        let count = (self.count + 1).min(self.period);
+        (self.sum - self.deque[self.index] + input) / N::from_usize(count)
+    }
+
+    fn update_last(&mut self, input: N) -> Self::Output {
+        if self.count == 0 {
+            return self.next(input);
+        }
+
+        let last = if self.index == 0 {
+            self.period - 1
+        } else {
+            self.index - 1
+        };
+        self.sum = self.sum - self.deque[last] + input;
+        self.deque[last] = input;
+        self.sum / N::from_usize(self.count)
     }
 }
 
-impl<T: Close> Next<&T> for SimpleMovingAverage {
-    type Output = f64;
+impl_for_floats! {
+    impl<T: Close<N>> Next<&T> for SimpleMovingAverage<N> {
+        type Output = N;
+
+        fn next(&mut self, input: &T) -> Self::Output {
+            self.next(input.close())
+        }
+    }
+
+    impl<T: Close<N>> NextBatch<T> for SimpleMovingAverage<N> {
+        type Output = N;
+
+        fn next_batch(&mut self, input: &[T]) -> Vec<Self::Output> {
+            let mut output = vec![N::zero(); input.len()];
+            self.next_batch_iter(input.iter().map(Close::close), &mut output);
+            output
+        }
+
+        fn next_batch_into(&mut self, input: &[T], output: &mut [Self::Output]) {
+            assert_eq!(input.len(), output.len());
+            self.next_batch_iter(input.iter().map(Close::close), output);
+        }
+    }
 
-    fn next(&mut self, input: &T) -> Self::Output {
-        self.next(input.close())
+    impl<T: Close<N>> Peek<&T> for SimpleMovingAverage<N> {
+        fn peek(&self, input: &T) -> Self::Output {
+            self.peek(input.close())
+        }
+
+        fn update_last(&mut self, input: &T) -> Self::Output {
+            self.update_last(input.close())
+        }
     }
 }
 
-impl Reset for SimpleMovingAverage {
+impl<N: Num> Reset for SimpleMovingAverage<N> {
     fn reset(&mut self) {
         self.index = 0;
         self.count = 0;
-        self.sum = 0.0;
+        self.sum = N::zero();
         for i in 0..self.period {
-            self.deque[i] = 0.0;
+            self.deque[i] = N::zero();
         }
     }
 }
 
-impl Default for SimpleMovingAverage {
+impl<N: Num> Default for SimpleMovingAverage<N> {
     fn default() -> Self {
         Self::new(9).unwrap()
     }
 }
 
-impl fmt::Display for SimpleMovingAverage {
+impl<N: Num> fmt::Display for SimpleMovingAverage<N> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         write!(f, "SMA({})", self.period)
     }
 }
 
+impl<N: Num> FromParams for SimpleMovingAverage<N> {
+    const NAME: &'static str = "SMA";
+
+    fn from_params(params: &[Param]) -> Result<Self> {
+        match *params {
+            [period] => Self::new(period.period()?),
+            _ => Err(TaError::InvalidParameter),
+        }
+    }
+}
+
+impl_from_str!(SimpleMovingAverage);
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::indicators::SimpleMovingAverage;
     use crate::test_helper::*;
 
     test_indicator!(SimpleMovingAverage);
@@ -161,6 +280,24 @@ mod tests {
         assert_eq!(sma.next(&bar(1.0)), 4.0);
     }
 
+    #[test]
+    fn test_next_batch() {
+        let input = [4.0, 5.0, 6.0, 6.0, 6.0, 6.0, 2.0, 1.5, 8.25];
+
+        let mut sma = SimpleMovingAverage::new(4).unwrap();
+        let expected: Vec<f64> = input.iter().map(|&x| sma.next(x)).collect();
+
+        let mut sma = SimpleMovingAverage::new(4).unwrap();
+        assert_eq!(sma.next_batch(&input[..2]), expected[..2]);
+        assert_eq!(sma.next_batch(&input[2..]), expected[2..]);
+
+        let bars: Vec<Bar> = input.iter().map(|&x| Bar::new().close(x)).collect();
+        let mut output = vec![0.0; bars.len()];
+        let mut sma = SimpleMovingAverage::new(4).unwrap();
+        sma.next_batch_into(&bars, &mut output);
+        assert_eq!(output, expected);
+    }
+
     #[test]
     fn test_reset() {
         let mut sma = SimpleMovingAverage::new(4).unwrap();
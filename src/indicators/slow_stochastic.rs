@@ -1,19 +1,25 @@
-use std::fmt;
+use core::fmt;
 
-use crate::errors::Result;
-use crate::indicators::{ExponentialMovingAverage, FastStochastic};
-use crate::{Close, High, Low, Next, Period, Reset};
+use crate::errors::{Result, TaError};
+use crate::indicators::generic::{FastStochastic, MovingAverage, MovingAverageType};
+use crate::registry::{FromParams, Param};
+use crate::{Close, High, Lookback, Low, Next, Num, Peek, Period, Reset};
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
 /// Slow stochastic oscillator.
 ///
 /// Basically it is a fast stochastic oscillator smoothed with exponential moving average.
+/// Use [`with_moving_average`](#method.with_moving_average) to smooth it with another
+/// [moving average type](enum.MovingAverageType.html). For both %K and %D lines see
+/// [Full Stochastic](struct.FullStochastic.html).
 ///
 /// # Parameters
 ///
 /// * _stochastic_period_ - number of periods for fast stochastic (integer greater than 0). Default is 14.
 /// * _ema_period_ - period for EMA (integer greater than 0). Default is 3.
+/// * _moving_average_ - type of the moving average. Default is
+///   [`MovingAverageType::Exponential`](enum.MovingAverageType.html).
 ///
 /// # Example
 ///
@@ -30,63 +36,160 @@ use serde::{Deserialize, Serialize};
 /// ```
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[derive(Clone, Debug)]
-pub struct SlowStochastic {
-    fast_stochastic: FastStochastic,
-    ema: ExponentialMovingAverage,
+pub struct SlowStochastic<N = f64> {
+    fast_stochastic: FastStochastic<N>,
+    ma: MovingAverage<N>,
+    count: usize,
 }
 
-impl SlowStochastic {
+impl<N: Num> SlowStochastic<N> {
     pub fn new(stochastic_period: usize, ema_period: usize) -> Result<Self> {
+        Self::with_moving_average(
+            stochastic_period,
+            ema_period,
+            MovingAverageType::Exponential,
+        )
+    }
+
+    pub fn with_moving_average(
+        stochastic_period: usize,
+        ma_period: usize,
+        moving_average: MovingAverageType,
+    ) -> Result<Self> {
         Ok(Self {
             fast_stochastic: FastStochastic::new(stochastic_period)?,
-            ema: ExponentialMovingAverage::new(ema_period)?,
+            ma: MovingAverage::new(moving_average, ma_period)?,
+            count: 0,
         })
     }
+
+    pub fn moving_average(&self) -> MovingAverageType {
+        self.ma.ma_type()
+    }
 }
 
-impl Next<f64> for SlowStochastic {
-    type Output = f64;
+impl<N: Num> Lookback for SlowStochastic<N> {
+    fn lookback(&self) -> usize {
+        self.fast_stochastic.period() + self.ma.lookback() - 1
+    }
 
-    fn next(&mut self, input: f64) -> Self::Output {
-        self.ema.next(self.fast_stochastic.next(input))
+    fn is_ready(&self) -> bool {
+        self.count == self.lookback()
     }
 }
 
-impl<T: High + Low + Close> Next<&T> for SlowStochastic {
-    type Output = f64;
+impl<N: Num> Next<N> for SlowStochastic<N> {
+    type Output = N;
+
+    fn next(&mut self, input: N) -> Self::Output {
+        if self.count < self.lookback() {
+            self.count += 1;
+        }
+        self.ma.next(self.fast_stochastic.next(input))
+    }
+}
+
+impl_next_batch!(SlowStochastic, N => N);
+
+impl<N: Num> Peek<N> for SlowStochastic<N> {
+    fn peek(&self, input: N) -> Self::Output {
+        self.ma.peek(self.fast_stochastic.peek(input))
+    }
+
+    fn update_last(&mut self, input: N) -> Self::Output {
+        if self.count == 0 {
+            return self.next(input);
+        }
+        self.ma.update_last(self.fast_stochastic.update_last(input))
+    }
+}
+
+impl_for_floats! {
+    impl<T: High<N> + Low<N> + Close<N>> Next<&T> for SlowStochastic<N> {
+        type Output = N;
+
+        fn next(&mut self, input: &T) -> Self::Output {
+            if self.count < self.lookback() {
+                self.count += 1;
+            }
+            self.ma.next(self.fast_stochastic.next(input))
+        }
+    }
+
+    impl_next_batch!(SlowStochastic<N>, High + Low + Close => N);
+
+    impl<T: High<N> + Low<N> + Close<N>> Peek<&T> for SlowStochastic<N> {
+        fn peek(&self, input: &T) -> Self::Output {
+            self.ma.peek(self.fast_stochastic.peek(input))
+        }
 
-    fn next(&mut self, input: &T) -> Self::Output {
-        self.ema.next(self.fast_stochastic.next(input))
+        fn update_last(&mut self, input: &T) -> Self::Output {
+            if self.count == 0 {
+                return self.next(input);
+            }
+            self.ma.update_last(self.fast_stochastic.update_last(input))
+        }
     }
 }
 
-impl Reset for SlowStochastic {
+impl<N: Num> Reset for SlowStochastic<N> {
     fn reset(&mut self) {
         self.fast_stochastic.reset();
-        self.ema.reset();
+        self.ma.reset();
+        self.count = 0;
     }
 }
 
-impl Default for SlowStochastic {
+impl<N: Num> Default for SlowStochastic<N> {
     fn default() -> Self {
         Self::new(14, 3).unwrap()
     }
 }
 
-impl fmt::Display for SlowStochastic {
+impl<N: Num> fmt::Display for SlowStochastic<N> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(
-            f,
-            "SLOW_STOCH({}, {})",
-            self.fast_stochastic.period(),
-            self.ema.period()
-        )
+        match self.moving_average() {
+            MovingAverageType::Exponential => write!(
+                f,
+                "SLOW_STOCH({}, {})",
+                self.fast_stochastic.period(),
+                self.ma.period()
+            ),
+            moving_average => write!(
+                f,
+                "SLOW_STOCH({}, {}, {})",
+                self.fast_stochastic.period(),
+                self.ma.period(),
+                moving_average
+            ),
+        }
     }
 }
 
+impl<N: Num> FromParams for SlowStochastic<N> {
+    const NAME: &'static str = "SLOW_STOCH";
+
+    fn from_params(params: &[Param]) -> Result<Self> {
+        match *params {
+            [stochastic_period, ma_period] => {
+                Self::new(stochastic_period.period()?, ma_period.period()?)
+            }
+            [stochastic_period, ma_period, moving_average] => Self::with_moving_average(
+                stochastic_period.period()?,
+                ma_period.period()?,
+                moving_average.moving_average()?,
+            ),
+            _ => Err(TaError::InvalidParameter),
+        }
+    }
+}
+
+impl_from_str!(SlowStochastic);
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::indicators::SlowStochastic;
     use crate::test_helper::*;
 
     test_indicator!(SlowStochastic);
@@ -108,6 +211,18 @@ mod tests {
         assert_eq!(stoch.next(55.0).round(), 77.0);
     }
 
+    #[test]
+    fn test_next_sma() {
+        let mut stoch =
+            SlowStochastic::with_moving_average(3, 2, MovingAverageType::Simple).unwrap();
+        assert_eq!(stoch.moving_average(), MovingAverageType::Simple);
+
+        assert_eq!(stoch.next(10.0), 50.0);
+        assert_eq!(stoch.next(50.0), 75.0);
+        assert_eq!(stoch.next(50.0), 100.0);
+        assert_eq!(stoch.next(30.0), 50.0);
+    }
+
     #[test]
     fn test_next_with_bars() {
         let test_data = vec![
@@ -148,5 +263,8 @@ mod tests {
     fn test_display() {
         let indicator = SlowStochastic::new(10, 2).unwrap();
         assert_eq!(format!("{}", indicator), "SLOW_STOCH(10, 2)");
+        let indicator =
+            SlowStochastic::with_moving_average(10, 2, MovingAverageType::Weighted).unwrap();
+        assert_eq!(format!("{}", indicator), "SLOW_STOCH(10, 2, WMA)");
     }
 }
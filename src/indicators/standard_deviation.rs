@@ -1,7 +1,11 @@
-use std::fmt;
+use alloc::boxed::Box;
+use alloc::vec;
+use alloc::vec::Vec;
+use core::fmt;
 
 use crate::errors::{Result, TaError};
-use crate::{Close, Next, Period, Reset};
+use crate::registry::{FromParams, Param};
+use crate::{Close, Lookback, Next, NextBatch, Num, Peek, Period, Reset};
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
@@ -41,16 +45,16 @@ use serde::{Deserialize, Serialize};
 #[doc(alias = "SD")]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[derive(Debug, Clone)]
-pub struct StandardDeviation {
+pub struct StandardDeviation<N = f64> {
     period: usize,
     index: usize,
     count: usize,
-    m: f64,
-    m2: f64,
-    deque: Box<[f64]>,
+    m: N,
+    m2: N,
+    deque: Box<[N]>,
 }
 
-impl StandardDeviation {
+impl<N: Num> StandardDeviation<N> {
     pub fn new(period: usize) -> Result<Self> {
         match period {
             0 => Err(TaError::InvalidParameter),
@@ -58,28 +62,95 @@ impl StandardDeviation {
                 period,
                 index: 0,
                 count: 0,
-                m: 0.0,
-                m2: 0.0,
-                deque: vec![0.0; period].into_boxed_slice(),
+                m: N::zero(),
+                m2: N::zero(),
+                deque: vec![N::zero(); period].into_boxed_slice(),
             }),
         }
     }
 
-    pub(super) fn mean(&self) -> f64 {
+    pub(super) fn mean(&self) -> N {
         self.m
     }
+
+    /// Mean `next(input)` would leave behind.
+    pub(super) fn peek_mean(&self, input: N) -> N {
+        self.peek_moments(input).0
+    }
+
+    // Returns the mean, the sum of squared differences and the count after `next(input)`.
+    fn peek_moments(&self, input: N) -> (N, N, usize) {
+        if self.count < self.period {
+            let count = self.count + 1;
+            let delta = input - self.m;
+            let m = self.m + delta / N::from_usize(count);
+            let m2 = self.m2 + delta * (input - m);
+            (m, m2.max(N::zero()), count)
+        } else {
+            let old_val = self.deque[self.index];
+            let delta = input - old_val;
+            let m = self.m + delta / N::from_usize(self.period);
+            let m2 = self.m2 + delta * (input - m + old_val - self.m);
+            (m, m2.max(N::zero()), self.count)
+        }
+    }
+
+    fn next_batch_iter<I: Iterator<Item = N>>(&mut self, mut input: I, output: &mut [N]) {
+        let mut output = output.iter_mut();
+
+        // Fill the window first, so the loop below doesn't need to check `count`.
+        while self.count < self.period {
+            match (input.next(), output.next()) {
+                (Some(item), Some(out)) => *out = self.next(item),
+                _ => return,
+            }
+        }
+
+        let period = N::from_usize(self.period);
+        for (item, out) in input.zip(output) {
+            let old_val = self.deque[self.index];
+            self.deque[self.index] = item;
+
+            self.index = if self.index + 1 < self.period {
+                self.index + 1
+            } else {
+                0
+            };
+
+            let delta = item - old_val;
+            let old_m = self.m;
+            self.m += delta / period;
+            let delta2 = item - self.m + old_val - old_m;
+            self.m2 += delta * delta2;
+            if self.m2 < N::zero() {
+                self.m2 = N::zero();
+            }
+
+            *out = (self.m2 / period).sqrt();
+        }
+    }
 }
 
-impl Period for StandardDeviation {
+impl<N: Num> Period for StandardDeviation<N> {
     fn period(&self) -> usize {
         self.period
     }
 }
 
-impl Next<f64> for StandardDeviation {
-    type Output = f64;
+impl<N: Num> Lookback for StandardDeviation<N> {
+    fn lookback(&self) -> usize {
+        self.period
+    }
 
-    fn next(&mut self, input: f64) -> Self::Output {
+    fn is_ready(&self) -> bool {
+        self.count == self.period
+    }
+}
+
+impl<N: Num> Next<N> for StandardDeviation<N> {
+    type Output = N;
+
+    fn next(&mut self, input: N) -> Self::Output {
         let old_val = self.deque[self.index];
         self.deque[self.index] = input;
 
@@ -92,59 +163,147 @@ impl Next<f64> for StandardDeviation {
         if self.count < self.period {
             self.count += 1;
             let delta = input - self.m;
-            self.m += delta / self.count as f64;
+            self.m += delta / N::from_usize(self.count);
             let delta2 = input - self.m;
             self.m2 += delta * delta2;
         } else {
             let delta = input - old_val;
             let old_m = self.m;
-            self.m += delta / self.period as f64;
+            self.m += delta / N::from_usize(self.period);
             let delta2 = input - self.m + old_val - old_m;
             self.m2 += delta * delta2;
         }
-        if self.m2 < 0.0 {
-            self.m2 = 0.0;
+        if self.m2 < N::zero() {
+            self.m2 = N::zero();
         }
 
-        (self.m2 / self.count as f64).sqrt()
+        (self.m2 / N::from_usize(self.count)).sqrt()
     }
 }
 
-impl<T: Close> Next<&T> for StandardDeviation {
-    type Output = f64;
+impl<N: Num> NextBatch<N> for StandardDeviation<N> {
+    type Output = N;
+
+    fn next_batch(&mut self, input: &[N]) -> Vec<Self::Output> {
+        let mut output = vec![N::zero(); input.len()];
+        self.next_batch_iter(input.iter().copied(), &mut output);
+        output
+    }
 
-    fn next(&mut self, input: &T) -> Self::Output {
-        self.next(input.close())
+    fn next_batch_into(&mut self, input: &[N], output: &mut [Self::Output]) {
+        assert_eq!(input.len(), output.len());
+        self.next_batch_iter(input.iter().copied(), output);
     }
 }
 
-impl Reset for StandardDeviation {
+impl<N: Num> Peek<N> for StandardDeviation<N> {
+    fn peek(&self, input: N) -> Self::Output {
+        let (_, m2, count) = self.peek_moments(input);
+        (m2 / N::from_usize(count)).sqrt()
+    }
+
+    fn update_last(&mut self, input: N) -> Self::Output {
+        if self.count == 0 {
+            return self.next(input);
+        }
+
+        let last = if self.index == 0 {
+            self.period - 1
+        } else {
+            self.index - 1
+        };
+        let old_val = self.deque[last];
+        self.deque[last] = input;
+
+        let delta = input - old_val;
+        let old_m = self.m;
+        self.m += delta / N::from_usize(self.count);
+        let delta2 = input - self.m + old_val - old_m;
+        self.m2 += delta * delta2;
+        if self.m2 < N::zero() {
+            self.m2 = N::zero();
+        }
+
+        (self.m2 / N::from_usize(self.count)).sqrt()
+    }
+}
+
+impl_for_floats! {
+    impl<T: Close<N>> Next<&T> for StandardDeviation<N> {
+        type Output = N;
+
+        fn next(&mut self, input: &T) -> Self::Output {
+            self.next(input.close())
+        }
+    }
+
+    impl<T: Close<N>> NextBatch<T> for StandardDeviation<N> {
+        type Output = N;
+
+        fn next_batch(&mut self, input: &[T]) -> Vec<Self::Output> {
+            let mut output = vec![N::zero(); input.len()];
+            self.next_batch_iter(input.iter().map(Close::close), &mut output);
+            output
+        }
+
+        fn next_batch_into(&mut self, input: &[T], output: &mut [Self::Output]) {
+            assert_eq!(input.len(), output.len());
+            self.next_batch_iter(input.iter().map(Close::close), output);
+        }
+    }
+
+    impl<T: Close<N>> Peek<&T> for StandardDeviation<N> {
+        fn peek(&self, input: &T) -> Self::Output {
+            self.peek(input.close())
+        }
+
+        fn update_last(&mut self, input: &T) -> Self::Output {
+            self.update_last(input.close())
+        }
+    }
+}
+
+impl<N: Num> Reset for StandardDeviation<N> {
     fn reset(&mut self) {
         self.index = 0;
         self.count = 0;
-        self.m = 0.0;
-        self.m2 = 0.0;
+        self.m = N::zero();
+        self.m2 = N::zero();
         for i in 0..self.period {
-            self.deque[i] = 0.0;
+            self.deque[i] = N::zero();
         }
     }
 }
 
-impl Default for StandardDeviation {
+impl<N: Num> Default for StandardDeviation<N> {
     fn default() -> Self {
         Self::new(9).unwrap()
     }
 }
 
-impl fmt::Display for StandardDeviation {
+impl<N: Num> fmt::Display for StandardDeviation<N> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         write!(f, "SD({})", self.period)
     }
 }
 
+impl<N: Num> FromParams for StandardDeviation<N> {
+    const NAME: &'static str = "SD";
+
+    fn from_params(params: &[Param]) -> Result<Self> {
+        match *params {
+            [period] => Self::new(period.period()?),
+            _ => Err(TaError::InvalidParameter),
+        }
+    }
+}
+
+impl_from_str!(StandardDeviation);
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::indicators::StandardDeviation;
     use crate::test_helper::*;
 
     test_indicator!(StandardDeviation);
@@ -202,6 +361,24 @@ mod tests {
         assert_eq!(sd.next(4.2), 0.0);
     }
 
+    #[test]
+    fn test_next_batch() {
+        let input = [10.0, 20.0, 30.0, 20.0, 10.0, 100.0, 1.872, 1.0, 1.0];
+
+        let mut sd = StandardDeviation::new(4).unwrap();
+        let expected: Vec<f64> = input.iter().map(|&x| sd.next(x)).collect();
+
+        let mut sd = StandardDeviation::new(4).unwrap();
+        assert_eq!(sd.next_batch(&input[..3]), expected[..3]);
+        assert_eq!(sd.next_batch(&input[3..]), expected[3..]);
+
+        let bars: Vec<Bar> = input.iter().map(|&x| Bar::new().close(x)).collect();
+        let mut output = vec![0.0; bars.len()];
+        let mut sd = StandardDeviation::new(4).unwrap();
+        sd.next_batch_into(&bars, &mut output);
+        assert_eq!(output, expected);
+    }
+
     #[test]
     fn test_reset() {
         let mut sd = StandardDeviation::new(4).unwrap();
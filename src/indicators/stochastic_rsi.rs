@@ -0,0 +1,365 @@
+use core::fmt;
+
+use crate::errors::{Result, TaError};
+use crate::indicators::generic::{
+    FastStochastic, MovingAverage, MovingAverageType, RelativeStrengthIndex,
+};
+use crate::registry::{FromParams, Param};
+use crate::{Close, Lookback, Next, Num, Peek, Period, Reset};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Stochastic RSI.
+///
+/// The [fast stochastic](struct.FastStochastic.html) applied to the values of the
+/// [RSI](struct.RelativeStrengthIndex.html) instead of the price. It shows where the RSI is
+/// relative to its own range, so it is a more sensitive, faster oscillator than the RSI itself.
+///
+/// # Formula
+///
+/// StochRSI = (RSI - min(RSI<sub>n</sub>)) / (max(RSI<sub>n</sub>) - min(RSI<sub>n</sub>)) × 100
+///
+/// %K = MA(StochRSI)
+///
+/// %D = MA(%K)
+///
+/// Where:
+///
+/// * _RSI<sub>n</sub>_ - RSI values for the last _stochastic_period_ periods
+/// * _MA_ - simple moving average, or another [moving average type](enum.MovingAverageType.html)
+///   passed to [`with_moving_average`](#method.with_moving_average)
+///
+/// # Parameters
+///
+/// * _rsi_period_ - number of periods of the RSI (integer greater than 0). Default is 14.
+/// * _stochastic_period_ - number of periods of the stochastic (integer greater than 0). Default is 14.
+/// * _k_period_ - number of periods to smooth %K (integer greater than 0). Default is 3.
+/// * _d_period_ - number of periods to smooth %D (integer greater than 0). Default is 3.
+///
+/// # Example
+///
+/// ```
+/// use ta::indicators::StochasticRsi;
+/// use ta::Next;
+///
+/// let mut stoch_rsi = StochasticRsi::new(1, 2, 2, 2).unwrap();
+///
+/// let output = stoch_rsi.next(10.0);
+/// assert_eq!((output.k, output.d), (50.0, 50.0));
+/// let output = stoch_rsi.next(11.0);
+/// assert_eq!((output.k, output.d), (75.0, 62.5));
+/// let output = stoch_rsi.next(10.0);
+/// assert_eq!((output.k, output.d), (50.0, 62.5));
+/// ```
+///
+/// # Links
+///
+/// * [Stochastic RSI, Investopedia](https://www.investopedia.com/terms/s/stochrsi.asp)
+///
+#[doc(alias = "StochRSI")]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone)]
+pub struct StochasticRsi<N = f64> {
+    rsi: RelativeStrengthIndex<N>,
+    stochastic: FastStochastic<N>,
+    k_ma: MovingAverage<N>,
+    d_ma: MovingAverage<N>,
+    count: usize,
+}
+
+impl<N: Num> StochasticRsi<N> {
+    pub fn new(
+        rsi_period: usize,
+        stochastic_period: usize,
+        k_period: usize,
+        d_period: usize,
+    ) -> Result<Self> {
+        Self::with_moving_average(
+            rsi_period,
+            stochastic_period,
+            k_period,
+            d_period,
+            MovingAverageType::Simple,
+        )
+    }
+
+    pub fn with_moving_average(
+        rsi_period: usize,
+        stochastic_period: usize,
+        k_period: usize,
+        d_period: usize,
+        moving_average: MovingAverageType,
+    ) -> Result<Self> {
+        Ok(Self {
+            rsi: RelativeStrengthIndex::new(rsi_period)?,
+            stochastic: FastStochastic::new(stochastic_period)?,
+            k_ma: MovingAverage::new(moving_average, k_period)?,
+            d_ma: MovingAverage::new(moving_average, d_period)?,
+            count: 0,
+        })
+    }
+
+    pub fn moving_average(&self) -> MovingAverageType {
+        self.k_ma.ma_type()
+    }
+}
+
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, PartialEq)]
+pub struct StochasticRsiOutput<N = f64> {
+    pub k: N,
+    pub d: N,
+}
+
+impl_fields!(StochasticRsiOutput { k, d });
+
+impl<N> From<StochasticRsiOutput<N>> for (N, N) {
+    fn from(output: StochasticRsiOutput<N>) -> Self {
+        (output.k, output.d)
+    }
+}
+
+impl<N: Num> Period for StochasticRsi<N> {
+    fn period(&self) -> usize {
+        self.rsi.period()
+    }
+}
+
+impl<N: Num> Lookback for StochasticRsi<N> {
+    fn lookback(&self) -> usize {
+        self.rsi.lookback()
+            + self.stochastic.lookback()
+            + self.k_ma.lookback()
+            + self.d_ma.lookback()
+            - 3
+    }
+
+    fn is_ready(&self) -> bool {
+        self.count == self.lookback()
+    }
+}
+
+impl<N: Num> Next<N> for StochasticRsi<N> {
+    type Output = StochasticRsiOutput<N>;
+
+    fn next(&mut self, input: N) -> Self::Output {
+        let k = self.k_ma.next(self.stochastic.next(self.rsi.next(input)));
+        let d = self.d_ma.next(k);
+        if self.count < self.lookback() {
+            self.count += 1;
+        }
+        StochasticRsiOutput { k, d }
+    }
+}
+
+impl_next_batch!(StochasticRsi, N => StochasticRsiOutput<N>);
+
+impl<N: Num> Peek<N> for StochasticRsi<N> {
+    fn peek(&self, input: N) -> Self::Output {
+        let k = self.k_ma.peek(self.stochastic.peek(self.rsi.peek(input)));
+        let d = self.d_ma.peek(k);
+        StochasticRsiOutput { k, d }
+    }
+
+    fn update_last(&mut self, input: N) -> Self::Output {
+        if self.count == 0 {
+            return self.next(input);
+        }
+
+        let rsi = self.rsi.update_last(input);
+        let k = self.k_ma.update_last(self.stochastic.update_last(rsi));
+        let d = self.d_ma.update_last(k);
+        StochasticRsiOutput { k, d }
+    }
+}
+
+impl_for_floats! {
+    impl<T: Close<N>> Next<&T> for StochasticRsi<N> {
+        type Output = StochasticRsiOutput<N>;
+
+        fn next(&mut self, input: &T) -> Self::Output {
+            self.next(input.close())
+        }
+    }
+
+    impl_next_batch!(StochasticRsi<N>, Close => StochasticRsiOutput<N>);
+
+    impl<T: Close<N>> Peek<&T> for StochasticRsi<N> {
+        fn peek(&self, input: &T) -> Self::Output {
+            self.peek(input.close())
+        }
+
+        fn update_last(&mut self, input: &T) -> Self::Output {
+            self.update_last(input.close())
+        }
+    }
+}
+
+impl<N: Num> Reset for StochasticRsi<N> {
+    fn reset(&mut self) {
+        self.rsi.reset();
+        self.stochastic.reset();
+        self.k_ma.reset();
+        self.d_ma.reset();
+        self.count = 0;
+    }
+}
+
+impl<N: Num> Default for StochasticRsi<N> {
+    fn default() -> Self {
+        Self::new(14, 14, 3, 3).unwrap()
+    }
+}
+
+impl<N: Num> fmt::Display for StochasticRsi<N> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self.moving_average() {
+            MovingAverageType::Simple => write!(
+                f,
+                "STOCH_RSI({}, {}, {}, {})",
+                self.rsi.period(),
+                self.stochastic.period(),
+                self.k_ma.period(),
+                self.d_ma.period()
+            ),
+            moving_average => write!(
+                f,
+                "STOCH_RSI({}, {}, {}, {}, {})",
+                self.rsi.period(),
+                self.stochastic.period(),
+                self.k_ma.period(),
+                self.d_ma.period(),
+                moving_average
+            ),
+        }
+    }
+}
+
+impl<N: Num> FromParams for StochasticRsi<N> {
+    const NAME: &'static str = "STOCH_RSI";
+
+    fn from_params(params: &[Param]) -> Result<Self> {
+        match *params {
+            [rsi_period, stochastic_period, k_period, d_period] => Self::new(
+                rsi_period.period()?,
+                stochastic_period.period()?,
+                k_period.period()?,
+                d_period.period()?,
+            ),
+            [rsi_period, stochastic_period, k_period, d_period, moving_average] => {
+                Self::with_moving_average(
+                    rsi_period.period()?,
+                    stochastic_period.period()?,
+                    k_period.period()?,
+                    d_period.period()?,
+                    moving_average.moving_average()?,
+                )
+            }
+            _ => Err(TaError::InvalidParameter),
+        }
+    }
+}
+
+impl_from_str!(StochasticRsi);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::indicators::StochasticRsi;
+    use crate::test_helper::*;
+
+    test_indicator!(StochasticRsi);
+
+    fn output(k: f64, d: f64) -> StochasticRsiOutput {
+        StochasticRsiOutput { k, d }
+    }
+
+    #[test]
+    fn test_new() {
+        assert!(StochasticRsi::new(0, 14, 3, 3).is_err());
+        assert!(StochasticRsi::new(14, 0, 3, 3).is_err());
+        assert!(StochasticRsi::new(14, 14, 0, 3).is_err());
+        assert!(StochasticRsi::new(14, 14, 3, 0).is_err());
+        assert!(StochasticRsi::new(1, 1, 1, 1).is_ok());
+    }
+
+    #[test]
+    fn test_next() {
+        let mut stoch_rsi = StochasticRsi::new(1, 2, 2, 2).unwrap();
+
+        // RSI(1) is 100 on the way up, 0 on the way down and 50 when flat
+        assert_eq!(stoch_rsi.next(10.0), output(50.0, 50.0));
+        assert_eq!(stoch_rsi.next(11.0), output(75.0, 62.5));
+        assert_eq!(stoch_rsi.next(10.0), output(50.0, 62.5));
+        assert_eq!(stoch_rsi.next(10.0), output(50.0, 50.0));
+        assert_eq!(stoch_rsi.next(12.0), output(100.0, 75.0));
+    }
+
+    #[test]
+    fn test_next_composition() {
+        let mut stoch_rsi = StochasticRsi::new(5, 4, 3, 2).unwrap();
+        let mut rsi = RelativeStrengthIndex::new(5).unwrap();
+        let mut stochastic = FastStochastic::new(4).unwrap();
+        let mut k_sma = MovingAverage::new(MovingAverageType::Simple, 3).unwrap();
+        let mut d_sma = MovingAverage::new(MovingAverageType::Simple, 2).unwrap();
+
+        for &input in peek_inputs().iter() {
+            let k = k_sma.next(stochastic.next(rsi.next(input)));
+            let d = d_sma.next(k);
+            assert_eq!(stoch_rsi.next(input), output(k, d));
+        }
+    }
+
+    #[test]
+    fn test_next_ema() {
+        let mut stoch_rsi =
+            StochasticRsi::with_moving_average(1, 2, 3, 3, MovingAverageType::Exponential).unwrap();
+        assert_eq!(stoch_rsi.moving_average(), MovingAverageType::Exponential);
+
+        assert_eq!(stoch_rsi.next(10.0), output(50.0, 50.0));
+        assert_eq!(stoch_rsi.next(11.0), output(75.0, 62.5));
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut stoch_rsi = StochasticRsi::new(1, 2, 2, 2).unwrap();
+
+        assert_eq!(stoch_rsi.next(10.0), output(50.0, 50.0));
+        assert_eq!(stoch_rsi.next(11.0), output(75.0, 62.5));
+
+        stoch_rsi.reset();
+
+        assert_eq!(stoch_rsi.next(10.0), output(50.0, 50.0));
+        assert_eq!(stoch_rsi.next(11.0), output(75.0, 62.5));
+    }
+
+    #[test]
+    fn test_lookback() {
+        let mut stoch_rsi = StochasticRsi::new(3, 4, 2, 3).unwrap();
+        assert_eq!(stoch_rsi.lookback(), 10);
+
+        for _ in 0..9 {
+            stoch_rsi.next(1.0);
+            assert!(!stoch_rsi.is_ready());
+        }
+        stoch_rsi.next(1.0);
+        assert!(stoch_rsi.is_ready());
+    }
+
+    #[test]
+    fn test_default() {
+        let stoch_rsi = StochasticRsi::default();
+        assert_eq!(stoch_rsi.moving_average(), MovingAverageType::Simple);
+    }
+
+    #[test]
+    fn test_display() {
+        let stoch_rsi = StochasticRsi::new(14, 14, 3, 3).unwrap();
+        assert_eq!(format!("{}", stoch_rsi), "STOCH_RSI(14, 14, 3, 3)");
+
+        let stoch_rsi =
+            StochasticRsi::with_moving_average(14, 14, 3, 3, MovingAverageType::Exponential)
+                .unwrap();
+        assert_eq!(format!("{}", stoch_rsi), "STOCH_RSI(14, 14, 3, 3, EMA)");
+    }
+}
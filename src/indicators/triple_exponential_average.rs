@@ -0,0 +1,304 @@
+use core::fmt;
+
+use crate::errors::{Result, TaError};
+use crate::indicators::generic::ExponentialMovingAverage;
+use crate::registry::{FromParams, Param};
+use crate::{Close, Lookback, Next, Num, Peek, Period, Reset};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Triple exponential average (TRIX).
+///
+/// An oscillator, which shows the percentage rate of change of a triple smoothed
+/// [exponential moving average](struct.ExponentialMovingAverage.html). For the triple
+/// exponential moving average overlay see [TEMA](struct.TripleExponentialMovingAverage.html).
+///
+/// For TRIX with a signal line see
+/// [TripleExponentialAverageWithSignal](struct.TripleExponentialAverageWithSignal.html).
+///
+/// # Formula
+///
+/// TRIX<sub>t</sub> = (EMA3<sub>t</sub> - EMA3<sub>t-1</sub>) / EMA3<sub>t-1</sub> × 100
+///
+/// Where:
+///
+/// * _EMA3_ - is the EMA of the EMA of the EMA of the input
+///
+/// The first input has no prior value, so TRIX starts at 0.
+///
+/// # Parameters
+///
+/// * _period_ - number of periods of each EMA (integer greater than 0). Default is 15.
+///
+/// # Example
+///
+/// ```
+/// use ta::indicators::TripleExponentialAverage;
+/// use ta::Next;
+///
+/// let mut trix = TripleExponentialAverage::new(3).unwrap();
+/// assert_eq!(trix.next(16.0), 0.0);
+/// assert_eq!(trix.next(17.0), 0.78125);
+/// ```
+///
+/// # Links
+///
+/// * [TRIX, Wikipedia](https://en.wikipedia.org/wiki/Trix_(technical_analysis))
+///
+#[doc(alias = "TRIX")]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone)]
+pub struct TripleExponentialAverage<N = f64> {
+    period: usize,
+    // k: f64,
+    current_em_3_value: N,
+    prev_em_3_value: N,
+    is_new: bool,
+    count: usize,
+    ema: ExponentialMovingAverage<N>,
+    ema2: ExponentialMovingAverage<N>,
+    ema3: ExponentialMovingAverage<N>,
+}
+
+impl<N: Num> TripleExponentialAverage<N> {
+    pub fn new(period: usize) -> Result<Self> {
+        match period {
+            0 => Err(TaError::InvalidParameter),
+            _ => Ok(Self {
+                period,
+                // k: 2.0 / (period + 1) as f64,
+                current_em_3_value: N::zero(),
+                prev_em_3_value: N::zero(),
+                is_new: true,
+                count: 0,
+                ema: ExponentialMovingAverage::new(period).unwrap(),
+                ema2: ExponentialMovingAverage::new(period).unwrap(),
+                ema3: ExponentialMovingAverage::new(period).unwrap(),
+            }),
+        }
+    }
+}
+
+impl<N: Num> Period for TripleExponentialAverage<N> {
+    fn period(&self) -> usize {
+        self.period
+    }
+}
+
+impl<N: Num> Lookback for TripleExponentialAverage<N> {
+    fn lookback(&self) -> usize {
+        3 * self.period - 1
+    }
+
+    fn is_ready(&self) -> bool {
+        self.count == self.lookback()
+    }
+}
+
+impl<N: Num> Next<N> for TripleExponentialAverage<N> {
+    type Output = N;
+
+    fn next(&mut self, input: N) -> Self::Output {
+        let ema_value = self.ema.next(input);
+        let ema_2_value = self.ema2.next(ema_value);
+        let ema_3_value = self.ema3.next(ema_2_value);
+
+        let mut trix = N::zero();
+
+        if self.is_new {
+            self.is_new = false;
+            self.current_em_3_value = ema_3_value;
+        } else {
+            trix = ((ema_3_value - self.current_em_3_value) / self.current_em_3_value)
+                * N::from_f64(100.0);
+            self.prev_em_3_value = self.current_em_3_value;
+            self.current_em_3_value = ema_3_value;
+        }
+
+        if self.count < self.lookback() {
+            self.count += 1;
+        }
+
+        trix
+    }
+}
+
+impl_next_batch!(TripleExponentialAverage, N => N);
+
+impl<N: Num> Peek<N> for TripleExponentialAverage<N> {
+    fn peek(&self, input: N) -> Self::Output {
+        if self.is_new {
+            return N::zero();
+        }
+
+        let ema_value = self.ema.peek(input);
+        let ema_2_value = self.ema2.peek(ema_value);
+        let ema_3_value = self.ema3.peek(ema_2_value);
+
+        ((ema_3_value - self.current_em_3_value) / self.current_em_3_value) * N::from_f64(100.0)
+    }
+
+    fn update_last(&mut self, input: N) -> Self::Output {
+        if self.is_new {
+            return self.next(input);
+        }
+
+        let ema_value = self.ema.update_last(input);
+        let ema_2_value = self.ema2.update_last(ema_value);
+        let ema_3_value = self.ema3.update_last(ema_2_value);
+        self.current_em_3_value = ema_3_value;
+
+        if self.count == 1 {
+            return N::zero();
+        }
+
+        ((ema_3_value - self.prev_em_3_value) / self.prev_em_3_value) * N::from_f64(100.0)
+    }
+}
+
+impl_for_floats! {
+    impl<T: Close<N>> Next<&T> for TripleExponentialAverage<N> {
+        type Output = N;
+
+        fn next(&mut self, input: &T) -> Self::Output {
+            self.next(input.close())
+        }
+    }
+
+    impl_next_batch!(TripleExponentialAverage<N>, Close => N);
+
+    impl<T: Close<N>> Peek<&T> for TripleExponentialAverage<N> {
+        fn peek(&self, input: &T) -> Self::Output {
+            self.peek(input.close())
+        }
+
+        fn update_last(&mut self, input: &T) -> Self::Output {
+            self.update_last(input.close())
+        }
+    }
+}
+
+impl<N: Num> Reset for TripleExponentialAverage<N> {
+    fn reset(&mut self) {
+        self.current_em_3_value = N::zero();
+        self.prev_em_3_value = N::zero();
+        self.is_new = true;
+        self.count = 0;
+
+        self.ema.reset();
+        self.ema2.reset();
+        self.ema3.reset();
+    }
+}
+
+impl<N: Num> Default for TripleExponentialAverage<N> {
+    fn default() -> Self {
+        Self::new(15).unwrap()
+    }
+}
+
+impl<N: Num> fmt::Display for TripleExponentialAverage<N> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "TRIX({})", self.period)
+    }
+}
+
+impl<N: Num> FromParams for TripleExponentialAverage<N> {
+    const NAME: &'static str = "TRIX";
+
+    fn from_params(params: &[Param]) -> Result<Self> {
+        match *params {
+            [period] => Self::new(period.period()?),
+            _ => Err(TaError::InvalidParameter),
+        }
+    }
+}
+
+impl_from_str!(TripleExponentialAverage);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::indicators::TripleExponentialAverage;
+    use crate::test_helper::*;
+
+    test_indicator!(TripleExponentialAverage);
+
+    #[test]
+    fn test_new() {
+        assert!(TripleExponentialAverage::new(0).is_err());
+        assert!(TripleExponentialAverage::new(1).is_ok());
+    }
+
+    #[test]
+    fn test_next() {
+        let mut trix = TripleExponentialAverage::new(3).unwrap();
+
+        assert_eq!(trix.next(16.0), 0.0);
+        assert_eq!(trix.next(17.0), 0.78125);
+        assert_eq!(trix.next(17.0), 1.1627906976744187);
+        assert_eq!(trix.next(10.0), -4.21455938697318);
+        assert_eq!(trix.next(17.0), -1.7999999999999998);
+
+        let mut trix = TripleExponentialAverage::new(3).unwrap();
+        let bar1 = Bar::new().close(2);
+        let bar2 = Bar::new().close(5);
+        assert_eq!(trix.next(&bar1), 0.0);
+        assert_eq!(trix.next(&bar2), 18.75);
+    }
+
+    #[test]
+    fn test_next_2() {
+        let mut trix = TripleExponentialAverage::new(15).unwrap();
+
+        trix.next(16.0);
+        trix.next(17.0);
+        trix.next(17.0);
+        trix.next(10.0);
+        trix.next(17.0);
+        trix.next(18.0);
+        trix.next(17.0);
+        trix.next(17.0);
+        let result = trix.next(17.0);
+
+        assert_eq!(result, 0.029258774080521098);
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut trix = TripleExponentialAverage::new(5).unwrap();
+
+        assert_eq!(trix.next(4.0), 0.0);
+        trix.next(10.0);
+        trix.next(15.0);
+        trix.next(20.0);
+        assert_ne!(trix.next(4.0), 4.0);
+
+        trix.reset();
+        assert_eq!(trix.next(4.0), 0.0);
+    }
+
+    #[test]
+    fn test_lookback() {
+        let mut trix = TripleExponentialAverage::new(3).unwrap();
+        assert_eq!(trix.lookback(), 8);
+
+        for _ in 0..7 {
+            trix.next(1.0);
+            assert!(!trix.is_ready());
+        }
+        trix.next(1.0);
+        assert!(trix.is_ready());
+    }
+
+    #[test]
+    fn test_default() {
+        TripleExponentialAverage::default();
+    }
+
+    #[test]
+    fn test_display() {
+        let trix = TripleExponentialAverage::new(7).unwrap();
+        assert_eq!(format!("{}", trix), "TRIX(7)");
+    }
+}
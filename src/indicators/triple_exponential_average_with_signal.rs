@@ -0,0 +1,297 @@
+use core::fmt;
+
+use crate::errors::{Result, TaError};
+use crate::indicators::generic::{ExponentialMovingAverage, TripleExponentialAverage};
+use crate::registry::{FromParams, Param};
+use crate::{Close, Lookback, Next, Num, Peek, Period, Reset};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Triple exponential average (TRIX) with a signal line.
+///
+/// [TRIX](struct.TripleExponentialAverage.html) and its signal line, an EMA of TRIX.
+///
+/// # Formula
+///
+/// Signal = EMA(TRIX)
+///
+/// The first TRIX is always 0, so the signal line starts with the second input.
+///
+/// # Parameters
+///
+/// * _period_ - number of periods of each EMA of TRIX (integer greater than 0). Default is 15.
+/// * _signal_period_ - period of the signal line (integer greater than 0). Default is 9.
+///
+/// # Example
+///
+/// ```
+/// use ta::indicators::TripleExponentialAverageWithSignal;
+/// use ta::Next;
+///
+/// let mut trix = TripleExponentialAverageWithSignal::new(3, 2).unwrap();
+/// assert_eq!(trix.next(16.0).trix, 0.0);
+///
+/// let output = trix.next(17.0);
+/// assert_eq!(output.trix, 0.78125);
+/// assert_eq!(output.signal, 0.78125);
+/// ```
+///
+/// # Links
+///
+/// * [TRIX, Wikipedia](https://en.wikipedia.org/wiki/Trix_(technical_analysis))
+///
+#[doc(alias = "TRIX")]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone)]
+pub struct TripleExponentialAverageWithSignal<N = f64> {
+    trix: TripleExponentialAverage<N>,
+    signal: ExponentialMovingAverage<N>,
+    signal_value: N,
+    count: usize,
+}
+
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, PartialEq)]
+pub struct TripleExponentialAverageOutput<N = f64> {
+    pub trix: N,
+    pub signal: N,
+}
+
+impl_fields!(TripleExponentialAverageOutput { trix, signal });
+
+impl<N> From<TripleExponentialAverageOutput<N>> for (N, N) {
+    fn from(output: TripleExponentialAverageOutput<N>) -> Self {
+        (output.trix, output.signal)
+    }
+}
+
+impl<N: Num> TripleExponentialAverageWithSignal<N> {
+    pub fn new(period: usize, signal_period: usize) -> Result<Self> {
+        Ok(Self {
+            trix: TripleExponentialAverage::new(period)?,
+            signal: ExponentialMovingAverage::new(signal_period)?,
+            signal_value: N::zero(),
+            count: 0,
+        })
+    }
+
+    pub fn signal_period(&self) -> usize {
+        self.signal.period()
+    }
+
+    fn output(&self, trix: N) -> TripleExponentialAverageOutput<N> {
+        TripleExponentialAverageOutput {
+            trix,
+            signal: self.signal_value,
+        }
+    }
+}
+
+impl<N: Num> Period for TripleExponentialAverageWithSignal<N> {
+    fn period(&self) -> usize {
+        self.trix.period()
+    }
+}
+
+impl<N: Num> Lookback for TripleExponentialAverageWithSignal<N> {
+    fn lookback(&self) -> usize {
+        self.trix.lookback() + self.signal.lookback() - 1
+    }
+
+    fn is_ready(&self) -> bool {
+        self.count == self.lookback()
+    }
+}
+
+impl<N: Num> Next<N> for TripleExponentialAverageWithSignal<N> {
+    type Output = TripleExponentialAverageOutput<N>;
+
+    fn next(&mut self, input: N) -> Self::Output {
+        let trix = self.trix.next(input);
+        if self.count > 0 {
+            self.signal_value = self.signal.next(trix);
+        }
+
+        if self.count < self.lookback() {
+            self.count += 1;
+        }
+
+        self.output(trix)
+    }
+}
+
+impl_next_batch!(TripleExponentialAverageWithSignal, N => TripleExponentialAverageOutput<N>);
+
+impl<N: Num> Peek<N> for TripleExponentialAverageWithSignal<N> {
+    fn peek(&self, input: N) -> Self::Output {
+        let trix = self.trix.peek(input);
+        if self.count == 0 {
+            return self.output(trix);
+        }
+
+        TripleExponentialAverageOutput {
+            trix,
+            signal: self.signal.peek(trix),
+        }
+    }
+
+    fn update_last(&mut self, input: N) -> Self::Output {
+        if self.count == 0 {
+            return self.next(input);
+        }
+
+        let trix = self.trix.update_last(input);
+        if self.count > 1 {
+            self.signal_value = self.signal.update_last(trix);
+        }
+
+        self.output(trix)
+    }
+}
+
+impl_for_floats! {
+    impl<T: Close<N>> Next<&T> for TripleExponentialAverageWithSignal<N> {
+        type Output = TripleExponentialAverageOutput<N>;
+
+        fn next(&mut self, input: &T) -> Self::Output {
+            self.next(input.close())
+        }
+    }
+
+    impl_next_batch!(TripleExponentialAverageWithSignal<N>, Close => TripleExponentialAverageOutput<N>);
+
+    impl<T: Close<N>> Peek<&T> for TripleExponentialAverageWithSignal<N> {
+        fn peek(&self, input: &T) -> Self::Output {
+            self.peek(input.close())
+        }
+
+        fn update_last(&mut self, input: &T) -> Self::Output {
+            self.update_last(input.close())
+        }
+    }
+}
+
+impl<N: Num> Reset for TripleExponentialAverageWithSignal<N> {
+    fn reset(&mut self) {
+        self.trix.reset();
+        self.signal.reset();
+        self.signal_value = N::zero();
+        self.count = 0;
+    }
+}
+
+impl<N: Num> Default for TripleExponentialAverageWithSignal<N> {
+    fn default() -> Self {
+        Self::new(15, 9).unwrap()
+    }
+}
+
+impl<N: Num> fmt::Display for TripleExponentialAverageWithSignal<N> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "TRIX({}, {})", self.period(), self.signal_period())
+    }
+}
+
+impl<N: Num> FromParams for TripleExponentialAverageWithSignal<N> {
+    const NAME: &'static str = "TRIX";
+
+    fn from_params(params: &[Param]) -> Result<Self> {
+        match *params {
+            [period, signal_period] => Self::new(period.period()?, signal_period.period()?),
+            _ => Err(TaError::InvalidParameter),
+        }
+    }
+}
+
+impl_from_str!(TripleExponentialAverageWithSignal);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::indicators::{TripleExponentialAverage, TripleExponentialAverageWithSignal};
+    use crate::test_helper::*;
+
+    test_indicator!(TripleExponentialAverageWithSignal);
+
+    #[test]
+    fn test_new() {
+        assert!(TripleExponentialAverageWithSignal::new(0, 9).is_err());
+        assert!(TripleExponentialAverageWithSignal::new(15, 0).is_err());
+        assert!(TripleExponentialAverageWithSignal::new(1, 1).is_ok());
+    }
+
+    #[test]
+    fn test_next() {
+        let mut trix = TripleExponentialAverageWithSignal::new(3, 2).unwrap();
+
+        assert_eq!(<(f64, f64)>::from(trix.next(16.0)), (0.0, 0.0));
+        assert_eq!(<(f64, f64)>::from(trix.next(17.0)), (0.78125, 0.78125));
+
+        let output = trix.next(17.0);
+        assert_eq!(output.trix, 1.1627906976744187);
+        assert_eq!(round(output.signal), 1.036);
+
+        let output = trix.next(10.0);
+        assert_eq!(output.trix, -4.21455938697318);
+        assert_eq!(round(output.signal), -2.465);
+    }
+
+    #[test]
+    fn test_next_same_as_trix() {
+        let mut trix = TripleExponentialAverageWithSignal::new(3, 2).unwrap();
+        let mut expected = TripleExponentialAverage::new(3).unwrap();
+
+        for bar in peek_bars().iter() {
+            assert_eq!(trix.next(bar).trix, expected.next(bar));
+        }
+    }
+
+    #[test]
+    fn test_peek() {
+        assert_peek(
+            TripleExponentialAverageWithSignal::new(3, 2).unwrap(),
+            &peek_inputs(),
+        );
+        assert_peek(
+            TripleExponentialAverageWithSignal::new(1, 1).unwrap(),
+            &peek_inputs(),
+        );
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut trix = TripleExponentialAverageWithSignal::new(3, 2).unwrap();
+        trix.next(16.0);
+        trix.next(17.0);
+
+        trix.reset();
+        assert_eq!(<(f64, f64)>::from(trix.next(16.0)), (0.0, 0.0));
+        assert_eq!(<(f64, f64)>::from(trix.next(17.0)), (0.78125, 0.78125));
+    }
+
+    #[test]
+    fn test_lookback() {
+        let mut trix = TripleExponentialAverageWithSignal::new(3, 2).unwrap();
+        assert_eq!(trix.lookback(), 9);
+
+        for _ in 0..8 {
+            trix.next(1.0);
+            assert!(!trix.is_ready());
+        }
+        trix.next(1.0);
+        assert!(trix.is_ready());
+    }
+
+    #[test]
+    fn test_default() {
+        let trix = TripleExponentialAverageWithSignal::default();
+        assert_eq!(trix.period(), 15);
+        assert_eq!(trix.signal_period(), 9);
+    }
+
+    #[test]
+    fn test_display() {
+        let trix = TripleExponentialAverageWithSignal::new(7, 9).unwrap();
+        assert_eq!(format!("{}", trix), "TRIX(7, 9)");
+    }
+}
@@ -1,197 +1,259 @@
-use std::fmt;
+use core::fmt;
 
 use crate::errors::{Result, TaError};
-use crate::indicators::ExponentialMovingAverage;
-use crate::{Close, Next, Period, Reset};
+use crate::indicators::generic::ExponentialMovingAverage;
+use crate::registry::{FromParams, Param};
+use crate::{Close, Lookback, Next, Num, Peek, Period, Reset};
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
-/// A triple exponential moving average (TRIX)
+/// Triple exponential moving average (TEMA).
 ///
-/// It is a type of infinite impulse response filter that applies weighting factors which decrease exponentially.
-/// The weighting for each older datum decreases exponentially, never reaching zero.
+/// A low lag moving average, which is built from three nested
+/// [exponential moving averages](struct.ExponentialMovingAverage.html), like the
+/// [DEMA](struct.DoubleExponentialMovingAverage.html) is built from two of them.
+///
+/// Not to be confused with [TRIX](struct.TripleExponentialAverage.html), which is the rate of
+/// change of a triple smoothed EMA.
+///
+/// # Formula
+///
+/// TEMA = 3 × EMA<sub>1</sub> - 3 × EMA<sub>2</sub> + EMA<sub>3</sub>
 ///
 /// Where:
 ///
-/// * _period_ - number of periods
+/// * _EMA<sub>1</sub>_ - is the EMA of the input
+/// * _EMA<sub>2</sub>_ - is the EMA of _EMA<sub>1</sub>_
+/// * _EMA<sub>3</sub>_ - is the EMA of _EMA<sub>2</sub>_
 ///
 /// # Parameters
 ///
-/// * _period_ - number of periods (integer greater than 0)
+/// * _period_ - number of periods of each EMA (integer greater than 0). Default is 9.
 ///
 /// # Example
 ///
 /// ```
-/// use ta::indicators::TripleExponentialAverage;
+/// use ta::indicators::TripleExponentialMovingAverage;
 /// use ta::Next;
 ///
-/// let mut trix = TripleExponentialAverage::new(3).unwrap();
-/// let result = trix.next(2.0);
+/// let mut tema = TripleExponentialMovingAverage::new(3).unwrap();
+/// assert_eq!(tema.next(2.0), 2.0);
+/// assert_eq!(tema.next(5.0), 4.625);
+/// assert_eq!(tema.next(1.0), 1.6875);
+/// assert_eq!(tema.next(6.25), 5.53125);
 /// ```
 ///
 /// # Links
 ///
-/// * [Exponential moving average, Wikipedia](https://en.wikipedia.org/wiki/Triple_exponential_moving_average)
+/// * [Triple exponential moving average, Wikipedia](https://en.wikipedia.org/wiki/Triple_exponential_moving_average)
 ///
-
-#[doc(alias = "TRIX")]
+#[doc(alias = "TEMA")]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[derive(Debug, Clone)]
-pub struct TripleExponentialAverage {
+pub struct TripleExponentialMovingAverage<N = f64> {
     period: usize,
-    // k: f64,
-    current_em_3_value: f64,
-    is_new: bool,
-    ema: ExponentialMovingAverage,
-    ema2: ExponentialMovingAverage,
-    ema3: ExponentialMovingAverage,
+    count: usize,
+    ema: ExponentialMovingAverage<N>,
+    ema2: ExponentialMovingAverage<N>,
+    ema3: ExponentialMovingAverage<N>,
 }
 
-impl TripleExponentialAverage {
+impl<N: Num> TripleExponentialMovingAverage<N> {
     pub fn new(period: usize) -> Result<Self> {
-        match period {
-            0 => Err(TaError::InvalidParameter),
-            _ => Ok(Self {
-                period,
-                // k: 2.0 / (period + 1) as f64,
-                current_em_3_value: 0.0,
-                is_new: true,
-                ema: ExponentialMovingAverage::new(period).unwrap(),
-                ema2: ExponentialMovingAverage::new(period).unwrap(),
-                ema3: ExponentialMovingAverage::new(period).unwrap(),
-            }),
-        }
+        Ok(Self {
+            period,
+            count: 0,
+            ema: ExponentialMovingAverage::new(period)?,
+            ema2: ExponentialMovingAverage::new(period)?,
+            ema3: ExponentialMovingAverage::new(period)?,
+        })
     }
 }
 
-impl Period for TripleExponentialAverage {
+impl<N: Num> Period for TripleExponentialMovingAverage<N> {
     fn period(&self) -> usize {
         self.period
     }
 }
 
-impl Next<f64> for TripleExponentialAverage {
-    type Output = f64;
+impl<N: Num> Lookback for TripleExponentialMovingAverage<N> {
+    fn lookback(&self) -> usize {
+        3 * self.period - 2
+    }
+
+    fn is_ready(&self) -> bool {
+        self.count == self.lookback()
+    }
+}
+
+impl<N: Num> Next<N> for TripleExponentialMovingAverage<N> {
+    type Output = N;
 
-    fn next(&mut self, input: f64) -> Self::Output {
+    fn next(&mut self, input: N) -> Self::Output {
         let ema_value = self.ema.next(input);
         let ema_2_value = self.ema2.next(ema_value);
         let ema_3_value = self.ema3.next(ema_2_value);
 
-        let mut trix = 0.0;
+        if self.count < self.lookback() {
+            self.count += 1;
+        }
+
+        N::from_f64(3.0) * ema_value - N::from_f64(3.0) * ema_2_value + ema_3_value
+    }
+}
+
+impl_next_batch!(TripleExponentialMovingAverage, N => N);
 
-        if self.is_new {
-            self.is_new = false;
-            self.current_em_3_value = ema_3_value;
-        } else {
-            trix = ((ema_3_value - self.current_em_3_value) / self.current_em_3_value) * 100.0;
-            self.current_em_3_value = ema_3_value;
+impl<N: Num> Peek<N> for TripleExponentialMovingAverage<N> {
+    fn peek(&self, input: N) -> Self::Output {
+        let ema_value = self.ema.peek(input);
+        let ema_2_value = self.ema2.peek(ema_value);
+        let ema_3_value = self.ema3.peek(ema_2_value);
+
+        N::from_f64(3.0) * ema_value - N::from_f64(3.0) * ema_2_value + ema_3_value
+    }
+
+    fn update_last(&mut self, input: N) -> Self::Output {
+        if self.count == 0 {
+            return self.next(input);
         }
 
-        trix
+        let ema_value = self.ema.update_last(input);
+        let ema_2_value = self.ema2.update_last(ema_value);
+        let ema_3_value = self.ema3.update_last(ema_2_value);
+
+        N::from_f64(3.0) * ema_value - N::from_f64(3.0) * ema_2_value + ema_3_value
     }
 }
 
-impl<T: Close> Next<&T> for TripleExponentialAverage {
-    type Output = f64;
+impl_for_floats! {
+    impl<T: Close<N>> Next<&T> for TripleExponentialMovingAverage<N> {
+        type Output = N;
+
+        fn next(&mut self, input: &T) -> Self::Output {
+            self.next(input.close())
+        }
+    }
+
+    impl_next_batch!(TripleExponentialMovingAverage<N>, Close => N);
+
+    impl<T: Close<N>> Peek<&T> for TripleExponentialMovingAverage<N> {
+        fn peek(&self, input: &T) -> Self::Output {
+            self.peek(input.close())
+        }
 
-    fn next(&mut self, input: &T) -> Self::Output {
-        self.next(input.close())
+        fn update_last(&mut self, input: &T) -> Self::Output {
+            self.update_last(input.close())
+        }
     }
 }
 
-impl Reset for TripleExponentialAverage {
+impl<N: Num> Reset for TripleExponentialMovingAverage<N> {
     fn reset(&mut self) {
-        self.current_em_3_value = 0.0;
-        self.is_new = true;
-
+        self.count = 0;
         self.ema.reset();
         self.ema2.reset();
         self.ema3.reset();
     }
 }
 
-impl Default for TripleExponentialAverage {
+impl<N: Num> Default for TripleExponentialMovingAverage<N> {
     fn default() -> Self {
-        Self::new(15).unwrap()
+        Self::new(9).unwrap()
     }
 }
 
-impl fmt::Display for TripleExponentialAverage {
+impl<N: Num> fmt::Display for TripleExponentialMovingAverage<N> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "TRIX({})", self.period)
+        write!(f, "TEMA({})", self.period)
+    }
+}
+
+impl<N: Num> FromParams for TripleExponentialMovingAverage<N> {
+    const NAME: &'static str = "TEMA";
+
+    fn from_params(params: &[Param]) -> Result<Self> {
+        match *params {
+            [period] => Self::new(period.period()?),
+            _ => Err(TaError::InvalidParameter),
+        }
     }
 }
 
+impl_from_str!(TripleExponentialMovingAverage);
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::indicators::TripleExponentialMovingAverage;
     use crate::test_helper::*;
 
-    test_indicator!(TripleExponentialAverage);
+    test_indicator!(TripleExponentialMovingAverage);
 
     #[test]
     fn test_new() {
-        assert!(TripleExponentialAverage::new(0).is_err());
-        assert!(TripleExponentialAverage::new(1).is_ok());
+        assert!(TripleExponentialMovingAverage::new(0).is_err());
+        assert!(TripleExponentialMovingAverage::new(1).is_ok());
     }
 
     #[test]
     fn test_next() {
-        let mut trix = TripleExponentialAverage::new(3).unwrap();
+        let mut tema = TripleExponentialMovingAverage::new(3).unwrap();
 
-        assert_eq!(trix.next(16.0), 0.0);
-        assert_eq!(trix.next(17.0), 0.78125);
-        assert_eq!(trix.next(17.0), 1.1627906976744187);
-        assert_eq!(trix.next(10.0), -4.21455938697318);
-        assert_eq!(trix.next(17.0), -1.7999999999999998);
+        assert_eq!(tema.next(2.0), 2.0);
+        assert_eq!(tema.next(5.0), 4.625);
+        assert_eq!(tema.next(1.0), 1.6875);
+        assert_eq!(tema.next(6.25), 5.53125);
 
-        let mut trix = TripleExponentialAverage::new(3).unwrap();
+        let mut tema = TripleExponentialMovingAverage::new(3).unwrap();
         let bar1 = Bar::new().close(2);
         let bar2 = Bar::new().close(5);
-        assert_eq!(trix.next(&bar1), 0.0);
-        assert_eq!(trix.next(&bar2), 18.75);
+        assert_eq!(tema.next(&bar1), 2.0);
+        assert_eq!(tema.next(&bar2), 4.625);
     }
 
     #[test]
-    fn test_next_2() {
-        let mut trix = TripleExponentialAverage::new(15).unwrap();
+    fn test_next_period_1() {
+        let mut tema = TripleExponentialMovingAverage::new(1).unwrap();
 
-        trix.next(16.0);
-        trix.next(17.0);
-        trix.next(17.0);
-        trix.next(10.0);
-        trix.next(17.0);
-        trix.next(18.0);
-        trix.next(17.0);
-        trix.next(17.0);
-        let result = trix.next(17.0);
-
-        assert_eq!(result, 0.029258774080521098);
+        assert_eq!(tema.next(10.0), 10.0);
+        assert_eq!(tema.next(13.0), 13.0);
     }
 
     #[test]
     fn test_reset() {
-        let mut trix = TripleExponentialAverage::new(5).unwrap();
+        let mut tema = TripleExponentialMovingAverage::new(3).unwrap();
+
+        assert_eq!(tema.next(2.0), 2.0);
+        assert_eq!(tema.next(5.0), 4.625);
 
-        assert_eq!(trix.next(4.0), 0.0);
-        trix.next(10.0);
-        trix.next(15.0);
-        trix.next(20.0);
-        assert_ne!(trix.next(4.0), 4.0);
+        tema.reset();
 
-        trix.reset();
-        assert_eq!(trix.next(4.0), 0.0);
+        assert_eq!(tema.next(2.0), 2.0);
+        assert_eq!(tema.next(5.0), 4.625);
+    }
+
+    #[test]
+    fn test_lookback() {
+        let mut tema = TripleExponentialMovingAverage::new(3).unwrap();
+        assert_eq!(tema.lookback(), 7);
+
+        for _ in 0..6 {
+            tema.next(1.0);
+            assert!(!tema.is_ready());
+        }
+        tema.next(1.0);
+        assert!(tema.is_ready());
     }
 
     #[test]
     fn test_default() {
-        TripleExponentialAverage::default();
+        TripleExponentialMovingAverage::default();
     }
 
     #[test]
     fn test_display() {
-        let trix = TripleExponentialAverage::new(7).unwrap();
-        assert_eq!(format!("{}", trix), "TRIX(7)");
+        let tema = TripleExponentialMovingAverage::new(7).unwrap();
+        assert_eq!(format!("{}", tema), "TEMA(7)");
     }
 }
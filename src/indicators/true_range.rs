@@ -1,7 +1,9 @@
-use std::fmt;
+use core::fmt;
 
+use crate::errors::{Result, TaError};
 use crate::helpers::max3;
-use crate::{Close, High, Low, Next, Reset};
+use crate::registry::{FromParams, Param};
+use crate::{Close, High, Lookback, Low, Next, Num, Peek, Reset};
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
@@ -51,68 +53,155 @@ use serde::{Deserialize, Serialize};
 /// ```
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[derive(Debug, Clone)]
-pub struct TrueRange {
-    prev_close: Option<f64>,
+pub struct TrueRange<N = f64> {
+    prev_close: Option<N>,
+    prev_prev_close: Option<N>,
 }
 
-impl TrueRange {
+impl<N: Num> TrueRange<N> {
     pub fn new() -> Self {
-        Self { prev_close: None }
+        Self {
+            prev_close: None,
+            prev_prev_close: None,
+        }
+    }
+
+    fn distance(prev_close: Option<N>, input: N) -> N {
+        match prev_close {
+            Some(prev) => (input - prev).abs(),
+            None => N::zero(),
+        }
+    }
+
+    fn range<T: High<N> + Low<N>>(prev_close: Option<N>, bar: &T) -> N {
+        match prev_close {
+            Some(prev_close) => {
+                let dist1 = bar.high() - bar.low();
+                let dist2 = (bar.high() - prev_close).abs();
+                let dist3 = (bar.low() - prev_close).abs();
+                max3(dist1, dist2, dist3)
+            }
+            None => bar.high() - bar.low(),
+        }
+    }
+
+    // The bar versions of `next`, `peek` and `update_last` for any numeric type, for the
+    // indicators, which accept only bars and use the true range.
+    pub(super) fn next_bar<T: High<N> + Low<N> + Close<N>>(&mut self, bar: &T) -> N {
+        let max_dist = Self::range(self.prev_close, bar);
+        self.prev_prev_close = self.prev_close;
+        self.prev_close = Some(bar.close());
+        max_dist
+    }
+
+    pub(super) fn peek_bar<T: High<N> + Low<N>>(&self, bar: &T) -> N {
+        Self::range(self.prev_close, bar)
+    }
+
+    pub(super) fn update_last_bar<T: High<N> + Low<N> + Close<N>>(&mut self, bar: &T) -> N {
+        if self.prev_close.is_none() {
+            return self.next_bar(bar);
+        }
+        self.prev_close = Some(bar.close());
+        Self::range(self.prev_prev_close, bar)
     }
 }
 
-impl Default for TrueRange {
+impl<N: Num> Default for TrueRange<N> {
     fn default() -> Self {
         Self::new()
     }
 }
 
-impl fmt::Display for TrueRange {
+impl<N: Num> fmt::Display for TrueRange<N> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         write!(f, "TRUE_RANGE()")
     }
 }
 
-impl Next<f64> for TrueRange {
-    type Output = f64;
+impl<N: Num> Lookback for TrueRange<N> {
+    fn lookback(&self) -> usize {
+        1
+    }
 
-    fn next(&mut self, input: f64) -> Self::Output {
-        let distance = match self.prev_close {
-            Some(prev) => (input - prev).abs(),
-            None => 0.0,
-        };
+    fn is_ready(&self) -> bool {
+        self.prev_close.is_some()
+    }
+}
+
+impl<N: Num> Next<N> for TrueRange<N> {
+    type Output = N;
+
+    fn next(&mut self, input: N) -> Self::Output {
+        let distance = Self::distance(self.prev_close, input);
+        self.prev_prev_close = self.prev_close;
         self.prev_close = Some(input);
         distance
     }
 }
 
-impl<T: High + Low + Close> Next<&T> for TrueRange {
-    type Output = f64;
+impl_next_batch!(TrueRange, N => N);
 
-    fn next(&mut self, bar: &T) -> Self::Output {
-        let max_dist = match self.prev_close {
-            Some(prev_close) => {
-                let dist1 = bar.high() - bar.low();
-                let dist2 = (bar.high() - prev_close).abs();
-                let dist3 = (bar.low() - prev_close).abs();
-                max3(dist1, dist2, dist3)
-            }
-            None => bar.high() - bar.low(),
-        };
-        self.prev_close = Some(bar.close());
-        max_dist
+impl<N: Num> Peek<N> for TrueRange<N> {
+    fn peek(&self, input: N) -> Self::Output {
+        Self::distance(self.prev_close, input)
+    }
+
+    fn update_last(&mut self, input: N) -> Self::Output {
+        if self.prev_close.is_none() {
+            return self.next(input);
+        }
+        self.prev_close = Some(input);
+        Self::distance(self.prev_prev_close, input)
     }
 }
 
-impl Reset for TrueRange {
+impl_for_floats! {
+    impl<T: High<N> + Low<N> + Close<N>> Next<&T> for TrueRange<N> {
+        type Output = N;
+
+        fn next(&mut self, bar: &T) -> Self::Output {
+            self.next_bar(bar)
+        }
+    }
+
+    impl_next_batch!(TrueRange<N>, High + Low + Close => N);
+
+    impl<T: High<N> + Low<N> + Close<N>> Peek<&T> for TrueRange<N> {
+        fn peek(&self, bar: &T) -> Self::Output {
+            self.peek_bar(bar)
+        }
+
+        fn update_last(&mut self, bar: &T) -> Self::Output {
+            self.update_last_bar(bar)
+        }
+    }
+}
+
+impl<N: Num> Reset for TrueRange<N> {
     fn reset(&mut self) {
         self.prev_close = None;
+        self.prev_prev_close = None;
+    }
+}
+
+impl<N: Num> FromParams for TrueRange<N> {
+    const NAME: &'static str = "TRUE_RANGE";
+
+    fn from_params(params: &[Param]) -> Result<Self> {
+        match *params {
+            [] => Ok(Self::new()),
+            _ => Err(TaError::InvalidParameter),
+        }
     }
 }
 
+impl_from_str!(TrueRange);
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::indicators::TrueRange;
     use crate::test_helper::*;
 
     test_indicator!(TrueRange);
@@ -0,0 +1,366 @@
+use core::fmt;
+
+use crate::errors::{Result, TaError};
+use crate::indicators::generic::{SimpleMovingAverage, TrueRange};
+use crate::registry::{FromParams, Param};
+use crate::{Close, High, Lookback, Low, Next, Num, Peek, Period, Reset};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Ultimate Oscillator (UO).
+///
+/// A momentum oscillator, which combines the buying pressure of three periods, so that it is
+/// less prone to the false divergences of single period oscillators. The values are in the
+/// range from 0 to 100.
+///
+/// # Formula
+///
+/// UO = 100 × (4 × A<sub>1</sub> + 2 × A<sub>2</sub> + A<sub>3</sub>) / 7
+///
+/// A<sub>i</sub> = Σ BP / Σ TR over the _i_-th period
+///
+/// Where:
+///
+/// * _BP_ - buying pressure, close - min(low, close<sub>prev</sub>)
+/// * _TR_ - [true range](struct.TrueRange.html)
+///
+/// When the sum of true ranges of a period is 0, its average is 0.5.
+///
+/// # Parameters
+///
+/// * _short_period_ - number of periods of A<sub>1</sub> (integer greater than 0). Default is 7.
+/// * _medium_period_ - number of periods of A<sub>2</sub> (integer greater than 0). Default is 14.
+/// * _long_period_ - number of periods of A<sub>3</sub> (integer greater than 0). Default is 28.
+///
+/// # Example
+///
+/// ```
+/// use ta::indicators::UltimateOscillator;
+/// use ta::Next;
+///
+/// let mut uo = UltimateOscillator::new(1, 2, 3).unwrap();
+/// assert_eq!(uo.next(10.0), 50.0);
+/// assert_eq!(uo.next(12.0), 100.0);
+/// assert_eq!(uo.next(11.0).round(), 29.0);
+/// ```
+///
+/// # Links
+///
+/// * [Ultimate oscillator, Wikipedia](https://en.wikipedia.org/wiki/Ultimate_oscillator)
+///
+#[doc(alias = "UO")]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone)]
+pub struct UltimateOscillator<N = f64> {
+    true_range: TrueRange<N>,
+    prev_close: Option<N>,
+    prev_prev_close: Option<N>,
+    short_bp: SimpleMovingAverage<N>,
+    short_tr: SimpleMovingAverage<N>,
+    medium_bp: SimpleMovingAverage<N>,
+    medium_tr: SimpleMovingAverage<N>,
+    long_bp: SimpleMovingAverage<N>,
+    long_tr: SimpleMovingAverage<N>,
+    count: usize,
+}
+
+impl<N: Num> UltimateOscillator<N> {
+    pub fn new(short_period: usize, medium_period: usize, long_period: usize) -> Result<Self> {
+        Ok(Self {
+            true_range: TrueRange::new(),
+            prev_close: None,
+            prev_prev_close: None,
+            short_bp: SimpleMovingAverage::new(short_period)?,
+            short_tr: SimpleMovingAverage::new(short_period)?,
+            medium_bp: SimpleMovingAverage::new(medium_period)?,
+            medium_tr: SimpleMovingAverage::new(medium_period)?,
+            long_bp: SimpleMovingAverage::new(long_period)?,
+            long_tr: SimpleMovingAverage::new(long_period)?,
+            count: 0,
+        })
+    }
+
+    pub fn short_period(&self) -> usize {
+        self.short_bp.period()
+    }
+
+    pub fn medium_period(&self) -> usize {
+        self.medium_bp.period()
+    }
+
+    pub fn long_period(&self) -> usize {
+        self.long_bp.period()
+    }
+
+    fn buying_pressure(prev_close: Option<N>, low: N, close: N) -> N {
+        match prev_close {
+            Some(prev_close) => close - low.min(prev_close),
+            None => close - low,
+        }
+    }
+
+    // Both sums are averaged over the same number of inputs, so the ratio of the averages is
+    // the ratio of the sums.
+    fn average(bp: N, tr: N) -> N {
+        if tr == N::zero() {
+            N::from_f64(0.5)
+        } else {
+            bp / tr
+        }
+    }
+
+    fn uo(short: N, medium: N, long: N) -> N {
+        N::from_f64(100.0) * (N::from_f64(4.0) * short + N::from_f64(2.0) * medium + long)
+            / N::from_f64(7.0)
+    }
+
+    fn next_values(&mut self, bp: N, tr: N) -> N {
+        if self.count < self.lookback() {
+            self.count += 1;
+        }
+        Self::uo(
+            Self::average(self.short_bp.next(bp), self.short_tr.next(tr)),
+            Self::average(self.medium_bp.next(bp), self.medium_tr.next(tr)),
+            Self::average(self.long_bp.next(bp), self.long_tr.next(tr)),
+        )
+    }
+
+    fn peek_values(&self, bp: N, tr: N) -> N {
+        Self::uo(
+            Self::average(self.short_bp.peek(bp), self.short_tr.peek(tr)),
+            Self::average(self.medium_bp.peek(bp), self.medium_tr.peek(tr)),
+            Self::average(self.long_bp.peek(bp), self.long_tr.peek(tr)),
+        )
+    }
+
+    fn update_last_values(&mut self, bp: N, tr: N) -> N {
+        Self::uo(
+            Self::average(self.short_bp.update_last(bp), self.short_tr.update_last(tr)),
+            Self::average(
+                self.medium_bp.update_last(bp),
+                self.medium_tr.update_last(tr),
+            ),
+            Self::average(self.long_bp.update_last(bp), self.long_tr.update_last(tr)),
+        )
+    }
+}
+
+impl<N: Num> Lookback for UltimateOscillator<N> {
+    fn lookback(&self) -> usize {
+        self.short_period()
+            .max(self.medium_period())
+            .max(self.long_period())
+            + 1
+    }
+
+    fn is_ready(&self) -> bool {
+        self.count == self.lookback()
+    }
+}
+
+impl<N: Num> Next<N> for UltimateOscillator<N> {
+    type Output = N;
+
+    fn next(&mut self, input: N) -> Self::Output {
+        let tr = self.true_range.next(input);
+        let bp = Self::buying_pressure(self.prev_close, input, input);
+        self.prev_prev_close = self.prev_close;
+        self.prev_close = Some(input);
+        self.next_values(bp, tr)
+    }
+}
+
+impl_next_batch!(UltimateOscillator, N => N);
+
+impl<N: Num> Peek<N> for UltimateOscillator<N> {
+    fn peek(&self, input: N) -> Self::Output {
+        let tr = self.true_range.peek(input);
+        let bp = Self::buying_pressure(self.prev_close, input, input);
+        self.peek_values(bp, tr)
+    }
+
+    fn update_last(&mut self, input: N) -> Self::Output {
+        if self.count == 0 {
+            return self.next(input);
+        }
+
+        let tr = self.true_range.update_last(input);
+        let bp = Self::buying_pressure(self.prev_prev_close, input, input);
+        self.prev_close = Some(input);
+        self.update_last_values(bp, tr)
+    }
+}
+
+impl_for_floats! {
+    impl<T: High<N> + Low<N> + Close<N>> Next<&T> for UltimateOscillator<N> {
+        type Output = N;
+
+        fn next(&mut self, input: &T) -> Self::Output {
+            let tr = self.true_range.next(input);
+            let bp = Self::buying_pressure(self.prev_close, input.low(), input.close());
+            self.prev_prev_close = self.prev_close;
+            self.prev_close = Some(input.close());
+            self.next_values(bp, tr)
+        }
+    }
+
+    impl_next_batch!(UltimateOscillator<N>, High + Low + Close => N);
+
+    impl<T: High<N> + Low<N> + Close<N>> Peek<&T> for UltimateOscillator<N> {
+        fn peek(&self, input: &T) -> Self::Output {
+            let tr = self.true_range.peek(input);
+            let bp = Self::buying_pressure(self.prev_close, input.low(), input.close());
+            self.peek_values(bp, tr)
+        }
+
+        fn update_last(&mut self, input: &T) -> Self::Output {
+            if self.count == 0 {
+                return self.next(input);
+            }
+
+            let tr = self.true_range.update_last(input);
+            let bp = Self::buying_pressure(self.prev_prev_close, input.low(), input.close());
+            self.prev_close = Some(input.close());
+            self.update_last_values(bp, tr)
+        }
+    }
+}
+
+impl<N: Num> Reset for UltimateOscillator<N> {
+    fn reset(&mut self) {
+        self.true_range.reset();
+        self.prev_close = None;
+        self.prev_prev_close = None;
+        self.short_bp.reset();
+        self.short_tr.reset();
+        self.medium_bp.reset();
+        self.medium_tr.reset();
+        self.long_bp.reset();
+        self.long_tr.reset();
+        self.count = 0;
+    }
+}
+
+impl<N: Num> Default for UltimateOscillator<N> {
+    fn default() -> Self {
+        Self::new(7, 14, 28).unwrap()
+    }
+}
+
+impl<N: Num> fmt::Display for UltimateOscillator<N> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "UO({}, {}, {})",
+            self.short_period(),
+            self.medium_period(),
+            self.long_period()
+        )
+    }
+}
+
+impl<N: Num> FromParams for UltimateOscillator<N> {
+    const NAME: &'static str = "UO";
+
+    fn from_params(params: &[Param]) -> Result<Self> {
+        match *params {
+            [short_period, medium_period, long_period] => Self::new(
+                short_period.period()?,
+                medium_period.period()?,
+                long_period.period()?,
+            ),
+            _ => Err(TaError::InvalidParameter),
+        }
+    }
+}
+
+impl_from_str!(UltimateOscillator);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::indicators::UltimateOscillator;
+    use crate::test_helper::*;
+
+    test_indicator!(UltimateOscillator);
+
+    #[test]
+    fn test_new() {
+        assert!(UltimateOscillator::new(0, 14, 28).is_err());
+        assert!(UltimateOscillator::new(7, 0, 28).is_err());
+        assert!(UltimateOscillator::new(7, 14, 0).is_err());
+        assert!(UltimateOscillator::new(1, 1, 1).is_ok());
+    }
+
+    #[test]
+    fn test_next_bar() {
+        let mut uo = UltimateOscillator::new(1, 2, 3).unwrap();
+
+        let bar1 = Bar::new().high(12).low(8).close(11);
+        let bar2 = Bar::new().high(13).low(10).close(10);
+        let bar3 = Bar::new().high(14).low(12).close(14);
+        let bar4 = Bar::new().high(14).low(14).close(14);
+
+        // BP = 3, TR = 4
+        assert_eq!(uo.next(&bar1), 75.0);
+        // BP = 0, TR = 3
+        assert_eq!(round(uo.next(&bar2)), 18.367);
+        // BP = 4, TR = 4
+        assert_eq!(round(uo.next(&bar3)), 82.56);
+        // BP = 0, TR = 0
+        assert_eq!(round(uo.next(&bar4)), 65.306);
+    }
+
+    #[test]
+    fn test_next_flat() {
+        let mut uo = UltimateOscillator::new(2, 3, 4).unwrap();
+        let bar = Bar::new().high(10).low(10).close(10);
+
+        for _ in 0..5 {
+            assert_eq!(uo.next(&bar), 50.0);
+        }
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut uo = UltimateOscillator::new(1, 2, 3).unwrap();
+
+        let bar1 = Bar::new().high(12).low(8).close(11);
+        let bar2 = Bar::new().high(13).low(10).close(10);
+
+        assert_eq!(uo.next(&bar1), 75.0);
+        assert_eq!(round(uo.next(&bar2)), 18.367);
+
+        uo.reset();
+
+        assert_eq!(uo.next(&bar1), 75.0);
+        assert_eq!(round(uo.next(&bar2)), 18.367);
+    }
+
+    #[test]
+    fn test_lookback() {
+        let mut uo = UltimateOscillator::new(2, 4, 3).unwrap();
+        assert_eq!(uo.lookback(), 5);
+
+        for _ in 0..4 {
+            uo.next(1.0);
+            assert!(!uo.is_ready());
+        }
+        uo.next(1.0);
+        assert!(uo.is_ready());
+    }
+
+    #[test]
+    fn test_default() {
+        let uo = UltimateOscillator::default();
+        assert_eq!(uo.short_period(), 7);
+        assert_eq!(uo.medium_period(), 14);
+        assert_eq!(uo.long_period(), 28);
+    }
+
+    #[test]
+    fn test_display() {
+        let uo = UltimateOscillator::new(7, 14, 28).unwrap();
+        assert_eq!(format!("{}", uo), "UO(7, 14, 28)");
+    }
+}
@@ -0,0 +1,141 @@
+use crate::Num;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Direction of the volume change, on which a [VolumeIndex] follows the close price.
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum VolumeChange {
+    Decrease,
+    Increase,
+}
+
+/// Cumulative index, which changes by the change of the close price on bars, where the volume
+/// changes in the given direction compared to the prior bar.
+///
+/// The common part of [NVI](struct.NegativeVolumeIndex.html) and
+/// [PVI](struct.PositiveVolumeIndex.html), which differ in the direction of the volume change
+/// only.
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone)]
+pub(crate) struct VolumeIndex<N> {
+    change: VolumeChange,
+    value: N,
+    prev_value: N,
+    prev_close: N,
+    prev_volume: N,
+    prev_prev_close: N,
+    prev_prev_volume: N,
+    count: usize,
+}
+
+const INITIAL_VALUE: f64 = 1000.0;
+
+impl<N: Num> VolumeIndex<N> {
+    pub fn new(change: VolumeChange) -> Self {
+        Self {
+            change,
+            value: N::from_f64(INITIAL_VALUE),
+            prev_value: N::from_f64(INITIAL_VALUE),
+            prev_close: N::zero(),
+            prev_volume: N::zero(),
+            prev_prev_close: N::zero(),
+            prev_prev_volume: N::zero(),
+            count: 0,
+        }
+    }
+
+    pub fn count(&self) -> usize {
+        self.count
+    }
+
+    pub fn next(&mut self, close: N, volume: N) -> N {
+        self.prev_value = self.value;
+        if self.count > 0 {
+            self.value = self.calc(self.value, self.prev_close, self.prev_volume, close, volume);
+        }
+        self.prev_prev_close = self.prev_close;
+        self.prev_prev_volume = self.prev_volume;
+        self.prev_close = close;
+        self.prev_volume = volume;
+        if self.count < 2 {
+            self.count += 1;
+        }
+        self.value
+    }
+
+    pub fn peek(&self, close: N, volume: N) -> N {
+        if self.count == 0 {
+            return self.value;
+        }
+        self.calc(self.value, self.prev_close, self.prev_volume, close, volume)
+    }
+
+    pub fn update_last(&mut self, close: N, volume: N) -> N {
+        match self.count {
+            0 => return self.next(close, volume),
+            1 => {}
+            _ => {
+                self.value = self.calc(
+                    self.prev_value,
+                    self.prev_prev_close,
+                    self.prev_prev_volume,
+                    close,
+                    volume,
+                )
+            }
+        }
+        self.prev_close = close;
+        self.prev_volume = volume;
+        self.value
+    }
+
+    pub fn reset(&mut self) {
+        *self = Self::new(self.change);
+    }
+
+    fn calc(&self, value: N, prev_close: N, prev_volume: N, close: N, volume: N) -> N {
+        let changed = match self.change {
+            VolumeChange::Decrease => volume < prev_volume,
+            VolumeChange::Increase => volume > prev_volume,
+        };
+        if changed && prev_close != N::zero() {
+            value * close / prev_close
+        } else {
+            value
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_next() {
+        let mut decrease = VolumeIndex::new(VolumeChange::Decrease);
+        let mut increase = VolumeIndex::new(VolumeChange::Increase);
+
+        for (close, volume, on_decrease, on_increase) in [
+            (10.0, 100.0, 1000.0, 1000.0),
+            (11.0, 50.0, 1100.0, 1000.0),
+            (12.0, 80.0, 1100.0, 1090.909090909091),
+            (12.0, 80.0, 1100.0, 1090.909090909091),
+        ] {
+            assert_eq!(decrease.next(close, volume), on_decrease);
+            assert_eq!(increase.next(close, volume), on_increase);
+        }
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut index = VolumeIndex::new(VolumeChange::Increase);
+        index.next(10.0, 100.0);
+        index.next(20.0, 200.0);
+
+        index.reset();
+        assert_eq!(index.count(), 0);
+        assert_eq!(index.next(10.0, 100.0), 1000.0);
+        assert_eq!(index.next(20.0, 200.0), 2000.0);
+    }
+}
@@ -1,72 +1,152 @@
-use std::fmt;
+use core::fmt;
 
-use crate::{Close, High, Low, Next, Reset, Volume};
+use crate::errors::{Result, TaError};
+use crate::registry::{FromParams, Param};
+use crate::{Close, High, Lookback, Low, Next, Num, Peek, Reset, Volume};
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
 /// Volume Weighted Average Price (VWAP).
 ///
+/// VWAP is accumulated from the first input. To anchor it at a particular bar, call
+/// [reset](../../trait.Reset.html#tymethod.reset) before feeding the bar. To anchor it at every
+/// session, day or week, wrap it with [SessionReset](../../struct.SessionReset.html).
+/// See also [VWAP with standard deviation bands](struct.VolumeWeightedAveragePriceBands.html).
 ///
 /// # Links
 ///
-/// * [On Balance Volume, Wikipedia](https://en.wikipedia.org/wiki/Volume-weighted_average_price)
-
-#[doc(alias = "OBV")]
+/// * [Volume-weighted average price, Wikipedia](https://en.wikipedia.org/wiki/Volume-weighted_average_price)
+#[doc(alias = "VWAP")]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[derive(Debug, Clone)]
-pub struct VolumeWeightedAveragePrice {
-    accumulated_price_volume: f64,
-    accumulated_volume: f64,
+pub struct VolumeWeightedAveragePrice<N = f64> {
+    accumulated_price_volume: N,
+    accumulated_volume: N,
+    last_price_volume: N,
+    last_volume: N,
+    count: usize,
 }
 
-impl VolumeWeightedAveragePrice {
+impl<N: Num> VolumeWeightedAveragePrice<N> {
     pub fn new() -> Self {
         Self {
-            accumulated_price_volume: 0.0,
-            accumulated_volume: 0.0,
+            accumulated_price_volume: N::zero(),
+            accumulated_volume: N::zero(),
+            last_price_volume: N::zero(),
+            last_volume: N::zero(),
+            count: 0,
+        }
+    }
+
+    fn price_volume<T: High<N> + Low<N> + Close<N> + Volume<N>>(input: &T) -> N {
+        ((input.high() + input.low() + input.close()) / N::from_f64(3.0)) * input.volume()
+    }
+
+    fn vwap(price_volume: N, volume: N) -> N {
+        if volume.abs() < N::from_f64(0.0001) {
+            return price_volume;
         }
+
+        price_volume / volume
     }
 }
 
-impl<T: High + Low + Close + Volume> Next<&T> for VolumeWeightedAveragePrice {
-    type Output = f64;
+impl<N: Num> Lookback for VolumeWeightedAveragePrice<N> {
+    fn lookback(&self) -> usize {
+        1
+    }
 
-    fn next(&mut self, input: &T) -> f64 {
-        let pv = ((input.high() + input.low() + input.close()) / 3.0) * input.volume();
+    fn is_ready(&self) -> bool {
+        self.count == self.lookback()
+    }
+}
+
+impl<N: Num, T: High<N> + Low<N> + Close<N> + Volume<N>> Next<&T>
+    for VolumeWeightedAveragePrice<N>
+{
+    type Output = N;
+
+    fn next(&mut self, input: &T) -> N {
+        let pv = Self::price_volume(input);
 
         self.accumulated_price_volume += pv;
         self.accumulated_volume += input.volume();
+        self.last_price_volume = pv;
+        self.last_volume = input.volume();
+        if self.count < self.lookback() {
+            self.count += 1;
+        }
 
-        if self.accumulated_volume.abs() < 0.0001 {
-            return self.accumulated_price_volume;
+        Self::vwap(self.accumulated_price_volume, self.accumulated_volume)
+    }
+}
+
+impl<N: Num, T: High<N> + Low<N> + Close<N> + Volume<N>> Peek<&T>
+    for VolumeWeightedAveragePrice<N>
+{
+    fn peek(&self, input: &T) -> N {
+        Self::vwap(
+            self.accumulated_price_volume + Self::price_volume(input),
+            self.accumulated_volume + input.volume(),
+        )
+    }
+
+    fn update_last(&mut self, input: &T) -> N {
+        if self.count == 0 {
+            return self.next(input);
         }
 
-        self.accumulated_price_volume / self.accumulated_volume
+        let pv = Self::price_volume(input);
+        self.accumulated_price_volume += pv - self.last_price_volume;
+        self.accumulated_volume += input.volume() - self.last_volume;
+        self.last_price_volume = pv;
+        self.last_volume = input.volume();
+
+        Self::vwap(self.accumulated_price_volume, self.accumulated_volume)
     }
 }
 
-impl Default for VolumeWeightedAveragePrice {
+impl_next_batch!(VolumeWeightedAveragePrice, High + Low + Close + Volume => N);
+
+impl<N: Num> Default for VolumeWeightedAveragePrice<N> {
     fn default() -> Self {
         Self::new()
     }
 }
 
-impl fmt::Display for VolumeWeightedAveragePrice {
+impl<N: Num> fmt::Display for VolumeWeightedAveragePrice<N> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "OBV")
+        write!(f, "VWAP")
     }
 }
 
-impl Reset for VolumeWeightedAveragePrice {
+impl<N: Num> Reset for VolumeWeightedAveragePrice<N> {
     fn reset(&mut self) {
-        self.accumulated_price_volume = 0.0;
-        self.accumulated_volume = 0.0;
+        self.accumulated_price_volume = N::zero();
+        self.accumulated_volume = N::zero();
+        self.last_price_volume = N::zero();
+        self.last_volume = N::zero();
+        self.count = 0;
     }
 }
 
+impl<N: Num> FromParams for VolumeWeightedAveragePrice<N> {
+    const NAME: &'static str = "VWAP";
+
+    fn from_params(params: &[Param]) -> Result<Self> {
+        match *params {
+            [] => Ok(Self::new()),
+            _ => Err(TaError::InvalidParameter),
+        }
+    }
+}
+
+impl_from_str!(VolumeWeightedAveragePrice);
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::indicators::VolumeWeightedAveragePrice;
     use crate::test_helper::*;
 
     #[test]
@@ -106,6 +186,13 @@ mod tests {
         assert!((result - 1.27).abs() < 0.0001);
     }
 
+    #[test]
+    fn test_peek() {
+        let bars = peek_bars();
+        let bars: Vec<&Bar> = bars.iter().collect();
+        assert_peek(VolumeWeightedAveragePrice::new(), &bars);
+    }
+
     #[test]
     fn test_reset() {
         let mut vwap = VolumeWeightedAveragePrice::new();
@@ -120,6 +207,20 @@ mod tests {
         assert_eq!(result, (1.3 + 0.8 + 1.1) / 3.0);
     }
 
+    #[test]
+    fn test_lookback() {
+        let mut vwap = VolumeWeightedAveragePrice::new();
+        assert_eq!(vwap.lookback(), 1);
+        assert!(!vwap.is_ready());
+
+        let bar = Bar::new().high(1.3).low(0.8).close(1.1).volume(100.0);
+        vwap.next(&bar);
+        assert!(vwap.is_ready());
+
+        vwap.reset();
+        assert!(!vwap.is_ready());
+    }
+
     #[test]
     fn test_default() {
         VolumeWeightedAveragePrice::default();
@@ -127,7 +228,7 @@ mod tests {
 
     #[test]
     fn test_display() {
-        let obv = VolumeWeightedAveragePrice::new();
-        assert_eq!(format!("{}", obv), "OBV");
+        let vwap = VolumeWeightedAveragePrice::new();
+        assert_eq!(format!("{}", vwap), "VWAP");
     }
 }
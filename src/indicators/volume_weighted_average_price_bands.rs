@@ -0,0 +1,415 @@
+use core::fmt;
+
+use crate::errors::{Result, TaError};
+use crate::registry::{FromParams, Param};
+use crate::{Close, High, Lookback, Low, Next, Num, Peek, Reset, Volume};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Volume Weighted Average Price (VWAP) with standard deviation bands.
+///
+/// Same as [VWAP](struct.VolumeWeightedAveragePrice.html), but it also returns the volume
+/// weighted standard deviation of the typical price and the bands at 1, 2 and 3 standard
+/// deviations above and below VWAP.
+///
+/// # Formula
+///
+/// VWAP = Σ(_tp_ × _v_) / Σ(_v_)
+///
+/// SD = √(Σ(_v_ × (_tp_ - VWAP)<sup>2</sup>) / Σ(_v_))
+///
+/// Upper<sub>n</sub> = VWAP + _n_ × SD
+///
+/// Lower<sub>n</sub> = VWAP - _n_ × SD
+///
+/// Where:
+///
+/// * _tp_ - typical price of a bar, (high + low + close) / 3
+/// * _v_ - volume of a bar
+/// * _n_ - 1, 2 or 3
+///
+/// Until there is any volume, VWAP is the typical price of the last bar and SD is 0.
+///
+/// The sum of squared deviations is updated with the weighted version of Welford's algorithm
+/// (West, 1979), which stays accurate for high prices with a small spread, unlike the sum of
+/// squared prices.
+///
+/// # Anchoring
+///
+/// VWAP is accumulated from the first input. To anchor it at a particular bar, call
+/// [reset](../../trait.Reset.html#tymethod.reset) before feeding the bar. To anchor it at every
+/// session, day or week, wrap it with [SessionReset](../../struct.SessionReset.html).
+///
+/// # Example
+///
+/// ```
+/// use ta::indicators::VolumeWeightedAveragePriceBands;
+/// use ta::{DataItem, Next};
+///
+/// let item = |close: f64, volume: f64| {
+///     DataItem::builder()
+///         .open(close)
+///         .high(close)
+///         .low(close)
+///         .close(close)
+///         .volume(volume)
+///         .build()
+///         .unwrap()
+/// };
+///
+/// let mut vwap = VolumeWeightedAveragePriceBands::new();
+/// vwap.next(&item(10.0, 100.0));
+/// let out = vwap.next(&item(20.0, 100.0));
+/// assert_eq!(out.vwap, 15.0);
+/// assert_eq!(out.sd, 5.0);
+/// assert_eq!(out.upper_2, 25.0);
+/// assert_eq!(out.lower_1, 10.0);
+/// ```
+///
+/// # Links
+///
+/// * [Volume-weighted average price, Wikipedia](https://en.wikipedia.org/wiki/Volume-weighted_average_price)
+///
+#[doc(alias = "VWAP")]
+#[doc(alias = "Anchored VWAP")]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone)]
+pub struct VolumeWeightedAveragePriceBands<N = f64> {
+    state: State<N>,
+    prev_state: State<N>,
+    count: usize,
+}
+
+// Sums over the bars fed so far, `prev_state` is the one before the last bar.
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, Copy)]
+struct State<N> {
+    price_volume: N,
+    volume: N,
+    // sum of volume weighted squared deviations of the typical price from VWAP
+    m2: N,
+}
+
+impl<N: Num> State<N> {
+    fn new() -> Self {
+        Self {
+            price_volume: N::zero(),
+            volume: N::zero(),
+            m2: N::zero(),
+        }
+    }
+
+    fn vwap(&self) -> N {
+        self.price_volume / self.volume
+    }
+
+    // Returns the state after a bar with the given typical price and volume.
+    fn add(&self, tp: N, volume: N) -> Self {
+        let mut state = Self {
+            price_volume: self.price_volume + tp * volume,
+            volume: self.volume + volume,
+            m2: self.m2,
+        };
+        if volume != N::zero() && self.volume != N::zero() {
+            let m2 = self.m2 + volume * (tp - self.vwap()) * (tp - state.vwap());
+            state.m2 = m2.max(N::zero());
+        }
+        state
+    }
+}
+
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, PartialEq)]
+pub struct VolumeWeightedAveragePriceBandsOutput<N = f64> {
+    pub vwap: N,
+    pub sd: N,
+    pub upper_1: N,
+    pub lower_1: N,
+    pub upper_2: N,
+    pub lower_2: N,
+    pub upper_3: N,
+    pub lower_3: N,
+}
+
+impl_fields!(VolumeWeightedAveragePriceBandsOutput {
+    vwap,
+    sd,
+    upper_1,
+    lower_1,
+    upper_2,
+    lower_2,
+    upper_3,
+    lower_3
+});
+
+impl<N: Num> VolumeWeightedAveragePriceBands<N> {
+    pub fn new() -> Self {
+        Self {
+            state: State::new(),
+            prev_state: State::new(),
+            count: 0,
+        }
+    }
+
+    // Returns the state after the given bar.
+    fn typical_price<T: High<N> + Low<N> + Close<N>>(input: &T) -> N {
+        (input.high() + input.low() + input.close()) / N::from_f64(3.0)
+    }
+
+    // Returns the bands of the given state, or the typical price of the last bar without
+    // any deviation, when there is no volume yet.
+    fn bands(state: &State<N>, tp: N) -> VolumeWeightedAveragePriceBandsOutput<N> {
+        let (vwap, sd) = if state.volume == N::zero() {
+            (tp, N::zero())
+        } else {
+            (state.vwap(), (state.m2 / state.volume).sqrt())
+        };
+        let (sd2, sd3) = (N::from_f64(2.0) * sd, N::from_f64(3.0) * sd);
+
+        VolumeWeightedAveragePriceBandsOutput {
+            vwap,
+            sd,
+            upper_1: vwap + sd,
+            lower_1: vwap - sd,
+            upper_2: vwap + sd2,
+            lower_2: vwap - sd2,
+            upper_3: vwap + sd3,
+            lower_3: vwap - sd3,
+        }
+    }
+}
+
+impl<N: Num> Lookback for VolumeWeightedAveragePriceBands<N> {
+    fn lookback(&self) -> usize {
+        1
+    }
+
+    fn is_ready(&self) -> bool {
+        self.count == self.lookback()
+    }
+}
+
+impl<N: Num, T: High<N> + Low<N> + Close<N> + Volume<N>> Next<&T>
+    for VolumeWeightedAveragePriceBands<N>
+{
+    type Output = VolumeWeightedAveragePriceBandsOutput<N>;
+
+    fn next(&mut self, input: &T) -> Self::Output {
+        self.prev_state = self.state;
+        let tp = Self::typical_price(input);
+        self.state = self.state.add(tp, input.volume());
+        if self.count < self.lookback() {
+            self.count += 1;
+        }
+
+        Self::bands(&self.state, tp)
+    }
+}
+
+impl_next_batch!(VolumeWeightedAveragePriceBands, High + Low + Close + Volume => VolumeWeightedAveragePriceBandsOutput<N>);
+
+impl<N: Num, T: High<N> + Low<N> + Close<N> + Volume<N>> Peek<&T>
+    for VolumeWeightedAveragePriceBands<N>
+{
+    fn peek(&self, input: &T) -> Self::Output {
+        let tp = Self::typical_price(input);
+        Self::bands(&self.state.add(tp, input.volume()), tp)
+    }
+
+    fn update_last(&mut self, input: &T) -> Self::Output {
+        if self.count == 0 {
+            return self.next(input);
+        }
+
+        let tp = Self::typical_price(input);
+        self.state = self.prev_state.add(tp, input.volume());
+        Self::bands(&self.state, tp)
+    }
+}
+
+impl<N: Num> Default for VolumeWeightedAveragePriceBands<N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<N: Num> fmt::Display for VolumeWeightedAveragePriceBands<N> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "VWAP_BANDS")
+    }
+}
+
+impl<N: Num> Reset for VolumeWeightedAveragePriceBands<N> {
+    fn reset(&mut self) {
+        self.state = State::new();
+        self.prev_state = State::new();
+        self.count = 0;
+    }
+}
+
+impl<N: Num> FromParams for VolumeWeightedAveragePriceBands<N> {
+    const NAME: &'static str = "VWAP_BANDS";
+
+    fn from_params(params: &[Param]) -> Result<Self> {
+        match *params {
+            [] => Ok(Self::new()),
+            _ => Err(TaError::InvalidParameter),
+        }
+    }
+}
+
+impl_from_str!(VolumeWeightedAveragePriceBands);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::indicators::VolumeWeightedAveragePrice;
+    use crate::indicators::VolumeWeightedAveragePriceBands;
+    use crate::test_helper::*;
+    use crate::SessionReset;
+
+    #[test]
+    fn test_next_bar() {
+        let mut vwap = VolumeWeightedAveragePriceBands::new();
+
+        let bar1 = Bar::new().high(12).low(9).close(9).volume(100.0);
+        let out = vwap.next(&bar1);
+        assert_eq!(out.vwap, 10.0);
+        assert_eq!(out.sd, 0.0);
+        assert_eq!(out.upper_3, 10.0);
+
+        let bar2 = Bar::new().high(14).low(12).close(13).volume(300.0);
+        let out = vwap.next(&bar2);
+        assert_eq!(out.vwap, 12.25);
+        assert_eq!(round(out.sd), 1.299);
+        assert_eq!(round(out.upper_1), 13.549);
+        assert_eq!(round(out.lower_1), 10.951);
+        assert_eq!(round(out.upper_2), 14.848);
+        assert_eq!(round(out.lower_2), 9.652);
+        assert_eq!(round(out.upper_3), 16.147);
+        assert_eq!(round(out.lower_3), 8.353);
+
+        // zero volume doesn't change anything
+        let bar3 = Bar::new().high(20).low(20).close(20).volume(0.0);
+        assert_eq!(vwap.next(&bar3), out);
+    }
+
+    #[test]
+    fn test_next_high_price_small_spread() {
+        let mut vwap = VolumeWeightedAveragePriceBands::new();
+
+        let mut out = None;
+        for i in 0..10_000 {
+            let price = if i % 2 == 0 { 50000.01 } else { 49999.99 };
+            let bar = Bar::new().high(price).low(price).close(price).volume(1e6);
+            out = Some(vwap.next(&bar));
+        }
+
+        let out = out.unwrap();
+        assert_eq!(round(out.vwap), 50000.0);
+        assert!((out.sd - 0.01).abs() < 1e-9, "{}", out.sd);
+    }
+
+    #[test]
+    fn test_next_small_volume() {
+        let mut vwap = VolumeWeightedAveragePriceBands::new();
+
+        let bar1 = Bar::new().high(10).low(10).close(10).volume(1e-5);
+        let out = vwap.next(&bar1);
+        assert_eq!(round(out.vwap), 10.0);
+        assert_eq!(out.sd, 0.0);
+
+        let bar2 = Bar::new().high(20).low(20).close(20).volume(3e-5);
+        let out = vwap.next(&bar2);
+        assert_eq!(round(out.vwap), 17.5);
+        assert_eq!(round(out.sd), 4.33);
+    }
+
+    #[test]
+    fn test_next_no_volume() {
+        let mut vwap = VolumeWeightedAveragePriceBands::new();
+
+        let bar1 = Bar::new().high(12).low(9).close(9).volume(0.0);
+        let out = vwap.next(&bar1);
+        assert_eq!(out.vwap, 10.0);
+        assert_eq!(out.sd, 0.0);
+        assert_eq!(out.upper_3, 10.0);
+
+        let bar2 = Bar::new().high(20).low(20).close(20).volume(0.0);
+        assert_eq!(vwap.peek(&bar2).vwap, 20.0);
+        assert_eq!(vwap.next(&bar2).vwap, 20.0);
+    }
+
+    #[test]
+    fn test_next_same_as_vwap() {
+        let mut bands = VolumeWeightedAveragePriceBands::new();
+        let mut vwap = VolumeWeightedAveragePrice::new();
+
+        for bar in peek_bars().iter() {
+            assert_eq!(bands.next(bar).vwap, vwap.next(bar));
+        }
+    }
+
+    #[test]
+    fn test_next_session() {
+        const DAY: i64 = 24 * 60 * 60 * 1000;
+        let mut vwap = SessionReset::daily(VolumeWeightedAveragePriceBands::new());
+
+        let bar1 = Bar::new().high(10).low(10).close(10).volume(100.0);
+        let bar2 = Bar::new().high(20).low(20).close(20).volume(100.0);
+        assert_eq!(vwap.next(&bar1.timestamp(DAY)).vwap, 10.0);
+        assert_eq!(vwap.next(&bar2.timestamp(DAY + 1)).sd, 5.0);
+
+        let bar3 = Bar::new().high(30).low(30).close(30).volume(100.0);
+        let out = vwap.next(&bar3.timestamp(2 * DAY));
+        assert_eq!(out.vwap, 30.0);
+        assert_eq!(out.sd, 0.0);
+    }
+
+    #[test]
+    fn test_peek() {
+        let bars = peek_bars();
+        let bars: Vec<&Bar> = bars.iter().collect();
+        assert_peek(VolumeWeightedAveragePriceBands::new(), &bars);
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut vwap = VolumeWeightedAveragePriceBands::new();
+
+        let bar1 = Bar::new().high(10).low(10).close(10).volume(100.0);
+        let bar2 = Bar::new().high(20).low(20).close(20).volume(100.0);
+        vwap.next(&bar1);
+        assert_eq!(vwap.next(&bar2).vwap, 15.0);
+
+        // anchor at bar2
+        vwap.reset();
+        let out = vwap.next(&bar2);
+        assert_eq!(out.vwap, 20.0);
+        assert_eq!(out.sd, 0.0);
+    }
+
+    #[test]
+    fn test_lookback() {
+        let mut vwap = VolumeWeightedAveragePriceBands::new();
+        assert_eq!(vwap.lookback(), 1);
+        assert!(!vwap.is_ready());
+
+        vwap.next(&Bar::new().high(1).low(1).close(1).volume(10.0));
+        assert!(vwap.is_ready());
+
+        vwap.reset();
+        assert!(!vwap.is_ready());
+    }
+
+    #[test]
+    fn test_default() {
+        VolumeWeightedAveragePriceBands::default();
+    }
+
+    #[test]
+    fn test_display() {
+        let vwap = VolumeWeightedAveragePriceBands::new();
+        assert_eq!(format!("{}", vwap), "VWAP_BANDS");
+    }
+}
@@ -0,0 +1,227 @@
+use core::fmt;
+
+use crate::errors::{Result, TaError};
+use crate::indicators::volume_weighted_window::VolumeWeightedWindow;
+use crate::registry::{FromParams, Param};
+use crate::{Close, Lookback, Next, Num, Peek, Period, Reset, Volume};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Volume weighted moving average (VWMA).
+///
+/// The average of close prices over the last _period_ bars, where every close is weighted by
+/// the volume of its bar.
+///
+/// # Formula
+///
+/// VWMA = Σ(_close_ × _volume_) / Σ(_volume_)
+///
+/// Where the sums are taken over the last _period_ bars. When the total volume of the window
+/// is 0, the close price of the last bar is returned.
+///
+/// # Parameters
+///
+/// * _period_ - number of periods (integer greater than 0). Default is 20.
+///
+/// # Example
+///
+/// ```
+/// use ta::indicators::VolumeWeightedMovingAverage;
+/// use ta::{DataItem, Next};
+///
+/// let item = |close: f64, volume: f64| {
+///     DataItem::builder()
+///         .open(close)
+///         .high(close)
+///         .low(close)
+///         .close(close)
+///         .volume(volume)
+///         .build()
+///         .unwrap()
+/// };
+///
+/// let mut vwma = VolumeWeightedMovingAverage::new(2).unwrap();
+/// assert_eq!(vwma.next(&item(10.0, 100.0)), 10.0);
+/// assert_eq!(vwma.next(&item(20.0, 300.0)), 17.5);
+/// assert_eq!(vwma.next(&item(30.0, 100.0)), 22.5);
+/// ```
+///
+/// # Links
+///
+/// * [Volume Weighted Moving Average, TradingView](https://www.tradingview.com/support/solutions/43000592293-volume-weighted-moving-average-vwma/)
+///
+#[doc(alias = "VWMA")]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone)]
+pub struct VolumeWeightedMovingAverage<N = f64> {
+    window: VolumeWeightedWindow<N>,
+}
+
+impl<N: Num> VolumeWeightedMovingAverage<N> {
+    pub fn new(period: usize) -> Result<Self> {
+        Ok(Self {
+            window: VolumeWeightedWindow::new(period)?,
+        })
+    }
+}
+
+impl<N: Num> Period for VolumeWeightedMovingAverage<N> {
+    fn period(&self) -> usize {
+        self.window.period()
+    }
+}
+
+impl<N: Num> Lookback for VolumeWeightedMovingAverage<N> {
+    fn lookback(&self) -> usize {
+        self.window.period()
+    }
+
+    fn is_ready(&self) -> bool {
+        self.window.count() == self.window.period()
+    }
+}
+
+impl<N: Num, T: Close<N> + Volume<N>> Next<&T> for VolumeWeightedMovingAverage<N> {
+    type Output = N;
+
+    fn next(&mut self, input: &T) -> Self::Output {
+        let price = input.close();
+        self.window.next(price, input.volume()).unwrap_or(price)
+    }
+}
+
+impl_next_batch!(VolumeWeightedMovingAverage, Close + Volume => N);
+
+impl<N: Num, T: Close<N> + Volume<N>> Peek<&T> for VolumeWeightedMovingAverage<N> {
+    fn peek(&self, input: &T) -> Self::Output {
+        let price = input.close();
+        self.window.peek(price, input.volume()).unwrap_or(price)
+    }
+
+    fn update_last(&mut self, input: &T) -> Self::Output {
+        if self.window.count() == 0 {
+            return self.next(input);
+        }
+
+        let price = input.close();
+        self.window
+            .update_last(price, input.volume())
+            .unwrap_or(price)
+    }
+}
+
+impl<N: Num> Reset for VolumeWeightedMovingAverage<N> {
+    fn reset(&mut self) {
+        self.window.reset();
+    }
+}
+
+impl<N: Num> Default for VolumeWeightedMovingAverage<N> {
+    fn default() -> Self {
+        Self::new(20).unwrap()
+    }
+}
+
+impl<N: Num> fmt::Display for VolumeWeightedMovingAverage<N> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "VWMA({})", self.period())
+    }
+}
+
+impl<N: Num> FromParams for VolumeWeightedMovingAverage<N> {
+    const NAME: &'static str = "VWMA";
+
+    fn from_params(params: &[Param]) -> Result<Self> {
+        match *params {
+            [period] => Self::new(period.period()?),
+            _ => Err(TaError::InvalidParameter),
+        }
+    }
+}
+
+impl_from_str!(VolumeWeightedMovingAverage);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::indicators::VolumeWeightedMovingAverage;
+    use crate::test_helper::*;
+
+    fn bar(close: f64, volume: f64) -> Bar {
+        Bar::new().close(close).volume(volume)
+    }
+
+    #[test]
+    fn test_new() {
+        assert!(VolumeWeightedMovingAverage::new(0).is_err());
+        assert!(VolumeWeightedMovingAverage::new(1).is_ok());
+    }
+
+    #[test]
+    fn test_next() {
+        let mut vwma = VolumeWeightedMovingAverage::new(3).unwrap();
+
+        assert_eq!(vwma.next(&bar(4.0, 100.0)), 4.0);
+        assert_eq!(vwma.next(&bar(8.0, 300.0)), 7.0);
+        assert_eq!(vwma.next(&bar(2.0, 400.0)), 4.5);
+        assert_eq!(vwma.next(&bar(6.0, 100.0)), 4.75);
+        assert_eq!(vwma.next(&bar(3.0, 0.0)), 2.8);
+        assert_eq!(vwma.next(&bar(5.0, 0.0)), 6.0);
+        assert_eq!(vwma.next(&bar(7.0, 0.0)), 7.0);
+    }
+
+    #[test]
+    fn test_next_no_volume_after_rounding_errors() {
+        let mut vwma = VolumeWeightedMovingAverage::new(2).unwrap();
+
+        vwma.next(&bar(10.0, 0.1));
+        vwma.next(&bar(20.0, 0.2));
+        vwma.next(&bar(30.0, 0.0));
+        // the running sum of the volume isn't exactly 0 here
+        assert_eq!(vwma.next(&bar(40.0, 0.0)), 40.0);
+        assert_eq!(vwma.peek(&bar(50.0, 0.0)), 50.0);
+        assert_eq!(vwma.update_last(&bar(45.0, 0.0)), 45.0);
+    }
+
+    #[test]
+    fn test_peek() {
+        let bars = peek_bars();
+        let bars: Vec<&Bar> = bars.iter().collect();
+        assert_peek(VolumeWeightedMovingAverage::new(3).unwrap(), &bars);
+        assert_peek(VolumeWeightedMovingAverage::new(1).unwrap(), &bars);
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut vwma = VolumeWeightedMovingAverage::new(3).unwrap();
+
+        assert_eq!(vwma.next(&bar(4.0, 100.0)), 4.0);
+        assert_eq!(vwma.next(&bar(8.0, 300.0)), 7.0);
+
+        vwma.reset();
+        assert_eq!(vwma.next(&bar(2.0, 400.0)), 2.0);
+        assert_eq!(vwma.next(&bar(6.0, 100.0)), 2.8);
+    }
+
+    #[test]
+    fn test_lookback() {
+        let mut vwma = VolumeWeightedMovingAverage::new(2).unwrap();
+        assert_eq!(vwma.lookback(), 2);
+
+        vwma.next(&bar(4.0, 100.0));
+        assert!(!vwma.is_ready());
+        vwma.next(&bar(4.0, 100.0));
+        assert!(vwma.is_ready());
+    }
+
+    #[test]
+    fn test_default() {
+        VolumeWeightedMovingAverage::default();
+    }
+
+    #[test]
+    fn test_display() {
+        let vwma = VolumeWeightedMovingAverage::new(14).unwrap();
+        assert_eq!(format!("{}", vwma), "VWMA(14)");
+    }
+}
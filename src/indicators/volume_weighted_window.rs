@@ -0,0 +1,171 @@
+use alloc::boxed::Box;
+use alloc::vec;
+
+use crate::errors::{Result, TaError};
+use crate::Num;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Volume weighted average of values over the last _period_ bars.
+///
+/// The common part of [VWMA](struct.VolumeWeightedMovingAverage.html) and the
+/// [rolling VWAP](struct.RollingVolumeWeightedAveragePrice.html), which differ in the price
+/// they average only, and [CMF](struct.ChaikinMoneyFlow.html), which averages the close
+/// location values.
+///
+/// The sums are updated by adding the new bar and subtracting the one, which leaves the
+/// window, so they drift away from 0 through rounding errors, even if all the volumes of the
+/// window are 0. The number of non-zero volumes tells when there is no volume in the window
+/// instead, and the sums are set back to exactly 0 then.
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone)]
+pub(crate) struct VolumeWeightedWindow<N> {
+    period: usize,
+    index: usize,
+    count: usize,
+    non_zero_count: usize,
+    sum_value_volume: N,
+    sum_volume: N,
+    value_volume: Box<[N]>,
+    volume: Box<[N]>,
+}
+
+impl<N: Num> VolumeWeightedWindow<N> {
+    pub fn new(period: usize) -> Result<Self> {
+        match period {
+            0 => Err(TaError::InvalidParameter),
+            _ => Ok(Self {
+                period,
+                index: 0,
+                count: 0,
+                non_zero_count: 0,
+                sum_value_volume: N::zero(),
+                sum_volume: N::zero(),
+                value_volume: vec![N::zero(); period].into_boxed_slice(),
+                volume: vec![N::zero(); period].into_boxed_slice(),
+            }),
+        }
+    }
+
+    pub fn period(&self) -> usize {
+        self.period
+    }
+
+    pub fn count(&self) -> usize {
+        self.count
+    }
+
+    /// Adds a bar and returns the average, or `None` when the window has no volume.
+    pub fn next(&mut self, value: N, volume: N) -> Option<N> {
+        self.replace(self.index, value, volume);
+
+        self.index = if self.index + 1 < self.period {
+            self.index + 1
+        } else {
+            0
+        };
+
+        if self.count < self.period {
+            self.count += 1;
+        }
+
+        self.average()
+    }
+
+    /// Returns the average `next` would return, without changing the window.
+    pub fn peek(&self, value: N, volume: N) -> Option<N> {
+        let non_zero_count = self.non_zero_count + Self::is_non_zero(volume)
+            - Self::is_non_zero(self.volume[self.index]);
+        if non_zero_count == 0 {
+            return None;
+        }
+
+        Some(
+            (self.sum_value_volume + value * volume - self.value_volume[self.index])
+                / (self.sum_volume + volume - self.volume[self.index]),
+        )
+    }
+
+    /// Replaces the last bar, the window must not be empty.
+    pub fn update_last(&mut self, value: N, volume: N) -> Option<N> {
+        let last = if self.index == 0 {
+            self.period - 1
+        } else {
+            self.index - 1
+        };
+        self.replace(last, value, volume);
+        self.average()
+    }
+
+    pub fn reset(&mut self) {
+        self.index = 0;
+        self.count = 0;
+        self.non_zero_count = 0;
+        self.sum_value_volume = N::zero();
+        self.sum_volume = N::zero();
+        for i in 0..self.period {
+            self.value_volume[i] = N::zero();
+            self.volume[i] = N::zero();
+        }
+    }
+
+    fn replace(&mut self, index: usize, value: N, volume: N) {
+        let value_volume = value * volume;
+
+        self.non_zero_count =
+            self.non_zero_count + Self::is_non_zero(volume) - Self::is_non_zero(self.volume[index]);
+        if self.non_zero_count == 0 {
+            self.sum_value_volume = N::zero();
+            self.sum_volume = N::zero();
+        } else {
+            self.sum_value_volume += value_volume - self.value_volume[index];
+            self.sum_volume += volume - self.volume[index];
+        }
+
+        self.value_volume[index] = value_volume;
+        self.volume[index] = volume;
+    }
+
+    fn average(&self) -> Option<N> {
+        if self.non_zero_count == 0 {
+            None
+        } else {
+            Some(self.sum_value_volume / self.sum_volume)
+        }
+    }
+
+    fn is_non_zero(volume: N) -> usize {
+        (volume != N::zero()) as usize
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helper::*;
+
+    #[test]
+    fn test_next() {
+        let mut window = VolumeWeightedWindow::new(2).unwrap();
+
+        assert_eq!(window.next(10.0, 100.0), Some(10.0));
+        assert_eq!(window.next(20.0, 300.0), Some(17.5));
+        assert_eq!(window.next(30.0, 0.0), Some(20.0));
+        assert_eq!(window.next(40.0, 0.0), None);
+        assert_eq!(window.next(50.0, 100.0), Some(50.0));
+    }
+
+    #[test]
+    fn test_no_volume_after_rounding_errors() {
+        let mut window = VolumeWeightedWindow::new(2).unwrap();
+
+        window.next(10.0, 0.1);
+        window.next(20.0, 0.2);
+        assert_eq!(window.peek(30.0, 0.0).map(round), Some(20.0));
+        window.next(30.0, 0.0);
+        assert_eq!(window.peek(40.0, 0.0), None);
+        assert_eq!(window.next(40.0, 0.0), None);
+        assert_eq!(window.update_last(40.0, 0.0), None);
+        assert_eq!(window.next(50.0, 0.1), Some(50.0));
+    }
+}
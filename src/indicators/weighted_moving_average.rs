@@ -1,7 +1,10 @@
-use std::fmt;
+use alloc::boxed::Box;
+use alloc::vec;
+use core::fmt;
 
 use crate::errors::{Result, TaError};
-use crate::{Close, Next, Period, Reset};
+use crate::registry::{FromParams, Param};
+use crate::{Close, Lookback, Next, Num, Peek, Period, Reset};
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
@@ -37,21 +40,20 @@ use serde::{Deserialize, Serialize};
 ///
 /// * [Weighted moving average, Wikipedia](https://en.wikipedia.org/wiki/Moving_average#Weighted_moving_average)
 ///
-
 #[doc(alias = "WMA")]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[derive(Debug, Clone)]
-pub struct WeightedMovingAverage {
+pub struct WeightedMovingAverage<N = f64> {
     period: usize,
     index: usize,
     count: usize,
-    weight: f64,
-    sum: f64,
-    sum_flat: f64,
-    deque: Box<[f64]>,
+    weight: N,
+    sum: N,
+    sum_flat: N,
+    deque: Box<[N]>,
 }
 
-impl WeightedMovingAverage {
+impl<N: Num> WeightedMovingAverage<N> {
     pub fn new(period: usize) -> Result<Self> {
         match period {
             0 => Err(TaError::InvalidParameter),
@@ -59,26 +61,36 @@ impl WeightedMovingAverage {
                 period,
                 index: 0,
                 count: 0,
-                weight: 0.0,
-                sum: 0.0,
-                sum_flat: 0.0,
-                deque: vec![0.0; period].into_boxed_slice(),
+                weight: N::zero(),
+                sum: N::zero(),
+                sum_flat: N::zero(),
+                deque: vec![N::zero(); period].into_boxed_slice(),
             }),
         }
     }
 }
 
-impl Period for WeightedMovingAverage {
+impl<N: Num> Period for WeightedMovingAverage<N> {
     fn period(&self) -> usize {
         self.period
     }
 }
 
-impl Next<f64> for WeightedMovingAverage {
-    type Output = f64;
+impl<N: Num> Lookback for WeightedMovingAverage<N> {
+    fn lookback(&self) -> usize {
+        self.period
+    }
 
-    fn next(&mut self, input: f64) -> Self::Output {
-        let old_val: f64 = self.deque[self.index];
+    fn is_ready(&self) -> bool {
+        self.count == self.period
+    }
+}
+
+impl<N: Num> Next<N> for WeightedMovingAverage<N> {
+    type Output = N;
+
+    fn next(&mut self, input: N) -> Self::Output {
+        let old_val: N = self.deque[self.index];
         self.deque[self.index] = input;
 
         self.index = if self.index + 1 < self.period {
@@ -89,52 +101,112 @@ impl Next<f64> for WeightedMovingAverage {
 
         if self.count < self.period {
             self.count += 1;
-            self.weight = self.count as f64;
+            self.weight = N::from_usize(self.count);
             self.sum += input * self.weight
         } else {
             self.sum = self.sum - self.sum_flat + (input * self.weight);
         }
         self.sum_flat = self.sum_flat - old_val + input;
-        self.sum / (self.weight * (self.weight + 1.0) / 2.0)
+        self.sum / (self.weight * (self.weight + N::one()) / N::from_f64(2.0))
     }
 }
 
-impl<T: Close> Next<&T> for WeightedMovingAverage {
-    type Output = f64;
+impl_next_batch!(WeightedMovingAverage, N => N);
 
-    fn next(&mut self, input: &T) -> Self::Output {
-        self.next(input.close())
+impl<N: Num> Peek<N> for WeightedMovingAverage<N> {
+    fn peek(&self, input: N) -> Self::Output {
+        let (weight, sum) = if self.count < self.period {
+            let weight = N::from_usize(self.count + 1);
+            (weight, self.sum + input * weight)
+        } else {
+            (self.weight, self.sum - self.sum_flat + input * self.weight)
+        };
+        sum / (weight * (weight + N::one()) / N::from_f64(2.0))
+    }
+
+    fn update_last(&mut self, input: N) -> Self::Output {
+        if self.count == 0 {
+            return self.next(input);
+        }
+
+        let last = if self.index == 0 {
+            self.period - 1
+        } else {
+            self.index - 1
+        };
+        let old_val = self.deque[last];
+        self.deque[last] = input;
+
+        self.sum += (input - old_val) * self.weight;
+        self.sum_flat += input - old_val;
+        self.sum / (self.weight * (self.weight + N::one()) / N::from_f64(2.0))
     }
 }
 
-impl Reset for WeightedMovingAverage {
+impl_for_floats! {
+    impl<T: Close<N>> Next<&T> for WeightedMovingAverage<N> {
+        type Output = N;
+
+        fn next(&mut self, input: &T) -> Self::Output {
+            self.next(input.close())
+        }
+    }
+
+    impl_next_batch!(WeightedMovingAverage<N>, Close => N);
+
+    impl<T: Close<N>> Peek<&T> for WeightedMovingAverage<N> {
+        fn peek(&self, input: &T) -> Self::Output {
+            self.peek(input.close())
+        }
+
+        fn update_last(&mut self, input: &T) -> Self::Output {
+            self.update_last(input.close())
+        }
+    }
+}
+
+impl<N: Num> Reset for WeightedMovingAverage<N> {
     fn reset(&mut self) {
         self.index = 0;
         self.count = 0;
-        self.weight = 0.0;
-        self.sum = 0.0;
-        self.sum_flat = 0.0;
+        self.weight = N::zero();
+        self.sum = N::zero();
+        self.sum_flat = N::zero();
         for i in 0..self.period {
-            self.deque[i] = 0.0;
+            self.deque[i] = N::zero();
         }
     }
 }
 
-impl Default for WeightedMovingAverage {
+impl<N: Num> Default for WeightedMovingAverage<N> {
     fn default() -> Self {
         Self::new(9).unwrap()
     }
 }
 
-impl fmt::Display for WeightedMovingAverage {
+impl<N: Num> fmt::Display for WeightedMovingAverage<N> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         write!(f, "WMA({})", self.period)
     }
 }
 
+impl<N: Num> FromParams for WeightedMovingAverage<N> {
+    const NAME: &'static str = "WMA";
+
+    fn from_params(params: &[Param]) -> Result<Self> {
+        match *params {
+            [period] => Self::new(period.period()?),
+            _ => Err(TaError::InvalidParameter),
+        }
+    }
+}
+
+impl_from_str!(WeightedMovingAverage);
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::indicators::WeightedMovingAverage;
     use crate::test_helper::*;
 
     test_indicator!(WeightedMovingAverage);
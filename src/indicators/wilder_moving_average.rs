@@ -0,0 +1,229 @@
+use core::fmt;
+
+use crate::errors::{Result, TaError};
+use crate::registry::{FromParams, Param};
+use crate::{Close, Lookback, Next, Num, Peek, Period, Reset};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Wilder's moving average, also known as running moving average (RMA) or smoothed moving
+/// average (SMMA).
+///
+/// It's the smoothing J. Welles Wilder used for his indicators, such as RSI and ATR. It's an
+/// exponential moving average with _α_ = 1 / _period_, seeded with a simple moving average of
+/// the first _period_ values.
+///
+/// # Formula
+///
+/// RMA<sub>t</sub> = RMA<sub>t-1</sub> + (p<sub>t</sub> - RMA<sub>t-1</sub>) / _period_
+///
+/// Where:
+///
+/// * _RMA<sub>t</sub>_ - value of Wilder's moving average at a point of time _t_
+/// * _p<sub>t</sub>_ - input value at a point of time _t_
+///
+/// Until _period_ values are consumed, a simple average of the consumed values is returned.
+///
+/// # Parameters
+///
+/// * _period_ - number of periods (integer greater than 0). Default is 14.
+///
+/// # Example
+///
+/// ```
+/// use ta::indicators::WilderMovingAverage;
+/// use ta::Next;
+///
+/// let mut rma = WilderMovingAverage::new(3).unwrap();
+/// assert_eq!(rma.next(3.0), 3.0);
+/// assert_eq!(rma.next(6.0), 4.5);
+/// assert_eq!(rma.next(9.0), 6.0);
+/// assert_eq!(rma.next(3.0), 5.0);
+/// ```
+///
+/// # Links
+///
+/// * [Modified moving average, Wikipedia](https://en.wikipedia.org/wiki/Moving_average#Modified_moving_average)
+///
+#[doc(alias = "RMA")]
+#[doc(alias = "SMMA")]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone)]
+pub struct WilderMovingAverage<N = f64> {
+    period: usize,
+    count: usize,
+    current: N,
+    prev: N,
+}
+
+impl<N: Num> WilderMovingAverage<N> {
+    pub fn new(period: usize) -> Result<Self> {
+        match period {
+            0 => Err(TaError::InvalidParameter),
+            _ => Ok(Self {
+                period,
+                count: 0,
+                current: N::zero(),
+                prev: N::zero(),
+            }),
+        }
+    }
+}
+
+impl<N: Num> Period for WilderMovingAverage<N> {
+    fn period(&self) -> usize {
+        self.period
+    }
+}
+
+impl<N: Num> Lookback for WilderMovingAverage<N> {
+    fn lookback(&self) -> usize {
+        self.period
+    }
+
+    fn is_ready(&self) -> bool {
+        self.count == self.period
+    }
+}
+
+impl<N: Num> Next<N> for WilderMovingAverage<N> {
+    type Output = N;
+
+    fn next(&mut self, input: N) -> Self::Output {
+        if self.count < self.period {
+            self.count += 1;
+        }
+        self.prev = self.current;
+        self.current += (input - self.current) / N::from_usize(self.count);
+        self.current
+    }
+}
+
+impl_next_batch!(WilderMovingAverage, N => N);
+
+impl<N: Num> Peek<N> for WilderMovingAverage<N> {
+    fn peek(&self, input: N) -> Self::Output {
+        let count = (self.count + 1).min(self.period);
+        self.current + (input - self.current) / N::from_usize(count)
+    }
+
+    fn update_last(&mut self, input: N) -> Self::Output {
+        if self.count == 0 {
+            return self.next(input);
+        }
+        self.current = self.prev + (input - self.prev) / N::from_usize(self.count);
+        self.current
+    }
+}
+
+impl_for_floats! {
+    impl<T: Close<N>> Next<&T> for WilderMovingAverage<N> {
+        type Output = N;
+
+        fn next(&mut self, input: &T) -> Self::Output {
+            self.next(input.close())
+        }
+    }
+
+    impl_next_batch!(WilderMovingAverage<N>, Close => N);
+
+    impl<T: Close<N>> Peek<&T> for WilderMovingAverage<N> {
+        fn peek(&self, input: &T) -> Self::Output {
+            self.peek(input.close())
+        }
+
+        fn update_last(&mut self, input: &T) -> Self::Output {
+            self.update_last(input.close())
+        }
+    }
+}
+
+impl<N: Num> Reset for WilderMovingAverage<N> {
+    fn reset(&mut self) {
+        self.count = 0;
+        self.current = N::zero();
+        self.prev = N::zero();
+    }
+}
+
+impl<N: Num> Default for WilderMovingAverage<N> {
+    fn default() -> Self {
+        Self::new(14).unwrap()
+    }
+}
+
+impl<N: Num> fmt::Display for WilderMovingAverage<N> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "RMA({})", self.period)
+    }
+}
+
+impl<N: Num> FromParams for WilderMovingAverage<N> {
+    const NAME: &'static str = "RMA";
+
+    fn from_params(params: &[Param]) -> Result<Self> {
+        match *params {
+            [period] => Self::new(period.period()?),
+            _ => Err(TaError::InvalidParameter),
+        }
+    }
+}
+
+impl_from_str!(WilderMovingAverage);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::indicators::WilderMovingAverage;
+    use crate::test_helper::*;
+
+    test_indicator!(WilderMovingAverage);
+
+    #[test]
+    fn test_new() {
+        assert!(WilderMovingAverage::new(0).is_err());
+        assert!(WilderMovingAverage::new(1).is_ok());
+    }
+
+    #[test]
+    fn test_next() {
+        let mut rma = WilderMovingAverage::new(3).unwrap();
+
+        assert_eq!(rma.next(3.0), 3.0);
+        assert_eq!(rma.next(6.0), 4.5);
+        assert_eq!(rma.next(9.0), 6.0);
+        assert_eq!(rma.next(3.0), 5.0);
+        assert_eq!(rma.next(11.0), 7.0);
+
+        let mut rma = WilderMovingAverage::new(3).unwrap();
+        let bar1 = Bar::new().close(3);
+        let bar2 = Bar::new().close(6);
+        assert_eq!(rma.next(&bar1), 3.0);
+        assert_eq!(rma.next(&bar2), 4.5);
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut rma = WilderMovingAverage::new(5).unwrap();
+
+        assert_eq!(rma.next(4.0), 4.0);
+        rma.next(10.0);
+        rma.next(15.0);
+        rma.next(20.0);
+        assert_ne!(rma.next(4.0), 4.0);
+
+        rma.reset();
+        assert_eq!(rma.next(4.0), 4.0);
+    }
+
+    #[test]
+    fn test_default() {
+        WilderMovingAverage::default();
+    }
+
+    #[test]
+    fn test_display() {
+        let rma = WilderMovingAverage::new(7).unwrap();
+        assert_eq!(format!("{}", rma), "RMA(7)");
+    }
+}
@@ -0,0 +1,247 @@
+use core::fmt;
+
+use crate::errors::{Result, TaError};
+use crate::indicators::generic::{Maximum, Minimum};
+use crate::registry::{FromParams, Param};
+use crate::{Close, High, Lookback, Low, Next, Num, Peek, Period, Reset};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Williams %R.
+///
+/// A momentum indicator, which shows where the close price is relative to the highest high of
+/// the period. It is the [fast stochastic](struct.FastStochastic.html) flipped to the range
+/// from -100 to 0: values above -20 are considered overbought, values below -80 oversold.
+///
+/// # Formula
+///
+/// %R = (H<sub>n</sub> - C<sub>t</sub>) / (H<sub>n</sub> - L<sub>n</sub>) × -100
+///
+/// Where:
+///
+/// * C<sub>t</sub> - close price of the current period
+/// * L<sub>n</sub> - lowest price for the last _n_ periods
+/// * H<sub>n</sub> - highest price for the last _n_ periods
+///
+/// When the highest and the lowest prices are equal, %R is -50.
+///
+/// # Parameters
+///
+/// * _period_ - number of periods (integer greater than 0). Default is 14.
+///
+/// # Example
+///
+/// ```
+/// use ta::indicators::WilliamsR;
+/// use ta::Next;
+///
+/// let mut wr = WilliamsR::new(3).unwrap();
+/// assert_eq!(wr.next(0.0), -50.0);
+/// assert_eq!(wr.next(200.0), 0.0);
+/// assert_eq!(wr.next(100.0), -50.0);
+/// assert_eq!(wr.next(120.0), -80.0);
+/// assert_eq!(wr.next(115.0), -25.0);
+/// ```
+///
+/// # Links
+///
+/// * [Williams %R, Wikipedia](https://en.wikipedia.org/wiki/Williams_%25R)
+///
+#[doc(alias = "%R")]
+#[doc(alias = "WR")]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone)]
+pub struct WilliamsR<N = f64> {
+    period: usize,
+    minimum: Minimum<N>,
+    maximum: Maximum<N>,
+}
+
+impl<N: Num> WilliamsR<N> {
+    pub fn new(period: usize) -> Result<Self> {
+        Ok(Self {
+            period,
+            minimum: Minimum::new(period)?,
+            maximum: Maximum::new(period)?,
+        })
+    }
+
+    fn williams_r(close: N, lowest: N, highest: N) -> N {
+        if highest == lowest {
+            N::from_f64(-50.0)
+        } else {
+            (highest - close) / (highest - lowest) * N::from_f64(-100.0)
+        }
+    }
+}
+
+impl<N: Num> Period for WilliamsR<N> {
+    fn period(&self) -> usize {
+        self.period
+    }
+}
+
+impl<N: Num> Lookback for WilliamsR<N> {
+    fn lookback(&self) -> usize {
+        self.period
+    }
+
+    fn is_ready(&self) -> bool {
+        self.maximum.is_ready()
+    }
+}
+
+impl<N: Num> Next<N> for WilliamsR<N> {
+    type Output = N;
+
+    fn next(&mut self, input: N) -> Self::Output {
+        let min = self.minimum.next(input);
+        let max = self.maximum.next(input);
+        Self::williams_r(input, min, max)
+    }
+}
+
+impl_next_batch!(WilliamsR, N => N);
+
+impl<N: Num> Peek<N> for WilliamsR<N> {
+    fn peek(&self, input: N) -> Self::Output {
+        let min = self.minimum.peek(input);
+        let max = self.maximum.peek(input);
+        Self::williams_r(input, min, max)
+    }
+
+    fn update_last(&mut self, input: N) -> Self::Output {
+        let min = self.minimum.update_last(input);
+        let max = self.maximum.update_last(input);
+        Self::williams_r(input, min, max)
+    }
+}
+
+impl_for_floats! {
+    impl<T: High<N> + Low<N> + Close<N>> Next<&T> for WilliamsR<N> {
+        type Output = N;
+
+        fn next(&mut self, input: &T) -> Self::Output {
+            let highest = self.maximum.next(input.high());
+            let lowest = self.minimum.next(input.low());
+            Self::williams_r(input.close(), lowest, highest)
+        }
+    }
+
+    impl_next_batch!(WilliamsR<N>, High + Low + Close => N);
+
+    impl<T: High<N> + Low<N> + Close<N>> Peek<&T> for WilliamsR<N> {
+        fn peek(&self, input: &T) -> Self::Output {
+            let highest = self.maximum.peek(input.high());
+            let lowest = self.minimum.peek(input.low());
+            Self::williams_r(input.close(), lowest, highest)
+        }
+
+        fn update_last(&mut self, input: &T) -> Self::Output {
+            let highest = self.maximum.update_last(input.high());
+            let lowest = self.minimum.update_last(input.low());
+            Self::williams_r(input.close(), lowest, highest)
+        }
+    }
+}
+
+impl<N: Num> Reset for WilliamsR<N> {
+    fn reset(&mut self) {
+        self.minimum.reset();
+        self.maximum.reset();
+    }
+}
+
+impl<N: Num> Default for WilliamsR<N> {
+    fn default() -> Self {
+        Self::new(14).unwrap()
+    }
+}
+
+impl<N: Num> fmt::Display for WilliamsR<N> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "%R({})", self.period)
+    }
+}
+
+impl<N: Num> FromParams for WilliamsR<N> {
+    const NAME: &'static str = "%R";
+
+    fn from_params(params: &[Param]) -> Result<Self> {
+        match *params {
+            [period] => Self::new(period.period()?),
+            _ => Err(TaError::InvalidParameter),
+        }
+    }
+}
+
+impl_from_str!(WilliamsR);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::indicators::WilliamsR;
+    use crate::test_helper::*;
+
+    test_indicator!(WilliamsR);
+
+    #[test]
+    fn test_new() {
+        assert!(WilliamsR::new(0).is_err());
+        assert!(WilliamsR::new(1).is_ok());
+    }
+
+    #[test]
+    fn test_next_with_f64() {
+        let mut wr = WilliamsR::new(3).unwrap();
+        assert_eq!(wr.next(0.0), -50.0);
+        assert_eq!(wr.next(200.0), 0.0);
+        assert_eq!(wr.next(100.0), -50.0);
+        assert_eq!(wr.next(120.0), -80.0);
+        assert_eq!(wr.next(115.0), -25.0);
+    }
+
+    #[test]
+    fn test_next_with_bars() {
+        let test_data = vec![
+            // high, low , close, expected
+            (20.0, 20.0, 20.0, -50.0), // min = 20, max = 20
+            (30.0, 10.0, 25.0, -25.0), // min = 10, max = 30
+            (40.0, 20.0, 16.0, -80.0), // min = 10, max = 40
+            (35.0, 15.0, 19.0, -70.0), // min = 10, max = 40
+            (30.0, 20.0, 25.0, -60.0), // min = 15, max = 40
+            (35.0, 25.0, 30.0, -25.0), // min = 15, max = 35
+        ];
+
+        let mut wr = WilliamsR::new(3).unwrap();
+
+        for (high, low, close, expected) in test_data {
+            let input_bar = Bar::new().high(high).low(low).close(close);
+            assert_eq!(wr.next(&input_bar), expected);
+        }
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut wr = WilliamsR::new(10).unwrap();
+        assert_eq!(wr.next(10.0), -50.0);
+        assert_eq!(wr.next(210.0), 0.0);
+        assert_eq!(wr.next(10.0), -100.0);
+
+        wr.reset();
+        assert_eq!(wr.next(10.0), -50.0);
+        assert_eq!(wr.next(20.0), 0.0);
+        assert_eq!(wr.next(12.5), -75.0);
+    }
+
+    #[test]
+    fn test_default() {
+        WilliamsR::default();
+    }
+
+    #[test]
+    fn test_display() {
+        let wr = WilliamsR::new(21).unwrap();
+        assert_eq!(format!("{}", wr), "%R(21)");
+    }
+}
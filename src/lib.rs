@@ -10,6 +10,33 @@
 //! Since `Next<T>` is a generic trait, most of the indicators can work with both input types: `f64` and more complex
 //! structures like [DataItem](struct.DataItem.html).
 //!
+//! Every indicator also implements [Lookback](trait.Lookback.html), which tells when its output
+//! becomes meaningful. Wrap an indicator with [WarmUp](struct.WarmUp.html) to get `None` until then.
+//!
+//! Indicators can be combined with [Chain](struct.Chain.html), which feeds the output of one
+//! indicator into another one, e.g. to get an SMA of ROC.
+//!
+//! Inputs, which implement [Timestamp](trait.Timestamp.html), can be fed to indicators wrapped
+//! with [SessionReset](struct.SessionReset.html), which start over at every session, e.g. a day.
+//!
+//! [NextBatch<T>](trait.NextBatch.html) feeds a whole slice of inputs at once, which is handy
+//! for backtesting over historical data.
+//!
+//! Indicators calculate with `f64` by default. Their versions in
+//! [indicators::generic](indicators/generic/index.html) work with any [numeric type](trait.Num.html),
+//! e.g. `f32` or a decimal type.
+//!
+//! [Peek<T>](trait.Peek.html) calculates the output for a bar, which is still forming, without
+//! changing the indicator, and replaces the last consumed input, which is handy for live trading.
+//!
+//! Indicators of different types can be stored together as [DynIndicator](trait.DynIndicator.html)
+//! trait objects. [Registry](struct.Registry.html) creates them by name and parameters, e.g. from
+//! a config file.
+//!
+//! Every indicator implements `FromStr`, which parses the string it prints with `Display`, e.g.
+//! `"EMA(9)".parse::<ExponentialMovingAverage>()`. `Box<dyn DynIndicator>` is parsed the same
+//! way with any built-in indicator, see [Registry::parse](struct.Registry.html#method.parse).
+//!
 //! # Example
 //! ```
 //! use ta::indicators::ExponentialMovingAverage;
@@ -30,32 +57,79 @@
 //!   * [Exponential Moving Average (EMA)](crate::indicators::ExponentialMovingAverage)
 //!   * [Simple Moving Average (SMA)](crate::indicators::SimpleMovingAverage)
 //!   * [Weighted Moving Average (WMA)](crate::indicators::WeightedMovingAverage)
+//!   * [Wilder's Moving Average (RMA)](crate::indicators::WilderMovingAverage)
+//!   * [Volume Weighted Moving Average (VWMA)](crate::indicators::VolumeWeightedMovingAverage)
+//!   * [Hull Moving Average (HMA)](crate::indicators::HullMovingAverage)
+//!   * [Kaufman's Adaptive Moving Average (KAMA)](crate::indicators::KaufmanAdaptiveMovingAverage)
+//!   * [Arnaud Legoux Moving Average (ALMA)](crate::indicators::ArnaudLegouxMovingAverage)
+//!   * [Triple Exponential Moving Average (TEMA)](crate::indicators::TripleExponentialMovingAverage)
+//!   * [Ichimoku Cloud](crate::indicators::IchimokuCloud)
+//!   * [Parabolic SAR (PSAR)](crate::indicators::ParabolicSar)
+//!   * [Average Directional Index (ADX)](crate::indicators::AverageDirectionalIndex)
+//!   * [Aroon and Aroon Oscillator](crate::indicators::Aroon)
 //! * Oscillators
-//!   * [Relative Strength Index (RSI)](indicators/struct.RelativeStrengthIndex.html)
-//!   * [Fast Stochastic](indicators/struct.FastStochastic.html)
-//!   * [Slow Stochastic](indicators/struct.SlowStochastic.html)
-//!   * [Moving Average Convergence Divergence (MACD)](indicators/struct.MovingAverageConvergenceDivergence.html)
-//!   * [Percentage Price Oscillator (PPO)](indicators/struct.PercentagePriceOscillator.html)
-//!   * [Commodity Channel Index (CCI)](indicators/struct.CommodityChannelIndex.html)
-//!   * [Money Flow Index (MFI)](indicators/struct.MoneyFlowIndex.html)
+//!   * [Relative Strength Index (RSI)](indicators/generic/struct.RelativeStrengthIndex.html)
+//!   * [Fast Stochastic](indicators/generic/struct.FastStochastic.html)
+//!   * [Slow Stochastic](indicators/generic/struct.SlowStochastic.html)
+//!   * [Full Stochastic](indicators/generic/struct.FullStochastic.html)
+//!   * [Williams %R](indicators/generic/struct.WilliamsR.html)
+//!   * [Ultimate Oscillator (UO)](indicators/generic/struct.UltimateOscillator.html)
+//!   * [Stochastic RSI](indicators/generic/struct.StochasticRsi.html)
+//!   * [Moving Average Convergence Divergence (MACD)](indicators/generic/struct.MovingAverageConvergenceDivergence.html)
+//!   * [Percentage Price Oscillator (PPO)](indicators/generic/struct.PercentagePriceOscillator.html)
+//!   * [Commodity Channel Index (CCI)](indicators/generic/struct.CommodityChannelIndex.html)
+//!   * [Money Flow Index (MFI)](indicators/generic/struct.MoneyFlowIndex.html)
+//!   * [Chaikin Money Flow (CMF)](indicators/generic/struct.ChaikinMoneyFlow.html)
+//!   * [Chaikin Oscillator](indicators/generic/struct.ChaikinOscillator.html)
+//!   * [Percentage Volume Oscillator (PVO)](indicators/generic/struct.PercentageVolumeOscillator.html)
+//!   * [Triple Exponential Average (TRIX)](indicators/generic/struct.TripleExponentialAverage.html)
+//!   * [TRIX with a signal line](indicators/generic/struct.TripleExponentialAverageWithSignal.html)
 //! * Other
-//!   * [Standard Deviation (SD)](indicators/struct.StandardDeviation.html)
-//!   * [Mean Absolute Deviation (MAD)](indicators/struct.MeanAbsoluteDeviation.html)
-//!   * [Bollinger Bands (BB)](indicators/struct.BollingerBands.html)
-//!   * [Chandelier Exit (CE)](indicators/struct.ChandelierExit.html)
-//!   * [Keltner Channel (KC)](indicators/struct.KeltnerChannel.html)
-//!   * [Maximum](indicators/struct.Maximum.html)
-//!   * [Minimum](indicators/struct.Minimum.html)
-//!   * [True Range](indicators/struct.TrueRange.html)
-//!   * [Average True Range (ATR)](indicators/struct.AverageTrueRange.html)
-//!   * [Efficiency Ratio (ER)](indicators/struct.EfficiencyRatio.html)
-//!   * [Rate of Change (ROC)](indicators/struct.RateOfChange.html)
-//!   * [On Balance Volume (OBV)](indicators/struct.OnBalanceVolume.html)
+//!   * [Standard Deviation (SD)](indicators/generic/struct.StandardDeviation.html)
+//!   * [Mean Absolute Deviation (MAD)](indicators/generic/struct.MeanAbsoluteDeviation.html)
+//!   * [Bollinger Bands (BB)](indicators/generic/struct.BollingerBands.html)
+//!   * [Chandelier Exit (CE)](indicators/generic/struct.ChandelierExit.html)
+//!   * [Keltner Channel (KC)](indicators/generic/struct.KeltnerChannel.html)
+//!   * [Maximum](indicators/generic/struct.Maximum.html)
+//!   * [Minimum](indicators/generic/struct.Minimum.html)
+//!   * [True Range](indicators/generic/struct.TrueRange.html)
+//!   * [Average True Range (ATR)](indicators/generic/struct.AverageTrueRange.html)
+//!   * [Efficiency Ratio (ER)](indicators/generic/struct.EfficiencyRatio.html)
+//!   * [Rate of Change (ROC)](indicators/generic/struct.RateOfChange.html)
+//!   * [On Balance Volume (OBV)](indicators/generic/struct.OnBalanceVolume.html)
+//!   * [Accumulation/Distribution line (A/D)](indicators/generic/struct.AccumulationDistribution.html)
+//!   * [Force Index (FI)](indicators/generic/struct.ForceIndex.html)
+//!   * [Ease of Movement (EMV)](indicators/generic/struct.EaseOfMovement.html)
+//!   * [Price Volume Trend (PVT)](indicators/generic/struct.PriceVolumeTrend.html)
+//!   * [Negative Volume Index (NVI)](indicators/generic/struct.NegativeVolumeIndex.html)
+//!   * [Positive Volume Index (PVI)](indicators/generic/struct.PositiveVolumeIndex.html)
+//!   * [Volume Weighted Average Price (VWAP)](indicators/generic/struct.VolumeWeightedAveragePrice.html)
+//!   * [VWAP with standard deviation bands](indicators/generic/struct.VolumeWeightedAveragePriceBands.html)
+//!   * [Rolling VWAP](indicators/generic/struct.RollingVolumeWeightedAveragePrice.html)
+//!
+//! # no_std
+//!
+//! The crate depends on `std` through the default `std` feature only. Without it, the crate is
+//! `no_std` and needs `alloc`, e.g. for embedded or WASM targets:
+//!
+//! ```toml
+//! [dependencies]
+//! ta = { version = "0.5", default-features = false }
+//! ```
+//!
+//! Then the float functions, which are missing in `core` (e.g. `sqrt` of
+//! [StandardDeviation](indicators/generic/struct.StandardDeviation.html)), come from `libm`,
+//! and [TaError](errors/enum.TaError.html) doesn't implement `std::error::Error`.
 //!
+#![cfg_attr(not(any(feature = "std", test)), no_std)]
+
+extern crate alloc;
+
 #[cfg(test)]
 #[macro_use]
 mod test_helper;
 
+#[macro_use]
 mod helpers;
 
 pub mod errors;
@@ -64,5 +138,23 @@ pub mod indicators;
 mod traits;
 pub use crate::traits::*;
 
+mod num;
+pub use crate::num::Num;
+
 mod data_item;
 pub use crate::data_item::DataItem;
+
+mod warm_up;
+pub use crate::warm_up::WarmUp;
+
+mod chain;
+pub use crate::chain::Chain;
+
+mod session_reset;
+pub use crate::session_reset::SessionReset;
+
+mod dyn_indicator;
+pub use crate::dyn_indicator::{DynIndicator, DynOutput};
+
+mod registry;
+pub use crate::registry::{Constructor, Param, Registry};
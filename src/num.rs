@@ -0,0 +1,403 @@
+use core::fmt;
+use core::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+
+/// Numeric type of the values, which indicators consume and return.
+///
+/// It's implemented for `f64`, which is the default of every indicator, and `f32`, which halves
+/// the memory of the indicator state. Implement it for a fixed-point or decimal type to avoid
+/// the rounding of binary floating point numbers. The operations, which such types usually
+/// don't provide (e.g. [sqrt](#method.sqrt)), have default implementations, which go through
+/// `f64`.
+///
+/// The indicators generic over the numeric type are in
+/// [indicators::generic](indicators/generic/index.html), the types with the same names in
+/// [indicators](indicators/index.html) are their `f64` versions.
+///
+/// Indicators, which accept plain numbers as inputs, accept bars (e.g.
+/// [DataItem](struct.DataItem.html)) only with `f64` and `f32` values, since both kinds of
+/// inputs can't be generic over the numeric type at the same time. Indicators, which accept
+/// bars only, work with any numeric type.
+///
+/// # Example
+///
+/// ```
+/// use ta::indicators::generic::SimpleMovingAverage;
+/// use ta::Next;
+///
+/// let mut sma = SimpleMovingAverage::<f32>::new(3).unwrap();
+/// assert_eq!(sma.next(10.0), 10.0);
+/// assert_eq!(sma.next(11.0), 10.5);
+/// assert_eq!(sma.next(12.0), 11.0);
+/// ```
+pub trait Num:
+    Copy
+    + PartialEq
+    + PartialOrd
+    + fmt::Debug
+    + Add<Output = Self>
+    + Sub<Output = Self>
+    + Mul<Output = Self>
+    + Div<Output = Self>
+    + Neg<Output = Self>
+    + AddAssign
+    + SubAssign
+    + MulAssign
+    + DivAssign
+{
+    /// Converts an `f64`, e.g. a constant or a parameter of an indicator.
+    fn from_f64(value: f64) -> Self;
+
+    /// Converts the value to `f64`.
+    fn to_f64(self) -> f64;
+
+    fn zero() -> Self {
+        Self::from_f64(0.0)
+    }
+
+    fn one() -> Self {
+        Self::from_f64(1.0)
+    }
+
+    /// Converts a count, e.g. a period of an indicator.
+    fn from_usize(value: usize) -> Self {
+        Self::from_f64(value as f64)
+    }
+
+    fn abs(self) -> Self {
+        if self < Self::zero() {
+            -self
+        } else {
+            self
+        }
+    }
+
+    fn max(self, other: Self) -> Self {
+        if other > self {
+            other
+        } else {
+            self
+        }
+    }
+
+    fn min(self, other: Self) -> Self {
+        if other < self {
+            other
+        } else {
+            self
+        }
+    }
+
+    fn sqrt(self) -> Self {
+        Self::from_f64(math::sqrt(self.to_f64()))
+    }
+
+    fn exp(self) -> Self {
+        Self::from_f64(math::exp(self.to_f64()))
+    }
+}
+
+// The float functions, which aren't in `core`, come from `libm` without `std`.
+#[cfg(feature = "std")]
+mod math {
+    pub fn sqrt(x: f64) -> f64 {
+        x.sqrt()
+    }
+
+    pub fn exp(x: f64) -> f64 {
+        x.exp()
+    }
+
+    pub fn sqrtf(x: f32) -> f32 {
+        x.sqrt()
+    }
+
+    pub fn expf(x: f32) -> f32 {
+        x.exp()
+    }
+}
+
+#[cfg(not(feature = "std"))]
+mod math {
+    pub use libm::{exp, expf, sqrt, sqrtf};
+}
+
+macro_rules! impl_num_for_float {
+    ($float:ident, $sqrt:ident, $exp:ident) => {
+        impl Num for $float {
+            fn from_f64(value: f64) -> Self {
+                value as $float
+            }
+
+            fn to_f64(self) -> f64 {
+                self as f64
+            }
+
+            fn zero() -> Self {
+                0.0
+            }
+
+            fn one() -> Self {
+                1.0
+            }
+
+            fn from_usize(value: usize) -> Self {
+                value as $float
+            }
+
+            fn abs(self) -> Self {
+                $float::abs(self)
+            }
+
+            fn max(self, other: Self) -> Self {
+                $float::max(self, other)
+            }
+
+            fn min(self, other: Self) -> Self {
+                $float::min(self, other)
+            }
+
+            fn sqrt(self) -> Self {
+                math::$sqrt(self)
+            }
+
+            fn exp(self) -> Self {
+                math::$exp(self)
+            }
+        }
+    };
+}
+
+impl_num_for_float!(f64, sqrt, exp);
+impl_num_for_float!(f32, sqrtf, expf);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::indicators::generic::*;
+    use crate::test_helper::*;
+    use crate::{Close, High, Low, Next, Volume};
+
+    // Decimal number with 9 fractional digits, as a stand-in for a decimal crate.
+    #[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+    struct Fixed(i64);
+
+    const SCALE: i64 = 1_000_000_000;
+
+    impl Num for Fixed {
+        fn from_f64(value: f64) -> Self {
+            Fixed((value * SCALE as f64).round() as i64)
+        }
+
+        fn to_f64(self) -> f64 {
+            self.0 as f64 / SCALE as f64
+        }
+    }
+
+    impl Add for Fixed {
+        type Output = Self;
+
+        fn add(self, other: Self) -> Self {
+            Fixed(self.0 + other.0)
+        }
+    }
+
+    impl Sub for Fixed {
+        type Output = Self;
+
+        fn sub(self, other: Self) -> Self {
+            Fixed(self.0 - other.0)
+        }
+    }
+
+    impl Mul for Fixed {
+        type Output = Self;
+
+        fn mul(self, other: Self) -> Self {
+            Fixed((self.0 as i128 * other.0 as i128 / SCALE as i128) as i64)
+        }
+    }
+
+    impl Div for Fixed {
+        type Output = Self;
+
+        fn div(self, other: Self) -> Self {
+            Fixed((self.0 as i128 * SCALE as i128 / other.0 as i128) as i64)
+        }
+    }
+
+    impl Neg for Fixed {
+        type Output = Self;
+
+        fn neg(self) -> Self {
+            Fixed(-self.0)
+        }
+    }
+
+    impl AddAssign for Fixed {
+        fn add_assign(&mut self, other: Self) {
+            *self = *self + other;
+        }
+    }
+
+    impl SubAssign for Fixed {
+        fn sub_assign(&mut self, other: Self) {
+            *self = *self - other;
+        }
+    }
+
+    impl MulAssign for Fixed {
+        fn mul_assign(&mut self, other: Self) {
+            *self = *self * other;
+        }
+    }
+
+    impl DivAssign for Fixed {
+        fn div_assign(&mut self, other: Self) {
+            *self = *self / other;
+        }
+    }
+
+    struct Candle<N> {
+        high: N,
+        low: N,
+        close: N,
+        volume: N,
+    }
+
+    impl<N: Num> High<N> for Candle<N> {
+        fn high(&self) -> N {
+            self.high
+        }
+    }
+
+    impl<N: Num> Low<N> for Candle<N> {
+        fn low(&self) -> N {
+            self.low
+        }
+    }
+
+    impl<N: Num> Close<N> for Candle<N> {
+        fn close(&self) -> N {
+            self.close
+        }
+    }
+
+    impl<N: Num> Volume<N> for Candle<N> {
+        fn volume(&self) -> N {
+            self.volume
+        }
+    }
+
+    fn inputs<N: Num>() -> Vec<N> {
+        peek_inputs().into_iter().map(N::from_f64).collect()
+    }
+
+    fn candles<N: Num>() -> Vec<Candle<N>> {
+        peek_bars()
+            .iter()
+            .map(|bar| Candle {
+                high: N::from_f64(bar.high()),
+                low: N::from_f64(bar.low()),
+                close: N::from_f64(bar.close()),
+                volume: N::from_f64(bar.volume()),
+            })
+            .collect()
+    }
+
+    fn assert_close(actual: f64, expected: f64, tolerance: f64) {
+        assert!(
+            (actual - expected).abs() <= tolerance * expected.abs().max(1.0),
+            "{} != {}",
+            actual,
+            expected
+        );
+    }
+
+    // Checks that the indicator gives the same outputs with f32 and Fixed as with f64, feeding
+    // it with numbers or with candles. f32 gets a looser tolerance, since indicators like the
+    // A/D line accumulate large values, which lose the fraction digits in f32.
+    macro_rules! assert_consistent {
+        (inputs, $($indicator:tt)*) => {
+            assert_consistent!(@feed inputs, [], $($indicator)*)
+        };
+        (candles, $($indicator:tt)*) => {
+            assert_consistent!(@feed candles, [&], $($indicator)*)
+        };
+        (@feed $inputs:ident, [$($by_ref:tt)?], $indicator:ident::$new:ident($($arg:expr),*) $(=> $field:ident)?) => {{
+            let mut indicator = $indicator::<f64>::$new($($arg),*).unwrap();
+            let mut indicator_f32 = $indicator::<f32>::$new($($arg),*).unwrap();
+            let mut indicator_fixed = $indicator::<Fixed>::$new($($arg),*).unwrap();
+
+            let items = $inputs::<f64>();
+            let items_f32 = $inputs::<f32>();
+            let items_fixed = $inputs::<Fixed>();
+            for i in 0..items.len() {
+                let expected = indicator.next($($by_ref)? items[i])$(.$field)?;
+                let output_f32 = indicator_f32.next($($by_ref)? items_f32[i])$(.$field)?;
+                let output_fixed = indicator_fixed.next($($by_ref)? items_fixed[i])$(.$field)?;
+                assert_close(output_f32.to_f64(), expected, 1e-3);
+                assert_close(output_fixed.to_f64(), expected, 1e-6);
+            }
+        }};
+    }
+
+    #[test]
+    fn test_float() {
+        assert_eq!(f64::from_f64(2.5), 2.5);
+        assert_eq!(f32::from_f64(2.5), 2.5);
+        assert_eq!(2.5_f32.to_f64(), 2.5);
+        assert_eq!(f32::from_usize(3), 3.0);
+        assert_eq!(Num::abs(-2.0_f64), 2.0);
+        assert_eq!(Num::max(2.0_f32, 3.0), 3.0);
+        assert_eq!(Num::min(2.0_f32, 3.0), 2.0);
+        assert_eq!(Num::sqrt(9.0_f64), 3.0);
+    }
+
+    #[test]
+    fn test_default_methods() {
+        assert_eq!(Fixed::zero(), Fixed(0));
+        assert_eq!(Fixed::one(), Fixed(SCALE));
+        assert_eq!(Fixed::from_usize(3), Fixed(3 * SCALE));
+        assert_eq!(Fixed::from_f64(-2.5).abs(), Fixed::from_f64(2.5));
+        assert_eq!(Fixed::one().max(Fixed::zero()), Fixed::one());
+        assert_eq!(Fixed::one().min(Fixed::zero()), Fixed::zero());
+        assert_eq!(Fixed::from_f64(6.25).sqrt(), Fixed::from_f64(2.5));
+        assert_eq!(Fixed::zero().exp(), Fixed::one());
+    }
+
+    #[test]
+    fn test_numbers() {
+        assert_consistent!(inputs, SimpleMovingAverage::new(5));
+        assert_consistent!(inputs, ExponentialMovingAverage::new(5));
+        assert_consistent!(inputs, WeightedMovingAverage::new(5));
+        assert_consistent!(inputs, WilderMovingAverage::new(5));
+        assert_consistent!(inputs, HullMovingAverage::new(5));
+        assert_consistent!(inputs, ArnaudLegouxMovingAverage::new(5, 0.85, 6.0));
+        assert_consistent!(inputs, KaufmanAdaptiveMovingAverage::new(5, 2, 30));
+        assert_consistent!(inputs, StandardDeviation::new(5));
+        assert_consistent!(inputs, MeanAbsoluteDeviation::new(5));
+        assert_consistent!(inputs, RelativeStrengthIndex::new(5));
+        assert_consistent!(inputs, RateOfChange::new(5));
+        assert_consistent!(inputs, BollingerBands::new(5, 2.0) => upper);
+        assert_consistent!(inputs, MovingAverageConvergenceDivergence::new(3, 6, 4) => histogram);
+        assert_consistent!(inputs, FastStochastic::new(5));
+        assert_consistent!(inputs, AverageTrueRange::new(5));
+        assert_consistent!(inputs, KeltnerChannel::new(5, 2.0) => lower);
+    }
+
+    #[test]
+    fn test_candles() {
+        assert_consistent!(candles, AverageDirectionalIndex::new(5) => adx);
+        assert_consistent!(candles, ChandelierExit::new(5, 3.0) => long);
+        assert_consistent!(candles, ParabolicSar::new(0.02, 0.2) => sar);
+        assert_consistent!(candles, IchimokuCloud::new(3, 5, 8, 4) => senkou_span_b);
+        assert_consistent!(candles, CommodityChannelIndex::new(5));
+        assert_consistent!(candles, MoneyFlowIndex::new(5));
+        assert_consistent!(candles, ChaikinMoneyFlow::new(5));
+        assert_consistent!(candles, ChaikinOscillator::new(3, 6));
+        assert_consistent!(candles, ForceIndex::new(5));
+        assert_consistent!(candles, EaseOfMovement::new(5, 1.0));
+        assert_consistent!(candles, RollingVolumeWeightedAveragePrice::new(5));
+        assert_consistent!(candles, PercentageVolumeOscillator::new(3, 6, 4) => histogram);
+    }
+}
@@ -0,0 +1,782 @@
+use alloc::boxed::Box;
+use alloc::collections::BTreeMap;
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::fmt;
+use core::str::FromStr;
+
+use crate::errors::{Result, TaError};
+use crate::indicators::*;
+use crate::DynIndicator;
+
+/// Parameter of an indicator, which is created by a [Registry](struct.Registry.html).
+///
+/// Periods are numbers as well, they must be non-negative integers not greater than
+/// [MAX_PERIOD](#associatedconstant.MAX_PERIOD).
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Param {
+    Number(f64),
+    MovingAverage(MovingAverageType),
+}
+
+impl Param {
+    /// The greatest period accepted by [period](#method.period), which bounds the memory an
+    /// indicator created from a string may allocate.
+    pub const MAX_PERIOD: usize = 1_000_000;
+
+    pub fn number(self) -> Result<f64> {
+        match self {
+            Param::Number(number) => Ok(number),
+            Param::MovingAverage(_) => Err(TaError::InvalidParameter),
+        }
+    }
+
+    pub fn period(self) -> Result<usize> {
+        let number = self.number()?;
+        if number < 0.0 || number > Self::MAX_PERIOD as f64 || number != number as usize as f64 {
+            return Err(TaError::InvalidParameter);
+        }
+        Ok(number as usize)
+    }
+
+    pub fn moving_average(self) -> Result<MovingAverageType> {
+        match self {
+            Param::MovingAverage(moving_average) => Ok(moving_average),
+            Param::Number(_) => Err(TaError::InvalidParameter),
+        }
+    }
+}
+
+impl From<f64> for Param {
+    fn from(number: f64) -> Self {
+        Param::Number(number)
+    }
+}
+
+impl From<usize> for Param {
+    fn from(period: usize) -> Self {
+        Param::Number(period as f64)
+    }
+}
+
+impl From<MovingAverageType> for Param {
+    fn from(moving_average: MovingAverageType) -> Self {
+        Param::MovingAverage(moving_average)
+    }
+}
+
+/// Creates an indicator from its parameters.
+pub type Constructor = fn(&[Param]) -> Result<Box<dyn DynIndicator>>;
+
+/// Indicator, which is created by name and parameters, and parsed with `FromStr`.
+pub(crate) trait FromParams: Sized {
+    /// Name, which the indicator prints with `Display`.
+    const NAME: &'static str;
+
+    fn from_params(params: &[Param]) -> Result<Self>;
+}
+
+/// Creates indicators by name and parameters.
+///
+/// The names and the parameters are the ones, which indicators print with `Display`, e.g.
+/// `BB` with `[20, 2]` is `BB(20, 2)`. Names are case insensitive. Indicators with optional
+/// parameters (e.g. the moving average type of `BB`) accept both the short and the long form.
+///
+/// # Example
+///
+/// ```
+/// use ta::indicators::MovingAverageType;
+/// use ta::{Param, Registry};
+///
+/// let registry = Registry::new();
+///
+/// let bb = registry.create("BB", &[20.0, 2.0]).unwrap();
+/// assert_eq!(bb.to_string(), "BB(20, 2)");
+///
+/// let params = [Param::from(20), Param::from(2.0), MovingAverageType::Weighted.into()];
+/// let bb = registry.create("bb", &params).unwrap();
+/// assert_eq!(bb.to_string(), "BB(20, 2, WMA)");
+///
+/// assert!(registry.create("BB", &[20.0]).is_err());
+/// assert!(registry.create("XYZ", &[20.0]).is_err());
+/// ```
+#[derive(Clone)]
+pub struct Registry {
+    constructors: BTreeMap<String, Constructor>,
+}
+
+impl Registry {
+    /// Returns a registry of all built-in indicators.
+    pub fn new() -> Self {
+        let mut registry = Self::empty();
+        registry.register_builtins();
+        registry
+    }
+
+    /// Returns a registry without indicators.
+    pub fn empty() -> Self {
+        Self {
+            constructors: BTreeMap::new(),
+        }
+    }
+
+    /// Adds an indicator, or replaces the one with the same name.
+    pub fn register(&mut self, name: &str, constructor: Constructor) {
+        self.constructors
+            .insert(name.to_ascii_uppercase(), constructor);
+    }
+
+    /// Returns the names of the indicators in alphabetical order.
+    pub fn names(&self) -> impl Iterator<Item = &str> + '_ {
+        self.constructors.keys().map(|name| name.as_str())
+    }
+
+    /// Creates an indicator.
+    ///
+    /// Returns `TaError::UnknownIndicator` for an unknown name, `TaError::InvalidParameter` for
+    /// a wrong number or type of parameters or an invalid value.
+    pub fn create<P: Into<Param> + Copy>(
+        &self,
+        name: &str,
+        params: &[P],
+    ) -> Result<Box<dyn DynIndicator>> {
+        let constructor = self
+            .constructors
+            .get(&name.to_ascii_uppercase())
+            .ok_or(TaError::UnknownIndicator)?;
+        let params: Vec<Param> = params.iter().map(|&param| param.into()).collect();
+        constructor(&params)
+    }
+
+    /// Parses an indicator from its `Display` representation, e.g. `"BB(20, 2)"`.
+    ///
+    /// Returns `TaError::InvalidFormat` for a malformed string, otherwise the same errors as
+    /// [create](struct.Registry.html#method.create).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ta::Registry;
+    ///
+    /// let registry = Registry::new();
+    /// let kc = registry.parse("KC(10, 2)").unwrap();
+    /// assert_eq!(kc.to_string(), "KC(10, 2)");
+    ///
+    /// assert!(registry.parse("KC(10, 2").is_err());
+    /// ```
+    pub fn parse(&self, s: &str) -> Result<Box<dyn DynIndicator>> {
+        let (name, params) = parse_call(s)?;
+        self.create(name, &params)
+    }
+
+    fn register_builtins(&mut self) {
+        self.register_builtin::<SimpleMovingAverage>();
+        self.register_builtin::<ExponentialMovingAverage>();
+        self.register_builtin::<DoubleExponentialMovingAverage>();
+        self.register_builtin::<TripleExponentialMovingAverage>();
+        self.register_builtin::<WeightedMovingAverage>();
+        self.register_builtin::<WilderMovingAverage>();
+        self.register_builtin::<HullMovingAverage>();
+        self.register_builtin::<VolumeWeightedMovingAverage>();
+        self.register_builtin::<KaufmanAdaptiveMovingAverage>();
+        self.register_builtin::<ArnaudLegouxMovingAverage>();
+        // TRIX(period) has no signal line, TRIX(period, signal_period) has one.
+        self.register("TRIX", |params| match params {
+            [_] => constructor::<TripleExponentialAverage>(params),
+            _ => constructor::<TripleExponentialAverageWithSignal>(params),
+        });
+        self.register_builtin::<StandardDeviation>();
+        self.register_builtin::<MeanAbsoluteDeviation>();
+        self.register_builtin::<Maximum>();
+        self.register_builtin::<Minimum>();
+        self.register_builtin::<RelativeStrengthIndex>();
+        self.register_builtin::<FastStochastic>();
+        self.register_builtin::<SlowStochastic>();
+        self.register_builtin::<FullStochastic>();
+        self.register_builtin::<WilliamsR>();
+        self.register_builtin::<UltimateOscillator>();
+        self.register_builtin::<StochasticRsi>();
+        self.register_builtin::<TrueRange>();
+        self.register_builtin::<AverageTrueRange>();
+        self.register_builtin::<Aroon>();
+        self.register_builtin::<AverageDirectionalIndex>();
+        self.register_builtin::<MovingAverageConvergenceDivergence>();
+        self.register_builtin::<PercentagePriceOscillator>();
+        self.register_builtin::<PercentageVolumeOscillator>();
+        self.register_builtin::<CommodityChannelIndex>();
+        self.register_builtin::<EfficiencyRatio>();
+        self.register_builtin::<BollingerBands>();
+        self.register_builtin::<ChandelierExit>();
+        self.register_builtin::<KeltnerChannel>();
+        self.register_builtin::<ParabolicSar>();
+        self.register_builtin::<IchimokuCloud>();
+        self.register_builtin::<RateOfChange>();
+        self.register_builtin::<MoneyFlowIndex>();
+        self.register_builtin::<AccumulationDistribution>();
+        self.register_builtin::<ChaikinMoneyFlow>();
+        self.register_builtin::<ChaikinOscillator>();
+        self.register_builtin::<OnBalanceVolume>();
+        self.register_builtin::<ForceIndex>();
+        self.register_builtin::<EaseOfMovement>();
+        self.register_builtin::<PriceVolumeTrend>();
+        self.register_builtin::<NegativeVolumeIndex>();
+        self.register_builtin::<PositiveVolumeIndex>();
+        // VWAP without parameters is the session VWAP, VWAP(period) the rolling one.
+        self.register("VWAP", |params| match params {
+            [] => constructor::<VolumeWeightedAveragePrice>(params),
+            _ => constructor::<RollingVolumeWeightedAveragePrice>(params),
+        });
+        self.register_builtin::<VolumeWeightedAveragePriceBands>();
+    }
+
+    fn register_builtin<I: FromParams + DynIndicator + 'static>(&mut self) {
+        self.register(I::NAME, constructor::<I>);
+    }
+}
+
+impl Default for Registry {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl fmt::Debug for Registry {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_set().entries(self.names()).finish()
+    }
+}
+
+fn boxed<I: DynIndicator + 'static>(indicator: Result<I>) -> Result<Box<dyn DynIndicator>> {
+    Ok(Box::new(indicator?))
+}
+
+/// Parses an indicator with the built-in [Registry](struct.Registry.html), e.g.
+/// `"MACD(12, 26, 9)".parse::<Box<dyn DynIndicator>>()`.
+impl FromStr for Box<dyn DynIndicator> {
+    type Err = TaError;
+
+    fn from_str(s: &str) -> Result<Self> {
+        Registry::new().parse(s)
+    }
+}
+
+/// Parses `NAME`, `NAME()` or `NAME(param, ...)`.
+pub(crate) fn parse_call(s: &str) -> Result<(&str, Vec<Param>)> {
+    let s = s.trim();
+    let (name, params) = match s.find('(') {
+        Some(open) => {
+            let params = s[open + 1..]
+                .strip_suffix(')')
+                .ok_or(TaError::InvalidFormat)?;
+            (s[..open].trim_end(), params)
+        }
+        None => (s, ""),
+    };
+
+    let is_paren = |c: char| c == '(' || c == ')';
+    if name.is_empty() || name.contains(is_paren) || params.contains(is_paren) {
+        return Err(TaError::InvalidFormat);
+    }
+
+    if params.trim().is_empty() {
+        return Ok((name, Vec::new()));
+    }
+    let params = params.split(',').map(parse_param).collect::<Result<_>>()?;
+    Ok((name, params))
+}
+
+/// Parses a number, or a moving average type, e.g. `SMA`.
+fn parse_param(s: &str) -> Result<Param> {
+    let s = s.trim();
+    if s.is_empty() {
+        return Err(TaError::InvalidFormat);
+    }
+    match s.parse::<f64>() {
+        Ok(number) => Ok(Param::Number(number)),
+        Err(_) => Ok(Param::MovingAverage(s.parse()?)),
+    }
+}
+
+/// Parses an indicator of a known type, see [impl_from_str](crate::helpers).
+pub(crate) fn parse_indicator<I: FromParams>(s: &str) -> Result<I> {
+    let (name, params) = parse_call(s)?;
+    if !name.eq_ignore_ascii_case(I::NAME) {
+        return Err(TaError::UnknownIndicator);
+    }
+    I::from_params(&params)
+}
+
+fn constructor<I: FromParams + DynIndicator + 'static>(
+    params: &[Param],
+) -> Result<Box<dyn DynIndicator>> {
+    boxed(I::from_params(params))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::DynOutput;
+    use rand::rngs::StdRng;
+    use rand::{Rng, SeedableRng};
+
+    #[test]
+    fn test_param() {
+        assert_eq!(Param::from(14).period(), Ok(14));
+        assert_eq!(Param::from(14.0).period(), Ok(14));
+        assert_eq!(Param::from(2.5).number(), Ok(2.5));
+        assert_eq!(Param::from(2.5).period(), Err(TaError::InvalidParameter));
+        assert_eq!(Param::from(-1.0).period(), Err(TaError::InvalidParameter));
+        assert_eq!(
+            Param::from(Param::MAX_PERIOD).period(),
+            Ok(Param::MAX_PERIOD)
+        );
+        assert_eq!(
+            Param::from(Param::MAX_PERIOD + 1).period(),
+            Err(TaError::InvalidParameter)
+        );
+        assert_eq!(
+            Param::from(MovingAverageType::Simple).moving_average(),
+            Ok(MovingAverageType::Simple)
+        );
+        assert_eq!(
+            Param::from(MovingAverageType::Simple).number(),
+            Err(TaError::InvalidParameter)
+        );
+        assert_eq!(
+            Param::from(14).moving_average(),
+            Err(TaError::InvalidParameter)
+        );
+    }
+
+    #[test]
+    fn test_create() {
+        use MovingAverageType::*;
+
+        let registry = Registry::new();
+        let create = |name: &str, params: &[Param]| registry.create(name, params).unwrap();
+        let n = |number: f64| Param::Number(number);
+        let ma = Param::MovingAverage;
+
+        let cases = [
+            (create("SMA", &[n(9.0)]), "SMA(9)"),
+            (create("EMA", &[n(9.0)]), "EMA(9)"),
+            (create("DEMA", &[n(9.0)]), "DEMA(9)"),
+            (create("TEMA", &[n(9.0)]), "TEMA(9)"),
+            (create("WMA", &[n(9.0)]), "WMA(9)"),
+            (create("RMA", &[n(9.0)]), "RMA(9)"),
+            (create("HMA", &[n(9.0)]), "HMA(9)"),
+            (create("VWMA", &[n(9.0)]), "VWMA(9)"),
+            (
+                create("KAMA", &[n(10.0), n(2.0), n(30.0)]),
+                "KAMA(10, 2, 30)",
+            ),
+            (
+                create("ALMA", &[n(9.0), n(0.85), n(6.0)]),
+                "ALMA(9, 0.85, 6)",
+            ),
+            (create("TRIX", &[n(9.0)]), "TRIX(9)"),
+            (create("TRIX", &[n(9.0), n(5.0)]), "TRIX(9, 5)"),
+            (create("SD", &[n(9.0)]), "SD(9)"),
+            (create("MAD", &[n(9.0)]), "MAD(9)"),
+            (create("MAX", &[n(9.0)]), "MAX(9)"),
+            (create("MIN", &[n(9.0)]), "MIN(9)"),
+            (create("RSI", &[n(14.0)]), "RSI(14)"),
+            (create("RSI", &[n(14.0), ma(Wilder)]), "RSI(14, RMA)"),
+            (create("FAST_STOCH", &[n(14.0)]), "FAST_STOCH(14)"),
+            (
+                create("SLOW_STOCH", &[n(14.0), n(3.0)]),
+                "SLOW_STOCH(14, 3)",
+            ),
+            (
+                create("SLOW_STOCH", &[n(14.0), n(3.0), ma(Simple)]),
+                "SLOW_STOCH(14, 3, SMA)",
+            ),
+            (
+                create("FULL_STOCH", &[n(14.0), n(3.0), n(3.0)]),
+                "FULL_STOCH(14, 3, 3)",
+            ),
+            (
+                create(
+                    "FULL_STOCH",
+                    &[n(14.0), n(3.0), n(3.0), ma(Exponential), ma(Simple)],
+                ),
+                "FULL_STOCH(14, 3, 3, EMA, SMA)",
+            ),
+            (create("%R", &[n(14.0)]), "%R(14)"),
+            (create("UO", &[n(7.0), n(14.0), n(28.0)]), "UO(7, 14, 28)"),
+            (
+                create("STOCH_RSI", &[n(14.0), n(14.0), n(3.0), n(3.0)]),
+                "STOCH_RSI(14, 14, 3, 3)",
+            ),
+            (
+                create(
+                    "STOCH_RSI",
+                    &[n(14.0), n(14.0), n(3.0), n(3.0), ma(Exponential)],
+                ),
+                "STOCH_RSI(14, 14, 3, 3, EMA)",
+            ),
+            (create("TRUE_RANGE", &[]), "TRUE_RANGE()"),
+            (create("ATR", &[n(14.0)]), "ATR(14)"),
+            (create("ATR", &[n(14.0), ma(Simple)]), "ATR(14, SMA)"),
+            (create("AROON", &[n(25.0)]), "AROON(25)"),
+            (create("ADX", &[n(14.0)]), "ADX(14)"),
+            (
+                create("MACD", &[n(12.0), n(26.0), n(9.0)]),
+                "MACD(12, 26, 9)",
+            ),
+            (
+                create("MACD", &[n(12.0), n(26.0), n(9.0), ma(Simple)]),
+                "MACD(12, 26, 9, SMA)",
+            ),
+            (create("PPO", &[n(12.0), n(26.0), n(9.0)]), "PPO(12, 26, 9)"),
+            (
+                create("PPO", &[n(12.0), n(26.0), n(9.0), ma(Simple)]),
+                "PPO(12, 26, 9, SMA)",
+            ),
+            (create("PVO", &[n(12.0), n(26.0), n(9.0)]), "PVO(12, 26, 9)"),
+            (
+                create("PVO", &[n(12.0), n(26.0), n(9.0), ma(Simple)]),
+                "PVO(12, 26, 9, SMA)",
+            ),
+            (create("CCI", &[n(20.0)]), "CCI(20)"),
+            (create("ER", &[n(10.0)]), "ER(10)"),
+            (create("BB", &[n(20.0), n(2.0)]), "BB(20, 2)"),
+            (
+                create("BB", &[n(20.0), n(2.5), ma(Weighted)]),
+                "BB(20, 2.5, WMA)",
+            ),
+            (create("CE", &[n(22.0), n(3.0)]), "CE(22, 3)"),
+            (
+                create("CE", &[n(22.0), n(3.0), ma(Wilder)]),
+                "CE(22, 3, RMA)",
+            ),
+            (create("KC", &[n(10.0), n(2.0)]), "KC(10, 2)"),
+            (
+                create("KC", &[n(10.0), n(2.0), ma(Simple), ma(Wilder)]),
+                "KC(10, 2, SMA, RMA)",
+            ),
+            (create("PSAR", &[n(0.02), n(0.2)]), "PSAR(0.02, 0.2)"),
+            (
+                create("ICHIMOKU", &[n(9.0), n(26.0), n(52.0), n(26.0)]),
+                "ICHIMOKU(9, 26, 52, 26)",
+            ),
+            (create("ROC", &[n(9.0)]), "ROC(9)"),
+            (create("MFI", &[n(14.0)]), "MFI(14)"),
+            (create("AD", &[]), "AD"),
+            (create("CMF", &[n(21.0)]), "CMF(21)"),
+            (create("CO", &[n(3.0), n(10.0)]), "CO(3, 10)"),
+            (
+                create("CO", &[n(3.0), n(10.0), ma(Simple)]),
+                "CO(3, 10, SMA)",
+            ),
+            (create("OBV", &[]), "OBV"),
+            (create("FI", &[n(13.0)]), "FI(13)"),
+            (create("EMV", &[n(14.0), n(10000.0)]), "EMV(14, 10000)"),
+            (create("PVT", &[]), "PVT"),
+            (create("NVI", &[]), "NVI"),
+            (create("PVI", &[]), "PVI"),
+            (create("VWAP", &[]), "VWAP"),
+            (create("VWAP", &[n(14.0)]), "VWAP(14)"),
+            (create("VWAP_BANDS", &[]), "VWAP_BANDS"),
+        ];
+
+        for (indicator, expected) in cases.iter() {
+            assert_eq!(&indicator.to_string(), expected);
+        }
+
+        // every built-in indicator is covered
+        let mut names: Vec<&str> = cases
+            .iter()
+            .map(|(_, expected)| expected.split('(').next().unwrap())
+            .collect();
+        names.sort();
+        names.dedup();
+        assert_eq!(registry.names().collect::<Vec<_>>(), names);
+    }
+
+    #[test]
+    fn test_create_errors() {
+        let registry = Registry::new();
+
+        assert_eq!(
+            registry.create("XYZ", &[9.0]).unwrap_err(),
+            TaError::UnknownIndicator
+        );
+        assert_eq!(
+            registry.create("SMA", &[9.0, 1.0]).unwrap_err(),
+            TaError::InvalidParameter
+        );
+        assert_eq!(
+            registry.create("SMA", &[9.5]).unwrap_err(),
+            TaError::InvalidParameter
+        );
+        assert_eq!(
+            registry.create("SMA", &[0.0]).unwrap_err(),
+            TaError::InvalidParameter
+        );
+        assert_eq!(
+            registry
+                .create("RSI", &[Param::from(14), Param::from(2.0)])
+                .unwrap_err(),
+            TaError::InvalidParameter
+        );
+    }
+
+    #[test]
+    fn test_case_insensitive() {
+        let registry = Registry::new();
+        assert_eq!(
+            registry.create("fast_stoch", &[14.0]).unwrap().to_string(),
+            "FAST_STOCH(14)"
+        );
+    }
+
+    // Parses with the `FromStr` of the indicator type and formats it again.
+    fn parse_with<I: FromStr<Err = TaError> + fmt::Display>(s: &str) -> Result<String> {
+        s.parse::<I>().map(|indicator| indicator.to_string())
+    }
+
+    // Random parameters of the given kinds: P - period, X - number, M - moving average type.
+    fn random_params(rng: &mut StdRng, kinds: &str) -> Vec<Param> {
+        use MovingAverageType::*;
+        let types = [
+            Simple,
+            Exponential,
+            Wilder,
+            Weighted,
+            DoubleExponential,
+            TripleExponential,
+            Hull,
+        ];
+
+        kinds
+            .chars()
+            .map(|kind| match kind {
+                'P' => Param::from(rng.gen_range(1..40usize)),
+                'X' => Param::from(rng.gen::<f64>() * 10.0),
+                'M' => Param::from(types[rng.gen_range(0..types.len())]),
+                _ => unreachable!(),
+            })
+            .collect()
+    }
+
+    // Defaults of the optional trailing parameters, which aren't printed when they're the defaults.
+    fn default_params(name: &str) -> Vec<Param> {
+        use MovingAverageType::*;
+        let types: &[MovingAverageType] = match name {
+            "RSI" | "SLOW_STOCH" | "ATR" | "MACD" | "PPO" | "PVO" | "CE" | "CO" => &[Exponential],
+            "STOCH_RSI" | "BB" => &[Simple],
+            "FULL_STOCH" => &[Simple, Simple],
+            "KC" => &[Exponential, Exponential],
+            _ => &[],
+        };
+        types.iter().copied().map(Param::from).collect()
+    }
+
+    #[test]
+    fn test_parse_round_trip() {
+        type ParseWith = fn(&str) -> Result<String>;
+        let shapes: [(&str, &str, ParseWith); 63] = [
+            ("SMA", "P", parse_with::<SimpleMovingAverage>),
+            ("EMA", "P", parse_with::<ExponentialMovingAverage>),
+            ("DEMA", "P", parse_with::<DoubleExponentialMovingAverage>),
+            ("TEMA", "P", parse_with::<TripleExponentialMovingAverage>),
+            ("WMA", "P", parse_with::<WeightedMovingAverage>),
+            ("RMA", "P", parse_with::<WilderMovingAverage>),
+            ("HMA", "P", parse_with::<HullMovingAverage>),
+            ("VWMA", "P", parse_with::<VolumeWeightedMovingAverage>),
+            ("KAMA", "PPP", parse_with::<KaufmanAdaptiveMovingAverage>),
+            ("ALMA", "PXX", parse_with::<ArnaudLegouxMovingAverage>),
+            ("TRIX", "P", parse_with::<TripleExponentialAverage>),
+            (
+                "TRIX",
+                "PP",
+                parse_with::<TripleExponentialAverageWithSignal>,
+            ),
+            ("SD", "P", parse_with::<StandardDeviation>),
+            ("MAD", "P", parse_with::<MeanAbsoluteDeviation>),
+            ("MAX", "P", parse_with::<Maximum>),
+            ("MIN", "P", parse_with::<Minimum>),
+            ("RSI", "P", parse_with::<RelativeStrengthIndex>),
+            ("RSI", "PM", parse_with::<RelativeStrengthIndex>),
+            ("FAST_STOCH", "P", parse_with::<FastStochastic>),
+            ("SLOW_STOCH", "PP", parse_with::<SlowStochastic>),
+            ("SLOW_STOCH", "PPM", parse_with::<SlowStochastic>),
+            ("FULL_STOCH", "PPP", parse_with::<FullStochastic>),
+            ("FULL_STOCH", "PPPMM", parse_with::<FullStochastic>),
+            ("%R", "P", parse_with::<WilliamsR>),
+            ("UO", "PPP", parse_with::<UltimateOscillator>),
+            ("STOCH_RSI", "PPPP", parse_with::<StochasticRsi>),
+            ("STOCH_RSI", "PPPPM", parse_with::<StochasticRsi>),
+            ("TRUE_RANGE", "", parse_with::<TrueRange>),
+            ("ATR", "P", parse_with::<AverageTrueRange>),
+            ("ATR", "PM", parse_with::<AverageTrueRange>),
+            ("AROON", "P", parse_with::<Aroon>),
+            ("ADX", "P", parse_with::<AverageDirectionalIndex>),
+            (
+                "MACD",
+                "PPP",
+                parse_with::<MovingAverageConvergenceDivergence>,
+            ),
+            (
+                "MACD",
+                "PPPM",
+                parse_with::<MovingAverageConvergenceDivergence>,
+            ),
+            ("PPO", "PPP", parse_with::<PercentagePriceOscillator>),
+            ("PPO", "PPPM", parse_with::<PercentagePriceOscillator>),
+            ("PVO", "PPP", parse_with::<PercentageVolumeOscillator>),
+            ("PVO", "PPPM", parse_with::<PercentageVolumeOscillator>),
+            ("CCI", "P", parse_with::<CommodityChannelIndex>),
+            ("ER", "P", parse_with::<EfficiencyRatio>),
+            ("BB", "PX", parse_with::<BollingerBands>),
+            ("BB", "PXM", parse_with::<BollingerBands>),
+            ("CE", "PX", parse_with::<ChandelierExit>),
+            ("CE", "PXM", parse_with::<ChandelierExit>),
+            ("KC", "PX", parse_with::<KeltnerChannel>),
+            ("KC", "PXMM", parse_with::<KeltnerChannel>),
+            ("PSAR", "XX", parse_with::<ParabolicSar>),
+            ("ICHIMOKU", "PPPP", parse_with::<IchimokuCloud>),
+            ("ROC", "P", parse_with::<RateOfChange>),
+            ("MFI", "P", parse_with::<MoneyFlowIndex>),
+            ("AD", "", parse_with::<AccumulationDistribution>),
+            ("CMF", "P", parse_with::<ChaikinMoneyFlow>),
+            ("CO", "PP", parse_with::<ChaikinOscillator>),
+            ("CO", "PPM", parse_with::<ChaikinOscillator>),
+            ("OBV", "", parse_with::<OnBalanceVolume>),
+            ("FI", "P", parse_with::<ForceIndex>),
+            ("EMV", "PX", parse_with::<EaseOfMovement>),
+            ("PVT", "", parse_with::<PriceVolumeTrend>),
+            ("NVI", "", parse_with::<NegativeVolumeIndex>),
+            ("PVI", "", parse_with::<PositiveVolumeIndex>),
+            ("VWAP", "", parse_with::<VolumeWeightedAveragePrice>),
+            ("VWAP", "P", parse_with::<RollingVolumeWeightedAveragePrice>),
+            (
+                "VWAP_BANDS",
+                "",
+                parse_with::<VolumeWeightedAveragePriceBands>,
+            ),
+        ];
+
+        let registry = Registry::new();
+        let mut rng = StdRng::seed_from_u64(42);
+
+        for (name, kinds, parse) in shapes.iter() {
+            let mut valid = 0;
+            for _ in 0..100 {
+                let params = random_params(&mut rng, kinds);
+                // random parameters may be out of range, e.g. KAMA(10, 30, 2)
+                let indicator = match registry.create(name, &params) {
+                    Ok(indicator) => indicator,
+                    Err(_) => continue,
+                };
+                valid += 1;
+
+                // the moving average types, which are the defaults, aren't printed
+                let formatted = indicator.to_string();
+                let (parsed_name, parsed_params) = parse_call(&formatted).unwrap();
+                assert_eq!(parsed_name, *name);
+                assert_eq!(parsed_params[..], params[..parsed_params.len()]);
+
+                let defaults = default_params(name);
+                let omitted = &params[parsed_params.len()..];
+                assert!(omitted.len() <= defaults.len(), "{}", formatted);
+                assert_eq!(omitted, &defaults[defaults.len() - omitted.len()..]);
+                assert_eq!(registry.parse(&formatted).unwrap().to_string(), formatted);
+                assert_eq!(parse(&formatted), Ok(formatted.clone()));
+                assert_eq!(
+                    formatted
+                        .to_lowercase()
+                        .parse::<Box<dyn DynIndicator>>()
+                        .unwrap()
+                        .to_string(),
+                    formatted
+                );
+            }
+            assert!(valid > 0, "no valid parameters for {}({})", name, kinds);
+        }
+    }
+
+    #[test]
+    fn test_parse() {
+        let registry = Registry::new();
+        let parse = |s: &str| registry.parse(s).map(|indicator| indicator.to_string());
+
+        assert_eq!(parse("SMA(9)"), Ok("SMA(9)".to_string()));
+        assert_eq!(parse("  sma ( 9 )  "), Ok("SMA(9)".to_string()));
+        assert_eq!(parse("BB(20,2.5,wma)"), Ok("BB(20, 2.5, WMA)".to_string()));
+        assert_eq!(
+            parse("MACD(12, 26, 9, hma)"),
+            Ok("MACD(12, 26, 9, HMA)".to_string())
+        );
+        assert_eq!(parse("TRUE_RANGE"), Ok("TRUE_RANGE()".to_string()));
+        assert_eq!(parse("OBV()"), Ok("OBV".to_string()));
+        assert_eq!(parse("VWAP"), Ok("VWAP".to_string()));
+        assert_eq!(parse("VWAP(14)"), Ok("VWAP(14)".to_string()));
+
+        assert_eq!(
+            "EMA(9)".parse::<SimpleMovingAverage>().unwrap_err(),
+            TaError::UnknownIndicator
+        );
+        assert_eq!(
+            "VWAP"
+                .parse::<RollingVolumeWeightedAveragePrice>()
+                .unwrap_err(),
+            TaError::InvalidParameter
+        );
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        let registry = Registry::new();
+        let parse = |s: &str| registry.parse(s).unwrap_err();
+
+        assert_eq!(parse(""), TaError::InvalidFormat);
+        assert_eq!(parse("SMA(1e18)"), TaError::InvalidParameter);
+        assert_eq!(parse("(9)"), TaError::InvalidFormat);
+        assert_eq!(parse("SMA(9"), TaError::InvalidFormat);
+        assert_eq!(parse("SMA9)"), TaError::InvalidFormat);
+        assert_eq!(parse("SMA(9))"), TaError::InvalidFormat);
+        assert_eq!(parse("SMA((9)"), TaError::InvalidFormat);
+        assert_eq!(parse("SMA(9) x"), TaError::InvalidFormat);
+        assert_eq!(parse("BB(20,, 2)"), TaError::InvalidFormat);
+        assert_eq!(parse("BB(20, 2,)"), TaError::InvalidFormat);
+        assert_eq!(parse("XYZ(9)"), TaError::UnknownIndicator);
+        assert_eq!(parse("SMA(9, 2)"), TaError::InvalidParameter);
+        assert_eq!(parse("SMA(9.5)"), TaError::InvalidParameter);
+        assert_eq!(parse("SMA(0)"), TaError::InvalidParameter);
+        assert_eq!(parse("SMA(SMA)"), TaError::InvalidParameter);
+        assert_eq!(parse("BB(20, 2, XMA)"), TaError::InvalidParameter);
+    }
+
+    #[test]
+    fn test_register() {
+        let mut registry = Registry::empty();
+        assert_eq!(registry.names().count(), 0);
+
+        // a custom name for SMA with a default period
+        registry.register("Average", |params| match *params {
+            [] => boxed(SimpleMovingAverage::new(5)),
+            [period] => boxed(SimpleMovingAverage::new(period.period()?)),
+            _ => Err(TaError::InvalidParameter),
+        });
+
+        assert_eq!(registry.names().collect::<Vec<_>>(), vec!["AVERAGE"]);
+        let mut sma = registry.create::<f64>("average", &[]).unwrap();
+        assert_eq!(sma.to_string(), "SMA(5)");
+        assert_eq!(
+            sma.next_dyn(
+                &crate::DataItem::builder()
+                    .open(2.0)
+                    .high(2.0)
+                    .low(2.0)
+                    .close(2.0)
+                    .volume(1.0)
+                    .build()
+                    .unwrap()
+            ),
+            DynOutput::Value(2.0)
+        );
+        assert!(registry.create("SMA", &[5.0]).is_err());
+    }
+}
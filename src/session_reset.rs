@@ -0,0 +1,413 @@
+use core::fmt;
+
+use crate::errors::{Result, TaError};
+use crate::{Lookback, Next, Peek, Period, Reset, Timestamp};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+const DAY: i64 = 24 * 60 * 60 * 1000;
+const WEEK: i64 = 7 * DAY;
+// 1970-01-05, the first Monday after the Unix epoch
+const MONDAY: i64 = 4 * DAY;
+
+/// Wraps an indicator and resets it, when an input starts a new session.
+///
+/// Sessions are consecutive time frames of the same length, e.g. days. A session is
+/// determined by the [timestamp](trait.Timestamp.html) of an input, so inputs must implement
+/// the `Timestamp` trait.
+///
+/// Any input from a different session than the current one starts a new session, so an input
+/// from an earlier session (out-of-order data) resets the indicator as well, and so does the
+/// next input of the current session after it. Out-of-order inputs within the current session
+/// are consumed as usual.
+///
+/// # Parameters
+///
+/// * _length_ - length of a session in milliseconds (integer greater than 0). Default is 1 day.
+/// * _offset_ - time in milliseconds, when sessions start, relative to the Unix epoch.
+///   E.g. 13:30 UTC is `(13 * 60 + 30) * 60 * 1000`. Default is 0 (midnight UTC).
+///
+/// # Example
+///
+/// ```
+/// use ta::indicators::VolumeWeightedAveragePrice;
+/// use ta::{DataItem, Next, SessionReset};
+///
+/// let mut vwap = SessionReset::daily(VolumeWeightedAveragePrice::new());
+///
+/// let item = |close: f64, volume: f64, timestamp: i64| {
+///     DataItem::builder()
+///         .open(close)
+///         .high(close)
+///         .low(close)
+///         .close(close)
+///         .volume(volume)
+///         .timestamp(timestamp)
+///         .build()
+///         .unwrap()
+/// };
+///
+/// // 2020-09-13
+/// assert_eq!(vwap.next(&item(10.0, 100.0, 1_600_000_000_000)), 10.0);
+/// assert_eq!(vwap.next(&item(13.0, 200.0, 1_600_000_060_000)), 12.0);
+/// // 2020-09-14, a new session
+/// assert_eq!(vwap.next(&item(11.0, 100.0, 1_600_086_400_000)), 11.0);
+/// ```
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone)]
+pub struct SessionReset<I> {
+    indicator: I,
+    // the wrapped indicator in its initial state, cloned at the start of a session
+    template: I,
+    length: i64,
+    offset: i64,
+    session: Option<i64>,
+    suspended: Option<Suspended<I>>,
+}
+
+// The indicator of the previous session, kept while the last input is the first one of a new
+// session, so the input can be replaced with one from the previous session.
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone)]
+struct Suspended<I> {
+    indicator: I,
+    session: i64,
+    // whether the indicator has consumed the input, which is going to be replaced
+    has_last: bool,
+}
+
+impl<I: Clone + Reset> SessionReset<I> {
+    pub fn new(indicator: I, length: i64, offset: i64) -> Result<Self> {
+        if length <= 0 {
+            return Err(TaError::InvalidParameter);
+        }
+
+        let mut template = indicator.clone();
+        template.reset();
+        Ok(Self {
+            indicator,
+            template,
+            length,
+            offset,
+            session: None,
+            suspended: None,
+        })
+    }
+
+    /// Resets the indicator at midnight UTC.
+    pub fn daily(indicator: I) -> Self {
+        Self::new(indicator, DAY, 0).unwrap()
+    }
+
+    /// Resets the indicator on Monday at midnight UTC.
+    pub fn weekly(indicator: I) -> Self {
+        Self::new(indicator, WEEK, MONDAY).unwrap()
+    }
+}
+
+impl<I> SessionReset<I> {
+    pub fn length(&self) -> i64 {
+        self.length
+    }
+
+    pub fn offset(&self) -> i64 {
+        self.offset
+    }
+
+    /// Returns a reference to the wrapped indicator.
+    pub fn inner(&self) -> &I {
+        &self.indicator
+    }
+
+    /// Unwraps the indicator.
+    pub fn into_inner(self) -> I {
+        self.indicator
+    }
+
+    /// Returns the number of the session a timestamp belongs to.
+    ///
+    /// Equal to `(timestamp - offset).div_euclid(length)` up to a constant, which doesn't matter
+    /// as sessions are only compared, but without overflowing for any timestamp and offset.
+    fn session_of(&self, timestamp: i64) -> i64 {
+        let session = timestamp.div_euclid(self.length);
+        if timestamp.rem_euclid(self.length) < self.offset.rem_euclid(self.length) {
+            session - 1
+        } else {
+            session
+        }
+    }
+}
+
+impl<I: Lookback> Lookback for SessionReset<I> {
+    fn lookback(&self) -> usize {
+        self.indicator.lookback()
+    }
+
+    fn is_ready(&self) -> bool {
+        self.indicator.is_ready()
+    }
+}
+
+impl<I: Period> Period for SessionReset<I> {
+    fn period(&self) -> usize {
+        self.indicator.period()
+    }
+}
+
+impl<'a, T, I> Next<&'a T> for SessionReset<I>
+where
+    T: Timestamp,
+    I: Next<&'a T> + Reset + Clone,
+{
+    type Output = I::Output;
+
+    fn next(&mut self, input: &'a T) -> Self::Output {
+        let session = self.session_of(input.timestamp());
+        self.suspended = match self.session {
+            Some(current) if current != session => {
+                let fresh = self.template.clone();
+                Some(Suspended {
+                    indicator: core::mem::replace(&mut self.indicator, fresh),
+                    session: current,
+                    has_last: false,
+                })
+            }
+            _ => None,
+        };
+        self.session = Some(session);
+        self.indicator.next(input)
+    }
+}
+
+impl<'a, T, I> Peek<&'a T> for SessionReset<I>
+where
+    T: Timestamp,
+    I: Peek<&'a T> + Reset + Clone,
+{
+    fn peek(&self, input: &'a T) -> Self::Output {
+        match self.session {
+            Some(current) if current != self.session_of(input.timestamp()) => {
+                self.template.peek(input)
+            }
+            _ => self.indicator.peek(input),
+        }
+    }
+
+    fn update_last(&mut self, input: &'a T) -> Self::Output {
+        let session = self.session_of(input.timestamp());
+        let current = match self.session {
+            Some(current) => current,
+            None => return self.next(input),
+        };
+        if current == session {
+            return self.indicator.update_last(input);
+        }
+
+        // The state preceding the last input.
+        let base = match self.suspended.take() {
+            Some(suspended) => suspended,
+            None => Suspended {
+                indicator: self.indicator.clone(),
+                session: current,
+                has_last: true,
+            },
+        };
+        self.session = Some(session);
+
+        if base.session == session {
+            self.indicator = base.indicator;
+            if base.has_last {
+                self.indicator.update_last(input)
+            } else {
+                self.indicator.next(input)
+            }
+        } else {
+            self.indicator = self.template.clone();
+            self.suspended = Some(base);
+            self.indicator.next(input)
+        }
+    }
+}
+
+impl<I: Reset> Reset for SessionReset<I> {
+    fn reset(&mut self) {
+        self.indicator.reset();
+        self.session = None;
+        self.suspended = None;
+    }
+}
+
+impl<I: Default + Clone + Reset> Default for SessionReset<I> {
+    fn default() -> Self {
+        Self::daily(I::default())
+    }
+}
+
+impl<I: fmt::Display> fmt::Display for SessionReset<I> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.indicator)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::indicators::{SimpleMovingAverage, VolumeWeightedAveragePrice};
+    use crate::test_helper::*;
+
+    const HOUR: i64 = 60 * 60 * 1000;
+
+    fn bar(close: f64, timestamp: i64) -> Bar {
+        Bar::new()
+            .high(close)
+            .low(close)
+            .close(close)
+            .volume(100.0)
+            .timestamp(timestamp)
+    }
+
+    #[test]
+    fn test_new() {
+        let sma = SimpleMovingAverage::new(3).unwrap();
+        assert!(SessionReset::new(sma.clone(), 0, 0).is_err());
+        assert!(SessionReset::new(sma.clone(), -HOUR, 0).is_err());
+        assert!(SessionReset::new(sma, HOUR, -HOUR).is_ok());
+    }
+
+    #[test]
+    fn test_next() {
+        let mut sma = SessionReset::daily(SimpleMovingAverage::new(3).unwrap());
+
+        assert_eq!(sma.next(&bar(10.0, 0)), 10.0);
+        assert_eq!(sma.next(&bar(12.0, 23 * HOUR)), 11.0);
+        assert_eq!(sma.next(&bar(20.0, 24 * HOUR)), 20.0);
+        assert_eq!(sma.next(&bar(22.0, 30 * HOUR)), 21.0);
+
+        // out-of-order within the session
+        assert_eq!(sma.next(&bar(24.0, 25 * HOUR)), 22.0);
+
+        // an earlier session
+        assert_eq!(sma.next(&bar(5.0, 2 * HOUR)), 5.0);
+        assert_eq!(sma.next(&bar(7.0, 3 * HOUR)), 6.0);
+
+        // back to the later session, which starts anew
+        assert_eq!(sma.next(&bar(30.0, 26 * HOUR)), 30.0);
+    }
+
+    #[test]
+    fn test_next_extreme_timestamps() {
+        let mut sma =
+            SessionReset::new(SimpleMovingAverage::new(3).unwrap(), HOUR, -HOUR / 2).unwrap();
+
+        assert_eq!(sma.next(&bar(10.0, i64::MIN)), 10.0);
+        assert_eq!(sma.next(&bar(12.0, i64::MIN + 1)), 11.0);
+        assert_eq!(sma.next(&bar(20.0, i64::MAX - 1)), 20.0);
+        assert_eq!(sma.next(&bar(22.0, i64::MAX)), 21.0);
+
+        let mut sma = SessionReset::new(SimpleMovingAverage::new(3).unwrap(), 1, i64::MAX).unwrap();
+        assert_eq!(sma.next(&bar(10.0, i64::MIN)), 10.0);
+        assert_eq!(sma.next(&bar(12.0, i64::MAX)), 12.0);
+
+        // sessions start at i64::MIN, -1 and i64::MAX - 1
+        let mut sma =
+            SessionReset::new(SimpleMovingAverage::new(3).unwrap(), i64::MAX, i64::MIN).unwrap();
+        assert_eq!(sma.next(&bar(10.0, i64::MIN)), 10.0);
+        assert_eq!(sma.next(&bar(12.0, -2)), 11.0);
+        assert_eq!(sma.next(&bar(20.0, -1)), 20.0);
+        assert_eq!(sma.next(&bar(22.0, i64::MAX - 2)), 21.0);
+        assert_eq!(sma.next(&bar(30.0, i64::MAX - 1)), 30.0);
+    }
+
+    #[test]
+    fn test_next_negative_timestamps() {
+        let mut sma = SessionReset::daily(SimpleMovingAverage::new(3).unwrap());
+
+        assert_eq!(sma.next(&bar(10.0, -2 * HOUR)), 10.0);
+        assert_eq!(sma.next(&bar(12.0, -HOUR)), 11.0);
+        assert_eq!(sma.next(&bar(20.0, 0)), 20.0);
+    }
+
+    #[test]
+    fn test_next_offset() {
+        // sessions start at 13:30 UTC
+        let mut vwap =
+            SessionReset::new(VolumeWeightedAveragePrice::new(), 24 * HOUR, 27 * HOUR / 2).unwrap();
+
+        assert_eq!(vwap.next(&bar(10.0, 13 * HOUR)), 10.0);
+        assert_eq!(vwap.next(&bar(20.0, 14 * HOUR)), 20.0);
+        assert_eq!(vwap.next(&bar(30.0, 20 * HOUR)), 25.0);
+        assert_eq!(vwap.next(&bar(40.0, 37 * HOUR)), 30.0);
+        assert_eq!(vwap.next(&bar(50.0, 38 * HOUR)), 50.0);
+    }
+
+    #[test]
+    fn test_next_weekly() {
+        const DAY: i64 = 24 * HOUR;
+        let mut sma = SessionReset::weekly(SimpleMovingAverage::new(3).unwrap());
+
+        // 2020-09-13 is Sunday
+        assert_eq!(sma.next(&bar(10.0, 1_599_955_200_000)), 10.0);
+        assert_eq!(sma.next(&bar(12.0, 1_599_955_200_000 + DAY - 1)), 11.0);
+        assert_eq!(sma.next(&bar(20.0, 1_599_955_200_000 + DAY)), 20.0);
+        assert_eq!(sma.next(&bar(22.0, 1_599_955_200_000 + 7 * DAY)), 21.0);
+        assert_eq!(sma.next(&bar(24.0, 1_599_955_200_000 + 8 * DAY)), 24.0);
+    }
+
+    #[test]
+    fn test_peek() {
+        let mut sma = SessionReset::daily(SimpleMovingAverage::new(3).unwrap());
+        sma.next(&bar(10.0, 0));
+        sma.next(&bar(12.0, HOUR));
+
+        assert_eq!(sma.peek(&bar(14.0, 2 * HOUR)), 12.0);
+        assert_eq!(sma.peek(&bar(14.0, 24 * HOUR)), 14.0);
+
+        assert_eq!(sma.next(&bar(14.0, 2 * HOUR)), 12.0);
+        assert_eq!(sma.update_last(&bar(17.0, 2 * HOUR)), 13.0);
+        assert_eq!(sma.update_last(&bar(20.0, 24 * HOUR)), 20.0);
+        assert_eq!(sma.next(&bar(22.0, 25 * HOUR)), 21.0);
+
+        let bars: Vec<Bar> = peek_inputs()
+            .into_iter()
+            .enumerate()
+            .map(|(i, close)| bar(close, i as i64 * 5 * HOUR))
+            .collect();
+        assert_peek(
+            SessionReset::daily(SimpleMovingAverage::new(3).unwrap()),
+            &bars.iter().collect::<Vec<_>>(),
+        );
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut sma = SessionReset::daily(SimpleMovingAverage::new(3).unwrap());
+        assert_eq!(sma.next(&bar(10.0, 24 * HOUR)), 10.0);
+        assert_eq!(sma.next(&bar(12.0, 25 * HOUR)), 11.0);
+
+        sma.reset();
+        assert!(!sma.is_ready());
+        assert_eq!(sma.next(&bar(14.0, 0)), 14.0);
+        assert_eq!(sma.next(&bar(16.0, HOUR)), 15.0);
+    }
+
+    #[test]
+    fn test_lookback() {
+        let sma = SessionReset::daily(SimpleMovingAverage::new(7).unwrap());
+        assert_eq!(sma.lookback(), 7);
+        assert_eq!(sma.period(), 7);
+        assert_eq!(sma.length(), 24 * HOUR);
+        assert_eq!(sma.offset(), 0);
+        assert_eq!(sma.inner().lookback(), 7);
+    }
+
+    #[test]
+    fn test_default() {
+        SessionReset::<VolumeWeightedAveragePrice>::default();
+    }
+
+    #[test]
+    fn test_display() {
+        let sma = SessionReset::daily(SimpleMovingAverage::new(5).unwrap());
+        assert_eq!(format!("{}", sma), "SMA(5)");
+    }
+}
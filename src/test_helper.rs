@@ -1,4 +1,6 @@
-use super::{Close, High, Low, Open, Volume};
+use std::fmt::Debug;
+
+use super::{Close, High, Low, Open, Peek, Timestamp, Volume};
 
 #[derive(Debug, PartialEq)]
 pub struct Bar {
@@ -7,6 +9,7 @@ pub struct Bar {
     low: f64,
     close: f64,
     volume: f64,
+    timestamp: i64,
 }
 
 impl Bar {
@@ -17,6 +20,7 @@ impl Bar {
             low: 0.0,
             high: 0.0,
             volume: 0.0,
+            timestamp: 0,
         }
     }
 
@@ -44,6 +48,11 @@ impl Bar {
         self.volume = val;
         self
     }
+
+    pub fn timestamp(mut self, val: i64) -> Self {
+        self.timestamp = val;
+        self
+    }
 }
 
 impl Open for Bar {
@@ -76,10 +85,65 @@ impl Volume for Bar {
     }
 }
 
+impl Timestamp for Bar {
+    fn timestamp(&self) -> i64 {
+        self.timestamp
+    }
+}
+
 pub fn round(num: f64) -> f64 {
     (num * 1000.0).round() / 1000.00
 }
 
+/// Returns inputs, which are exactly representable as `f64` and go up and down.
+pub fn peek_inputs() -> Vec<f64> {
+    (0..60)
+        .map(|i| 10.0 + (i * 37 % 23) as f64 * 0.25)
+        .collect()
+}
+
+/// Returns bars built around [peek_inputs](fn.peek_inputs.html).
+pub fn peek_bars() -> Vec<Bar> {
+    peek_inputs()
+        .into_iter()
+        .enumerate()
+        .map(|(i, close)| {
+            Bar::new()
+                .high(close + 0.25 * (i % 3) as f64 + 0.5)
+                .low(close - 0.25 * (i % 4) as f64 - 0.25)
+                .close(close)
+                .volume(100.0 + 50.0 * (i % 7) as f64)
+        })
+        .collect()
+}
+
+/// Checks that `peek` and `update_last` give the same outputs as `next`.
+///
+/// Every input is peeked first, then a tentative input is consumed and replaced with the
+/// actual one. Outputs are compared with 6 decimals, since replacing an input of a running sum
+/// may lead to a tiny floating point error (which may also flip the sign of zero).
+pub fn assert_peek<T: Copy, I: Clone + Peek<T>>(indicator: I, inputs: &[T])
+where
+    I::Output: Debug,
+{
+    fn format<O: Debug>(output: O) -> String {
+        format!("{:.6?}", output).replace("-0.000000", "0.000000")
+    }
+
+    let mut expected = indicator.clone();
+    let mut indicator = indicator;
+
+    for (i, &input) in inputs.iter().enumerate() {
+        let tentative = inputs[(i + 1) % inputs.len()];
+        let output = format(expected.next(input));
+
+        assert_eq!(format(indicator.peek(input)), output);
+        indicator.next(tentative);
+        indicator.update_last(tentative);
+        assert_eq!(format(indicator.update_last(input)), output);
+    }
+}
+
 macro_rules! test_indicator {
     ($i:tt) => {
         #[test]
@@ -99,8 +163,29 @@ macro_rules! test_indicator {
             indicator.reset();
             assert_eq!(indicator.next(12.3), first_output);
 
+            // ensure NextBatch is implemented and works the same way as Next
+            indicator.reset();
+            let batch_output = crate::NextBatch::next_batch(&mut indicator, &[12.3]);
+            assert_eq!(batch_output, vec![first_output]);
+            crate::NextBatch::next_batch(&mut indicator, &[bar]);
+
+            // ensure Lookback is implemented and works correctly
+            indicator.reset();
+            let lookback = crate::Lookback::lookback(&indicator);
+            for _ in 1..lookback {
+                indicator.next(12.3);
+            }
+            assert!(!crate::Lookback::is_ready(&indicator));
+            indicator.next(12.3);
+            assert!(crate::Lookback::is_ready(&indicator));
+
+            // ensure Peek is implemented and works the same way as Next
+            crate::test_helper::assert_peek($i::default(), &crate::test_helper::peek_inputs());
+            let bars = crate::test_helper::peek_bars();
+            crate::test_helper::assert_peek($i::default(), &bars.iter().collect::<Vec<_>>());
+
             // ensure Display is implemented
-            format!("{}", indicator);
+            let _ = format!("{}", indicator);
         }
     };
 }
@@ -1,6 +1,8 @@
 // Indicator traits
 //
 
+use alloc::vec::Vec;
+
 /// Resets an indicator to the initial state.
 pub trait Reset {
     fn reset(&mut self);
@@ -11,13 +13,28 @@ pub trait Period {
     fn period(&self) -> usize;
 }
 
+/// Tells how many inputs an indicator needs before its output becomes meaningful.
+///
+/// Most of the indicators return a value for every input, including the first ones, when
+/// they have not seen enough data yet (e.g. [SMA](indicators/generic/struct.SimpleMovingAverage.html)
+/// averages over less than _period_ values). Use [WarmUp](struct.WarmUp.html) to get `None`
+/// for those values instead.
+pub trait Lookback {
+    /// Returns the number of inputs required before the output is valid.
+    fn lookback(&self) -> usize;
+
+    /// Returns `true` when the indicator has consumed at least `lookback()` inputs.
+    fn is_ready(&self) -> bool;
+}
+
 /// Consumes a data item of type `T` and returns `Output`.
 ///
-/// Typically `T` can be `f64` or a struct similar to [DataItem](struct.DataItem.html), that implements
-/// traits necessary to calculate value of a particular indicator.
+/// Typically `T` can be `f64`, another [numeric type](trait.Num.html) or a struct similar to
+/// [DataItem](struct.DataItem.html), that implements traits necessary to calculate value of a
+/// particular indicator.
 ///
 /// In most cases `Output` is `f64`, but sometimes it can be different. For example for
-/// [MACD](indicators/struct.MovingAverageConvergenceDivergence.html) it is `(f64, f64, f64)` since
+/// [MACD](indicators/generic/struct.MovingAverageConvergenceDivergence.html) it is `(f64, f64, f64)` since
 /// MACD returns 3 values.
 ///
 pub trait Next<T> {
@@ -25,27 +42,150 @@ pub trait Next<T> {
     fn next(&mut self, input: T) -> Self::Output;
 }
 
+/// Calculates the output for a tentative data item, e.g. a still forming bar in live trading.
+///
+/// [peek](#tymethod.peek) returns the same output as [next](trait.Next.html#tymethod.next)
+/// would, but leaves the indicator untouched, so it can be called on every tick without
+/// cloning the indicator. [update_last](#tymethod.update_last) replaces the data item last
+/// consumed by `next`, as if `next` was given the new item in the first place.
+///
+/// # Example
+///
+/// ```
+/// use ta::indicators::SimpleMovingAverage;
+/// use ta::{Next, Peek};
+///
+/// let mut sma = SimpleMovingAverage::new(3).unwrap();
+/// sma.next(10.0);
+/// sma.next(11.0);
+///
+/// // the current bar is still forming
+/// assert_eq!(sma.peek(15.0), 12.0);
+/// assert_eq!(sma.peek(12.0), 11.0);
+///
+/// // the bar is closed
+/// assert_eq!(sma.next(12.0), 11.0);
+///
+/// // the close price of the last bar is corrected
+/// assert_eq!(sma.update_last(18.0), 13.0);
+/// assert_eq!(sma.next(13.0), 14.0);
+/// ```
+pub trait Peek<T>: Next<T> {
+    /// Returns the output `next(input)` would return, without changing the state.
+    fn peek(&self, input: T) -> Self::Output;
+
+    /// Replaces the input last given to `next` and returns the updated output.
+    ///
+    /// If nothing has been consumed yet, it's the same as `next(input)`.
+    fn update_last(&mut self, input: T) -> Self::Output;
+}
+
 /// Open price of a particular period.
-pub trait Open {
-    fn open(&self) -> f64;
+///
+/// The price traits are generic over the [numeric type](trait.Num.html) of the price, which
+/// is `f64` by default.
+pub trait Open<N = f64> {
+    fn open(&self) -> N;
 }
 
 /// Close price of a particular period.
-pub trait Close {
-    fn close(&self) -> f64;
+pub trait Close<N = f64> {
+    fn close(&self) -> N;
 }
 
 /// Lowest price of a particular period.
-pub trait Low {
-    fn low(&self) -> f64;
+pub trait Low<N = f64> {
+    fn low(&self) -> N;
 }
 
 /// Highest price of a particular period.
-pub trait High {
-    fn high(&self) -> f64;
+pub trait High<N = f64> {
+    fn high(&self) -> N;
 }
 
 /// Trading volume of a particular trading period.
-pub trait Volume {
-    fn volume(&self) -> f64;
+pub trait Volume<N = f64> {
+    fn volume(&self) -> N;
+}
+
+/// Time of a particular period, as Unix timestamp in milliseconds (UTC).
+///
+/// It's optional: indicators don't need it, but it allows wrapping them with
+/// [SessionReset](struct.SessionReset.html) to start over at session boundaries.
+pub trait Timestamp {
+    fn timestamp(&self) -> i64;
+}
+
+/// Consumes a whole slice of data items at once and returns the outputs.
+///
+/// It's a batch counterpart of [Next<T>](trait.Next.html): `next_batch(&items)` gives the
+/// same results as calling `next` for every item in order, but avoids writing the loop by hand.
+/// Some indicators (e.g. [SMA](indicators/generic/struct.SimpleMovingAverage.html)) provide specialised
+/// kernels, which are faster than calling `next` repeatedly.
+///
+/// `T` can be `f64` or a struct similar to [DataItem](struct.DataItem.html).
+///
+/// # Example
+///
+/// ```
+/// use ta::indicators::SimpleMovingAverage;
+/// use ta::NextBatch;
+///
+/// let mut sma = SimpleMovingAverage::new(3).unwrap();
+/// assert_eq!(sma.next_batch(&[10.0, 11.0, 12.0, 13.0]), vec![10.0, 10.5, 11.0, 12.0]);
+///
+/// let mut output = [0.0; 2];
+/// sma.next_batch_into(&[14.0, 15.0], &mut output);
+/// assert_eq!(output, [13.0, 14.0]);
+/// ```
+pub trait NextBatch<T> {
+    type Output;
+
+    /// Feeds all the items of `input` and returns the outputs.
+    fn next_batch(&mut self, input: &[T]) -> Vec<Self::Output>;
+
+    /// Feeds all the items of `input` and writes the outputs into `output`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `input` and `output` have different lengths.
+    fn next_batch_into(&mut self, input: &[T], output: &mut [Self::Output]);
+}
+
+/// Output of an indicator, which consists of several named values, e.g.
+/// [BollingerBandsOutput](indicators/generic/struct.BollingerBandsOutput.html).
+///
+/// It gives access to the values without knowing the output type, e.g. to write them into a
+/// CSV file. The outputs can be converted into arrays of their values as well.
+///
+/// # Example
+///
+/// ```
+/// use ta::indicators::BollingerBands;
+/// use ta::{Fields, Next};
+///
+/// let mut bb = BollingerBands::new(3, 2.0).unwrap();
+/// let output = bb.next(2.0);
+///
+/// assert_eq!(output.fields(), vec![("average", 2.0), ("upper", 2.0), ("lower", 2.0)]);
+/// assert_eq!(output.get("upper"), Some(2.0));
+///
+/// let [average, upper, lower]: [f64; 3] = output.into();
+/// ```
+pub trait Fields {
+    type Value: Copy;
+
+    /// Names of the fields in the order of their declaration.
+    const NAMES: &'static [&'static str];
+
+    /// Returns the values of the fields in the order of `NAMES`.
+    fn values(&self) -> Vec<Self::Value>;
+
+    /// Returns the names and the values of the fields.
+    fn fields(&self) -> Vec<(&'static str, Self::Value)> {
+        Self::NAMES.iter().copied().zip(self.values()).collect()
+    }
+
+    /// Returns the value of the field with the given name.
+    fn get(&self, name: &str) -> Option<Self::Value>;
 }
@@ -0,0 +1,196 @@
+use core::fmt;
+
+use crate::{Lookback, Next, Peek, Period, Reset};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Wraps an indicator and returns `None` until the indicator is warmed up.
+///
+/// The wrapped indicator is fed as usual, but its output is returned only when
+/// [is_ready()](trait.Lookback.html#tymethod.is_ready) is `true`, i.e. when the indicator
+/// has consumed at least [lookback()](trait.Lookback.html#tymethod.lookback) inputs.
+///
+/// # Example
+///
+/// ```
+/// use ta::indicators::SimpleMovingAverage;
+/// use ta::{Next, WarmUp};
+///
+/// let mut sma = WarmUp::new(SimpleMovingAverage::new(3).unwrap());
+/// assert_eq!(sma.next(10.0), None);
+/// assert_eq!(sma.next(11.0), None);
+/// assert_eq!(sma.next(12.0), Some(11.0));
+/// assert_eq!(sma.next(13.0), Some(12.0));
+/// ```
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone)]
+pub struct WarmUp<I> {
+    indicator: I,
+    count: usize,
+}
+
+impl<I> WarmUp<I> {
+    pub fn new(indicator: I) -> Self {
+        Self {
+            indicator,
+            count: 0,
+        }
+    }
+
+    /// Returns a reference to the wrapped indicator.
+    pub fn inner(&self) -> &I {
+        &self.indicator
+    }
+
+    /// Unwraps the indicator.
+    pub fn into_inner(self) -> I {
+        self.indicator
+    }
+}
+
+impl<I: Lookback> Lookback for WarmUp<I> {
+    fn lookback(&self) -> usize {
+        self.indicator.lookback()
+    }
+
+    fn is_ready(&self) -> bool {
+        self.indicator.is_ready()
+    }
+}
+
+impl<I: Period> Period for WarmUp<I> {
+    fn period(&self) -> usize {
+        self.indicator.period()
+    }
+}
+
+impl<T, I: Next<T> + Lookback> Next<T> for WarmUp<I> {
+    type Output = Option<I::Output>;
+
+    fn next(&mut self, input: T) -> Self::Output {
+        self.count = self.count.saturating_add(1);
+        let output = self.indicator.next(input);
+        if self.indicator.is_ready() {
+            Some(output)
+        } else {
+            None
+        }
+    }
+}
+
+impl<T, I: Peek<T> + Lookback> Peek<T> for WarmUp<I> {
+    fn peek(&self, input: T) -> Self::Output {
+        let output = self.indicator.peek(input);
+        if self.indicator.is_ready() || self.count + 1 >= self.indicator.lookback() {
+            Some(output)
+        } else {
+            None
+        }
+    }
+
+    fn update_last(&mut self, input: T) -> Self::Output {
+        if self.count == 0 {
+            return self.next(input);
+        }
+
+        let output = self.indicator.update_last(input);
+        if self.indicator.is_ready() {
+            Some(output)
+        } else {
+            None
+        }
+    }
+}
+
+impl<I: Reset> Reset for WarmUp<I> {
+    fn reset(&mut self) {
+        self.indicator.reset();
+        self.count = 0;
+    }
+}
+
+impl<I: Default> Default for WarmUp<I> {
+    fn default() -> Self {
+        Self::new(I::default())
+    }
+}
+
+impl<I: fmt::Display> fmt::Display for WarmUp<I> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.indicator)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::indicators::{MoneyFlowIndex, RelativeStrengthIndex, SimpleMovingAverage};
+    use crate::test_helper::*;
+
+    #[test]
+    fn test_next() {
+        let mut rsi = WarmUp::new(RelativeStrengthIndex::new(3).unwrap());
+        assert_eq!(rsi.next(10.0), None);
+        assert_eq!(rsi.next(10.5), None);
+        assert_eq!(rsi.next(10.0), None);
+        assert_eq!(rsi.next(9.5).map(f64::round), Some(16.0));
+    }
+
+    #[test]
+    fn test_next_bar() {
+        let mut mfi = WarmUp::new(MoneyFlowIndex::new(2).unwrap());
+
+        let bar1 = Bar::new().high(3).low(1).close(2).volume(500.0);
+        let bar2 = Bar::new().high(2.3).low(2.0).close(2.3).volume(1000.0);
+        let bar3 = Bar::new().high(9).low(7).close(8).volume(200.0);
+
+        assert_eq!(mfi.next(&bar1), None);
+        assert_eq!(mfi.next(&bar2), None);
+        assert_eq!(mfi.next(&bar3), Some(100.0));
+    }
+
+    #[test]
+    fn test_peek() {
+        let mut sma = WarmUp::new(SimpleMovingAverage::new(3).unwrap());
+        sma.next(10.0);
+        assert_eq!(sma.peek(11.0), None);
+        sma.next(11.0);
+        assert_eq!(sma.peek(15.0), Some(12.0));
+        assert_eq!(sma.update_last(14.0), None);
+
+        assert_peek(
+            WarmUp::new(SimpleMovingAverage::new(3).unwrap()),
+            &peek_inputs(),
+        );
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut sma = WarmUp::new(SimpleMovingAverage::new(2).unwrap());
+        assert_eq!(sma.next(4.0), None);
+        assert_eq!(sma.next(6.0), Some(5.0));
+
+        sma.reset();
+        assert!(!sma.is_ready());
+        assert_eq!(sma.next(4.0), None);
+    }
+
+    #[test]
+    fn test_lookback() {
+        let sma = WarmUp::new(SimpleMovingAverage::new(7).unwrap());
+        assert_eq!(sma.lookback(), 7);
+        assert_eq!(sma.period(), 7);
+        assert_eq!(sma.inner().lookback(), 7);
+    }
+
+    #[test]
+    fn test_default() {
+        WarmUp::<SimpleMovingAverage>::default();
+    }
+
+    #[test]
+    fn test_display() {
+        let sma = WarmUp::new(SimpleMovingAverage::new(5).unwrap());
+        assert_eq!(format!("{}", sma), "SMA(5)");
+    }
+}
@@ -7,7 +7,7 @@ extern crate ta;
 mod test {
     #[cfg(feature = "serde")]
     mod serde {
-        use ta::indicators::SimpleMovingAverage;
+        use ta::indicators::{BollingerBands, BollingerBandsOutput, SimpleMovingAverage};
         use ta::Next;
 
         // Simple smoke test that serde works (not sure if this is really necessary)
@@ -19,5 +19,16 @@ mod test {
 
             assert_eq!(deserialized.next(2.0), macd.next(2.0));
         }
+
+        #[test]
+        fn test_serde_output() {
+            let mut bb = BollingerBands::new(3, 2.0).unwrap();
+            bb.next(2.0);
+            let output = bb.next(5.0);
+            let bytes = bincode::serialize(&output).unwrap();
+            let deserialized: BollingerBandsOutput = bincode::deserialize(&bytes).unwrap();
+
+            assert_eq!(deserialized, output);
+        }
     }
 }